@@ -2,6 +2,13 @@
 
 pub mod prelude;
 
+pub mod calendar_feed;
+pub mod calendar_period;
+pub mod role_request;
 pub mod sea_orm_active_enums;
 pub mod service_user;
+pub mod subject_alias;
+pub mod teacher_alias;
+pub mod teacher_claim;
 pub mod user;
+pub mod user_follow;
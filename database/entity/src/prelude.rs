@@ -1,4 +1,11 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.1.12
 
+pub use super::calendar_feed::Entity as CalendarFeed;
+pub use super::calendar_period::Entity as CalendarPeriod;
+pub use super::role_request::Entity as RoleRequest;
 pub use super::service_user::Entity as ServiceUser;
+pub use super::subject_alias::Entity as SubjectAlias;
+pub use super::teacher_alias::Entity as TeacherAlias;
+pub use super::teacher_claim::Entity as TeacherClaim;
 pub use super::user::Entity as User;
+pub use super::user_follow::Entity as UserFollow;
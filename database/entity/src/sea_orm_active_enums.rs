@@ -23,3 +23,73 @@ pub enum UserRole {
     #[sea_orm(string_value = "admin")]
     Admin,
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(
+    rs_type = "String",
+    db_type = "Enum",
+    enum_name = "calendar_period_kind"
+)]
+pub enum CalendarPeriodKind {
+    #[sea_orm(string_value = "semester")]
+    Semester,
+    #[sea_orm(string_value = "holiday")]
+    Holiday,
+    #[sea_orm(string_value = "practice")]
+    Practice,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "week_parity")]
+pub enum WeekParity {
+    #[sea_orm(string_value = "numerator")]
+    Numerator,
+    #[sea_orm(string_value = "denominator")]
+    Denominator,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    :: serde :: Serialize,
+    :: serde :: Deserialize,
+    :: utoipa :: ToSchema,
+)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "claim_status")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ClaimStatus {
+    #[sea_orm(string_value = "pending")]
+    Pending,
+    #[sea_orm(string_value = "approved")]
+    Approved,
+    #[sea_orm(string_value = "rejected")]
+    Rejected,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    EnumIter,
+    DeriveActiveEnum,
+    :: serde :: Serialize,
+    :: serde :: Deserialize,
+    :: utoipa :: ToSchema,
+)]
+#[sea_orm(rs_type = "String", db_type = "Enum", enum_name = "follow_kind")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum FollowKind {
+    #[sea_orm(string_value = "group")]
+    Group,
+    #[sea_orm(string_value = "teacher")]
+    Teacher,
+    #[sea_orm(string_value = "cabinet")]
+    Cabinet,
+}
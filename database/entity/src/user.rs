@@ -13,6 +13,10 @@ pub struct Model {
     pub password: Option<String>,
     pub vk_id: Option<i32>,
     pub group: Option<String>,
+    pub provider: Option<String>,
+    pub subgroup: Option<i32>,
+    pub teacher_name: Option<String>,
+    pub teacher_provider: Option<String>,
     pub role: UserRole,
     pub android_version: Option<String>,
     #[sea_orm(unique)]
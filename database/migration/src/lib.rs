@@ -4,6 +4,18 @@ use sea_orm_migration::prelude::*;
 
 mod m20250904_024854_init;
 mod m20251027_230335_add_service_users;
+mod m20251103_184512_add_user_provider;
+mod m20251104_120000_add_teacher_aliases;
+mod m20251105_120000_add_subject_aliases;
+mod m20251106_120000_add_academic_calendar;
+mod m20251107_120000_add_calendar_feeds;
+mod m20251108_120000_add_user_subgroup;
+mod m20251109_120000_add_teacher_claims;
+mod m20251110_120000_add_role_requests;
+mod m20251111_120000_add_user_follows;
+mod m20251112_120000_add_teacher_binding_provider;
+mod m20251113_120000_add_role_request_origin;
+mod m20251114_120000_hash_calendar_feed_tokens;
 
 pub struct Migrator;
 
@@ -13,6 +25,18 @@ impl MigratorTrait for Migrator {
         vec![
             Box::new(m20250904_024854_init::Migration),
             Box::new(m20251027_230335_add_service_users::Migration),
+            Box::new(m20251103_184512_add_user_provider::Migration),
+            Box::new(m20251104_120000_add_teacher_aliases::Migration),
+            Box::new(m20251105_120000_add_subject_aliases::Migration),
+            Box::new(m20251106_120000_add_academic_calendar::Migration),
+            Box::new(m20251107_120000_add_calendar_feeds::Migration),
+            Box::new(m20251108_120000_add_user_subgroup::Migration),
+            Box::new(m20251109_120000_add_teacher_claims::Migration),
+            Box::new(m20251110_120000_add_role_requests::Migration),
+            Box::new(m20251111_120000_add_user_follows::Migration),
+            Box::new(m20251112_120000_add_teacher_binding_provider::Migration),
+            Box::new(m20251113_120000_add_role_request_origin::Migration),
+            Box::new(m20251114_120000_hash_calendar_feed_tokens::Migration),
         ]
     }
 }
@@ -0,0 +1,33 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(TeacherAlias::Table)
+                    .if_not_exists()
+                    .col(string_uniq(TeacherAlias::Alias).primary_key().not_null())
+                    .col(string(TeacherAlias::Canonical))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TeacherAlias::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum TeacherAlias {
+    Table,
+    Alias,
+    Canonical,
+}
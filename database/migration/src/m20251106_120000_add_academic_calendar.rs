@@ -0,0 +1,98 @@
+use sea_orm_migration::prelude::extension::postgres::Type;
+use sea_orm_migration::sea_orm::{EnumIter, Iterable};
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(CalendarPeriodKind)
+                    .values(CalendarPeriodKindVariants::iter())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(WeekParity)
+                    .values(WeekParityVariants::iter())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(CalendarPeriod::Table)
+                    .if_not_exists()
+                    .col(pk_auto(CalendarPeriod::Id))
+                    .col(string_null(CalendarPeriod::Provider))
+                    .col(enumeration(
+                        CalendarPeriod::Kind,
+                        CalendarPeriodKind,
+                        CalendarPeriodKindVariants::iter(),
+                    ))
+                    .col(string(CalendarPeriod::Name))
+                    .col(date(CalendarPeriod::StartDate))
+                    .col(date(CalendarPeriod::EndDate))
+                    .col(enumeration_null(
+                        CalendarPeriod::FirstWeek,
+                        WeekParity,
+                        WeekParityVariants::iter(),
+                    ))
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CalendarPeriod::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_type(Type::drop().name(WeekParity).to_owned())
+            .await?;
+
+        manager
+            .drop_type(Type::drop().name(CalendarPeriodKind).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+struct CalendarPeriodKind;
+
+#[derive(DeriveIden, EnumIter)]
+enum CalendarPeriodKindVariants {
+    Semester,
+    Holiday,
+    Practice,
+}
+
+#[derive(DeriveIden)]
+struct WeekParity;
+
+#[derive(DeriveIden, EnumIter)]
+enum WeekParityVariants {
+    Numerator,
+    Denominator,
+}
+
+#[derive(DeriveIden)]
+enum CalendarPeriod {
+    Table,
+    Id,
+    Provider,
+    Kind,
+    Name,
+    StartDate,
+    EndDate,
+    FirstWeek,
+}
@@ -0,0 +1,47 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(CalendarFeed::Table)
+                    .if_not_exists()
+                    .col(string_uniq(CalendarFeed::UserId).primary_key().not_null())
+                    .col(string_uniq(CalendarFeed::Token).not_null())
+                    .col(timestamp_with_time_zone(CalendarFeed::CreatedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(CalendarFeed::Table, CalendarFeed::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(CalendarFeed::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum CalendarFeed {
+    Table,
+    UserId,
+    Token,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+    Id,
+}
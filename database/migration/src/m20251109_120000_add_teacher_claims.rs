@@ -0,0 +1,103 @@
+use sea_orm_migration::prelude::extension::postgres::Type;
+use sea_orm_migration::sea_orm::{EnumIter, Iterable};
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(User::Table)
+                    .add_column(string_null(User::TeacherName))
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(ClaimStatus)
+                    .values(ClaimStatusVariants::iter())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(TeacherClaim::Table)
+                    .if_not_exists()
+                    .col(pk_auto(TeacherClaim::Id))
+                    .col(string(TeacherClaim::UserId))
+                    .col(string(TeacherClaim::Provider))
+                    .col(string(TeacherClaim::TeacherName))
+                    .col(enumeration(
+                        TeacherClaim::Status,
+                        ClaimStatus,
+                        ClaimStatusVariants::iter(),
+                    ))
+                    .col(timestamp_with_time_zone(TeacherClaim::CreatedAt))
+                    .col(timestamp_with_time_zone_null(TeacherClaim::DecidedAt))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(TeacherClaim::Table, TeacherClaim::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(TeacherClaim::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_type(Type::drop().name(ClaimStatus).to_owned())
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(User::Table)
+                    .drop_column(User::TeacherName)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+struct ClaimStatus;
+
+#[derive(DeriveIden, EnumIter)]
+enum ClaimStatusVariants {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+#[derive(DeriveIden)]
+enum TeacherClaim {
+    Table,
+    Id,
+    UserId,
+    Provider,
+    TeacherName,
+    Status,
+    CreatedAt,
+    DecidedAt,
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+    Id,
+    TeacherName,
+}
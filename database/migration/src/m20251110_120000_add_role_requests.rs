@@ -0,0 +1,86 @@
+use sea_orm_migration::sea_orm::{EnumIter, Iterable};
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(RoleRequest::Table)
+                    .if_not_exists()
+                    .col(pk_auto(RoleRequest::Id))
+                    .col(string(RoleRequest::UserId))
+                    .col(enumeration(
+                        RoleRequest::Role,
+                        UserRole,
+                        UserRoleVariants::iter(),
+                    ))
+                    .col(enumeration(
+                        RoleRequest::Status,
+                        ClaimStatus,
+                        ClaimStatusVariants::iter(),
+                    ))
+                    .col(timestamp_with_time_zone(RoleRequest::CreatedAt))
+                    .col(timestamp_with_time_zone_null(RoleRequest::DecidedAt))
+                    .col(string_null(RoleRequest::DecidedBy))
+                    .col(string_null(RoleRequest::Comment))
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(RoleRequest::Table, RoleRequest::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(RoleRequest::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+struct UserRole;
+
+#[derive(DeriveIden, EnumIter)]
+enum UserRoleVariants {
+    Student,
+    Teacher,
+    Admin,
+}
+
+#[derive(DeriveIden)]
+struct ClaimStatus;
+
+#[derive(DeriveIden, EnumIter)]
+enum ClaimStatusVariants {
+    Pending,
+    Approved,
+    Rejected,
+}
+
+#[derive(DeriveIden)]
+enum RoleRequest {
+    Table,
+    Id,
+    UserId,
+    Role,
+    Status,
+    CreatedAt,
+    DecidedAt,
+    DecidedBy,
+    Comment,
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+    Id,
+}
@@ -0,0 +1,90 @@
+use sea_orm_migration::prelude::extension::postgres::Type;
+use sea_orm_migration::sea_orm::{EnumIter, Iterable};
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_type(
+                Type::create()
+                    .as_enum(FollowKind)
+                    .values(FollowKindVariants::iter())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserFollow::Table)
+                    .if_not_exists()
+                    .col(pk_auto(UserFollow::Id))
+                    .col(string(UserFollow::UserId))
+                    .col(string(UserFollow::Provider))
+                    .col(enumeration(
+                        UserFollow::Kind,
+                        FollowKind,
+                        FollowKindVariants::iter(),
+                    ))
+                    .col(string(UserFollow::Name))
+                    .col(timestamp_with_time_zone(UserFollow::CreatedAt))
+                    .index(
+                        Index::create()
+                            .unique()
+                            .col(UserFollow::UserId)
+                            .col(UserFollow::Provider)
+                            .col(UserFollow::Kind)
+                            .col(UserFollow::Name),
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .from(UserFollow::Table, UserFollow::UserId)
+                            .to(User::Table, User::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(UserFollow::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_type(Type::drop().name(FollowKind).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+struct FollowKind;
+
+#[derive(DeriveIden, EnumIter)]
+enum FollowKindVariants {
+    Group,
+    Teacher,
+    Cabinet,
+}
+
+#[derive(DeriveIden)]
+enum UserFollow {
+    Table,
+    Id,
+    UserId,
+    Provider,
+    Kind,
+    Name,
+    CreatedAt,
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+    Id,
+}
@@ -0,0 +1,89 @@
+use sea_orm_migration::{prelude::*, schema::*};
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(User::Table)
+                    .add_column(string_null(User::TeacherProvider))
+                    .to_owned(),
+            )
+            .await?;
+
+        let db = manager.get_connection();
+
+        // existing bindings take the provider from the approved claim
+        db.execute_unprepared(
+            r#"UPDATE "user" SET teacher_provider = claim.provider
+            FROM (
+                SELECT DISTINCT ON (user_id) user_id, provider FROM teacher_claim
+                WHERE status = 'approved'
+                ORDER BY user_id, decided_at DESC
+            ) AS claim
+            WHERE claim.user_id = "user".id AND "user".teacher_name IS NOT NULL"#,
+        )
+        .await?;
+
+        // bindings without a claim and repeated bindings of the same teacher
+        // can't be attributed, such users have to claim the teacher again
+        db.execute_unprepared(
+            r#"UPDATE "user" SET teacher_name = NULL, teacher_provider = NULL
+            WHERE teacher_name IS NOT NULL AND (
+                teacher_provider IS NULL OR id IN (
+                    SELECT id FROM (
+                        SELECT id, row_number() OVER (
+                            PARTITION BY teacher_provider, teacher_name ORDER BY id
+                        ) AS number
+                        FROM "user" WHERE teacher_name IS NOT NULL
+                    ) AS bound
+                    WHERE number > 1
+                )
+            )"#,
+        )
+        .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("user_teacher_binding")
+                    .table(User::Table)
+                    .col(User::TeacherProvider)
+                    .col(User::TeacherName)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("user_teacher_binding")
+                    .table(User::Table)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(User::Table)
+                    .drop_column(User::TeacherProvider)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum User {
+    Table,
+    TeacherName,
+    TeacherProvider,
+}
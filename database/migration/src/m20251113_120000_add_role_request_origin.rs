@@ -0,0 +1,78 @@
+use sea_orm_migration::sea_orm::{EnumIter, Iterable};
+use sea_orm_migration::{prelude::*, schema::*};
+
+/// Comment of the requests created for the teachers that signed up before the verification.
+const AUTO_APPROVED: &str = "Approved automatically: signed up before role verification.";
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // requests made so far could only be made by students
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(RoleRequest::Table)
+                    .add_column(
+                        enumeration(RoleRequest::FromRole, UserRole, UserRoleVariants::iter())
+                            .default("student"),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        // teachers that signed up before the verification was introduced keep the role,
+        // the request records that it was granted without a review
+        manager
+            .get_connection()
+            .execute_unprepared(&format!(
+                r#"INSERT INTO role_request (user_id, role, from_role, status, created_at, decided_at, comment)
+                SELECT id, 'teacher'::user_role, 'student'::user_role, 'approved'::claim_status, now(), now(), '{AUTO_APPROVED}'
+                FROM "user"
+                WHERE role = 'teacher' AND NOT EXISTS (
+                    SELECT 1 FROM role_request
+                    WHERE user_id = "user".id AND role = 'teacher' AND status = 'approved'
+                )"#
+            ))
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // roles weren't changed by the migration, only the records of it are removed
+        manager
+            .get_connection()
+            .execute_unprepared(&format!(
+                "DELETE FROM role_request WHERE comment = '{AUTO_APPROVED}' AND decided_by IS NULL"
+            ))
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(RoleRequest::Table)
+                    .drop_column(RoleRequest::FromRole)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+struct UserRole;
+
+#[derive(DeriveIden, EnumIter)]
+enum UserRoleVariants {
+    Student,
+    Teacher,
+    Admin,
+}
+
+#[derive(DeriveIden)]
+enum RoleRequest {
+    Table,
+    FromRole,
+}
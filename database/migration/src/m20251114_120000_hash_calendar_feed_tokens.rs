@@ -0,0 +1,52 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(CalendarFeed::Table)
+                    .rename_column(CalendarFeed::Token, CalendarFeed::TokenHash)
+                    .to_owned(),
+            )
+            .await?;
+
+        // existing feeds keep working, their tokens are hashed the same way as new ones
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "UPDATE calendar_feed SET token_hash = encode(sha256(convert_to(token_hash, 'UTF8')), 'hex')",
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // tokens can't be restored from their hashes
+        manager
+            .get_connection()
+            .execute_unprepared("DELETE FROM calendar_feed")
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(CalendarFeed::Table)
+                    .rename_column(CalendarFeed::TokenHash, CalendarFeed::Token)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum CalendarFeed {
+    Table,
+    Token,
+    TokenHash,
+}
@@ -8,6 +8,27 @@ pub mod entity {
 
     pub use entity::*;
 
+    pub use entity::calendar_feed::{
+        ActiveModel as ActiveCalendarFeed, //
+        Column as CalendarFeedColumn,      //
+        Entity as CalendarFeedEntity,      //
+        Model as CalendarFeed,             //
+    };
+
+    pub use entity::calendar_period::{
+        ActiveModel as ActiveCalendarPeriod, //
+        Column as CalendarPeriodColumn,      //
+        Entity as CalendarPeriodEntity,      //
+        Model as CalendarPeriod,             //
+    };
+
+    pub use entity::role_request::{
+        ActiveModel as ActiveRoleRequest, //
+        Column as RoleRequestColumn,      //
+        Entity as RoleRequestEntity,      //
+        Model as RoleRequest,             //
+    };
+
     pub use entity::user::{
         ActiveModel as ActiveUser, //
         Column as UserColumn,      //
@@ -15,6 +36,13 @@ pub mod entity {
         Model as User,             //
     };
 
+    pub use entity::user_follow::{
+        ActiveModel as ActiveUserFollow, //
+        Column as UserFollowColumn,      //
+        Entity as UserFollowEntity,      //
+        Model as UserFollow,             //
+    };
+
     pub use entity::service_user::{
         ActiveModel as ActiveServiceUser, //
         Column as ServiceUserColumn,      //
@@ -22,6 +50,27 @@ pub mod entity {
         Model as ServiceUser,             //
     };
 
+    pub use entity::subject_alias::{
+        ActiveModel as ActiveSubjectAlias, //
+        Column as SubjectAliasColumn,      //
+        Entity as SubjectAliasEntity,      //
+        Model as SubjectAlias,             //
+    };
+
+    pub use entity::teacher_alias::{
+        ActiveModel as ActiveTeacherAlias, //
+        Column as TeacherAliasColumn,      //
+        Entity as TeacherAliasEntity,      //
+        Model as TeacherAlias,             //
+    };
+
+    pub use entity::teacher_claim::{
+        ActiveModel as ActiveTeacherClaim, //
+        Column as TeacherClaimColumn,      //
+        Entity as TeacherClaimEntity,      //
+        Model as TeacherClaim,             //
+    };
+
     #[derive(Debug, Serialize, Deserialize, PartialEq)]
     #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
     pub enum UserType {
@@ -70,4 +70,33 @@ impl Query {
 
     define_is_exists!(service_user, id, str, Id);
     define_is_exists!(service_user, name, str, Name);
+
+    // Teacher alias
+
+    define_find_by!(teacher_alias, alias, str, Alias);
+
+    // Subject alias
+
+    define_find_by!(subject_alias, alias, str, Alias);
+
+    // Calendar period
+
+    define_find_by!(calendar_period, id, i32, Id);
+
+    // Teacher claim
+
+    define_find_by!(teacher_claim, id, i32, Id);
+
+    // Role request
+
+    define_find_by!(role_request, id, i32, Id);
+
+    // User follow
+
+    define_find_by!(user_follow, id, i32, Id);
+
+    // Calendar feed
+
+    define_find_by!(calendar_feed, user_id, str, UserId);
+    define_find_by!(calendar_feed, token_hash, str, TokenHash);
 }
@@ -0,0 +1,41 @@
+use crate::{LessonBoundaries, LessonType};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Lesson slot of the bell schedule.
+#[derive(Clone, Hash, Debug, Serialize, Deserialize, ToSchema)]
+pub struct Bell {
+    /// Index of the pair, absent for additional lessons.
+    pub index: Option<u32>,
+
+    /// Type of the slot.
+    #[serde(rename = "type")]
+    pub lesson_type: LessonType,
+
+    /// Beginning and end of the slot.
+    pub time: LessonBoundaries,
+}
+
+/// Bell schedule of a single day, common to all groups.
+#[derive(Clone, Hash, Debug, Serialize, Deserialize, ToSchema)]
+pub struct BellDay {
+    /// Day of the week.
+    pub name: String,
+
+    /// Date.
+    pub date: DateTime<Utc>,
+
+    /// Lesson slots in the order they are listed in the schedule.
+    pub bells: Vec<Bell>,
+}
+
+impl BellDay {
+    /// Beginning of the first and end of the last slot of the day.
+    pub fn boundaries(&self) -> Option<LessonBoundaries> {
+        let start = self.bells.iter().map(|bell| bell.time.start).min()?;
+        let end = self.bells.iter().map(|bell| bell.time.end).max()?;
+
+        Some(LessonBoundaries { start, end })
+    }
+}
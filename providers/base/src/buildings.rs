@@ -0,0 +1,260 @@
+use crate::LessonType::Break;
+use crate::{Lesson, LessonBoundaries, ScheduleEntry};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use utoipa::ToSchema;
+
+/// Building where lessons take place.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct Building {
+    /// Identifier.
+    pub id: String,
+
+    /// Address as it is written in the schedule.
+    pub address: String,
+
+    /// Beginnings of cabinet names located in this building.
+    #[serde(default)]
+    pub cabinet_prefixes: Vec<String>,
+}
+
+/// Move between buildings during a break.
+#[derive(Clone, Hash, Debug, Serialize, Deserialize, ToSchema)]
+pub struct Commute {
+    /// Identifier of the building of the previous lesson.
+    pub from: String,
+
+    /// Identifier of the building of the next lesson.
+    pub to: String,
+
+    /// Time available for the move.
+    pub time: LessonBoundaries,
+}
+
+/// Letters and digits of the address in lower case.
+fn address_key(address: &str) -> String {
+    address
+        .chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Picks the building of the lesson.
+///
+/// The building with the longest matching cabinet prefix wins,
+/// then the building at the day's address, then the first building of the list.
+fn resolve_building<'a>(
+    lesson: &Lesson,
+    street: Option<&str>,
+    buildings: &'a [Building],
+) -> Option<&'a Building> {
+    let mut by_cabinet: Option<(usize, &Building)> = None;
+
+    let cabinets = lesson
+        .subgroups
+        .iter()
+        .flatten()
+        .flatten()
+        .filter_map(|subgroup| subgroup.cabinet.as_deref());
+
+    for cabinet in cabinets {
+        let cabinet = cabinet.to_lowercase();
+
+        for building in buildings {
+            for prefix in &building.cabinet_prefixes {
+                let longer = by_cabinet.is_none_or(|(length, _)| prefix.len() > length);
+
+                if longer && cabinet.starts_with(&prefix.to_lowercase()) {
+                    by_cabinet = Some((prefix.len(), building));
+                }
+            }
+        }
+    }
+
+    let by_cabinet = by_cabinet.map(|(_, building)| building);
+
+    let by_street = || {
+        let street = address_key(street?);
+
+        buildings.iter().find(|building| {
+            let address = address_key(&building.address);
+
+            street.contains(&address) || address.contains(&street)
+        })
+    };
+
+    by_cabinet.or_else(by_street).or(buildings.first())
+}
+
+/// Sets [`Lesson::building`] of every lesson of the groups.
+pub fn assign_buildings(groups: &mut HashMap<String, ScheduleEntry>, buildings: &[Building]) {
+    for day in groups.values_mut().flat_map(|group| &mut group.days) {
+        let street = day.street.clone();
+
+        for lesson in &mut day.lessons {
+            lesson.building = if lesson.lesson_type == Break {
+                None
+            } else {
+                resolve_building(lesson, street.as_deref(), buildings)
+                    .map(|building| building.id.clone())
+            };
+        }
+    }
+}
+
+/// Fills [`crate::Day::commutes`] with moves between buildings of consecutive lessons.
+pub fn find_commutes(entries: &mut HashMap<String, ScheduleEntry>) {
+    for day in entries.values_mut().flat_map(|entry| &mut entry.days) {
+        let lessons: Vec<&Lesson> = day
+            .lessons
+            .iter()
+            .filter(|lesson| lesson.lesson_type != Break)
+            .collect();
+
+        day.commutes = lessons
+            .windows(2)
+            .filter_map(|pair| {
+                let from = pair[0].building.as_ref()?;
+                let to = pair[1].building.as_ref()?;
+
+                (from != to).then(|| Commute {
+                    from: from.clone(),
+                    to: to.clone(),
+                    time: LessonBoundaries {
+                        start: pair[0].time.end,
+                        end: pair[1].time.start,
+                    },
+                })
+            })
+            .collect();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Day, DayCalendar, LessonSubGroup, LessonType};
+    use chrono::{DateTime, TimeDelta, Utc};
+
+    fn at(minutes: i64) -> DateTime<Utc> {
+        DateTime::default() + TimeDelta::minutes(minutes)
+    }
+
+    fn lesson(cabinet: &str, start: i64, end: i64) -> Lesson {
+        Lesson {
+            lesson_type: LessonType::Default,
+            range: Some([1, 1]),
+            name: Some("История".to_string()),
+            subject_id: None,
+            subject: None,
+            time: LessonBoundaries {
+                start: at(start),
+                end: at(end),
+            },
+            subgroups: Some(vec![Some(LessonSubGroup {
+                cabinet: Some(cabinet.to_string()),
+                teacher: Some("Иванов И.И.".to_string()),
+            })]),
+            modifiers: Vec::new(),
+            raw: None,
+            building: None,
+            groups: Vec::new(),
+        }
+    }
+
+    fn building(id: &str, address: &str, prefixes: &[&str]) -> Building {
+        Building {
+            id: id.to_string(),
+            address: address.to_string(),
+            cabinet_prefixes: prefixes.iter().map(|prefix| prefix.to_string()).collect(),
+        }
+    }
+
+    fn groups(street: Option<&str>, lessons: Vec<Lesson>) -> HashMap<String, ScheduleEntry> {
+        let entry = ScheduleEntry {
+            name: "ИС-214/23".to_string(),
+            days: vec![Day {
+                name: "Понедельник".to_string(),
+                street: street.map(str::to_string),
+                date: DateTime::default(),
+                lessons,
+                commutes: Vec::new(),
+                calendar: DayCalendar::default(),
+            }],
+        };
+
+        HashMap::from([(entry.name.clone(), entry)])
+    }
+
+    fn resolved(groups: &HashMap<String, ScheduleEntry>) -> Vec<Option<&str>> {
+        groups["ИС-214/23"].days[0]
+            .lessons
+            .iter()
+            .map(|lesson| lesson.building.as_deref())
+            .collect()
+    }
+
+    #[test]
+    fn resolve() {
+        let buildings = [
+            building("main", "Пушкина 13", &["1", "2"]),
+            building("second", "ул. Максима Горького, 18", &["к/", "к/1"]),
+            building("third", "Советская 5", &["к/10"]),
+        ];
+
+        let mut by_cabinet = groups(
+            None,
+            vec![
+                lesson("101", 0, 90),
+                lesson("к/5", 100, 190),
+                lesson("К/101", 200, 290),
+                lesson("спортзал", 300, 390),
+            ],
+        );
+        assign_buildings(&mut by_cabinet, &buildings);
+
+        assert_eq!(
+            resolved(&by_cabinet),
+            [Some("main"), Some("second"), Some("third"), Some("main")]
+        );
+
+        let mut by_street = groups(Some("Максима Горького 18"), vec![lesson("спортзал", 0, 90)]);
+        assign_buildings(&mut by_street, &buildings);
+
+        assert_eq!(resolved(&by_street), [Some("second")]);
+
+        let mut without_buildings = groups(None, vec![lesson("101", 0, 90)]);
+        assign_buildings(&mut without_buildings, &[]);
+
+        assert_eq!(resolved(&without_buildings), [None]);
+    }
+
+    #[test]
+    fn commutes() {
+        let buildings = [
+            building("main", "Пушкина 13", &["1"]),
+            building("second", "Максима Горького 18", &["к/"]),
+        ];
+
+        let mut entries = groups(
+            None,
+            vec![
+                lesson("101", 0, 90),
+                lesson("102", 100, 190),
+                lesson("к/5", 200, 290),
+            ],
+        );
+        assign_buildings(&mut entries, &buildings);
+        find_commutes(&mut entries);
+
+        let commutes = &entries["ИС-214/23"].days[0].commutes;
+
+        assert_eq!(commutes.len(), 1);
+        assert_eq!(commutes[0].from, "main");
+        assert_eq!(commutes[0].to, "second");
+        assert_eq!(commutes[0].time.start, at(190));
+        assert_eq!(commutes[0].time.end, at(200));
+    }
+}
@@ -0,0 +1,330 @@
+use crate::LessonType::Break;
+use crate::{Day, Lesson, LessonSubGroup, ScheduleEntry};
+use std::collections::{BTreeMap, HashMap};
+
+/// Whether the lesson of another group or subgroup is the same lesson.
+///
+/// Different lessons at the same time are kept apart, so double-bookings stay visible.
+fn is_same_lesson(a: &Lesson, b: &Lesson) -> bool {
+    a.time.start == b.time.start
+        && a.time.end == b.time.end
+        && a.name == b.name
+        && a.lesson_type == b.lesson_type
+}
+
+/// Adds the subgroups of the lesson in the cabinet to the same lesson of another group or subgroup.
+fn merge_lesson(lesson: &mut Lesson, subgroups: Vec<Option<LessonSubGroup>>, group: &str) {
+    let merged = lesson.subgroups.get_or_insert_with(Vec::new);
+
+    for (index, subgroup) in subgroups.into_iter().enumerate() {
+        let Some(subgroup) = subgroup else {
+            continue;
+        };
+
+        match merged.get_mut(index) {
+            Some(slot @ None) => *slot = Some(subgroup),
+            Some(Some(own)) if *own == subgroup => {}
+            // another teacher of the subgroup with the same number, both are kept
+            Some(Some(_)) => {
+                if !merged.contains(&Some(subgroup.clone())) {
+                    merged.push(Some(subgroup));
+                }
+            }
+            None => {
+                merged.resize(index, None);
+                merged.push(Some(subgroup));
+            }
+        }
+    }
+
+    if !lesson.groups.iter().any(|name| name == group) {
+        lesson.groups.push(group.to_string());
+    }
+}
+
+/// Schedule of the cabinet built from the schedule of groups.
+///
+/// The same lesson of several groups or subgroups taking place in the cabinet is merged into one.
+/// Returns `None` if there are no lessons in the cabinet.
+///
+/// # Arguments
+///
+/// * `groups`: schedule of groups.
+/// * `cabinet`: name of the cabinet, compared case-insensitively.
+///
+/// returns: schedule named as the cabinet is written in the schedule.
+pub fn cabinet_schedule(
+    groups: &HashMap<String, ScheduleEntry>,
+    cabinet: &str,
+) -> Option<ScheduleEntry> {
+    let cabinet = cabinet.trim().to_lowercase();
+
+    let in_cabinet = |name: &Option<String>| {
+        name.as_deref()
+            .is_some_and(|name| name.trim().to_lowercase() == cabinet)
+    };
+
+    let mut name = None;
+    let mut days: BTreeMap<_, Day> = BTreeMap::new();
+
+    // sorted to get the same order of groups regardless of the map order
+    let mut sorted_groups: Vec<&ScheduleEntry> = groups.values().collect();
+    sorted_groups.sort_by(|a, b| a.name.cmp(&b.name));
+
+    for group in sorted_groups {
+        for group_day in &group.days {
+            // groups may have different days, the cabinet has all of them
+            let day = days.entry(group_day.date).or_insert_with(|| Day {
+                name: group_day.name.clone(),
+                street: None,
+                date: group_day.date,
+                lessons: Vec::new(),
+                commutes: Vec::new(),
+                calendar: group_day.calendar.clone(),
+            });
+
+            for group_lesson in &group_day.lessons {
+                if group_lesson.lesson_type == Break {
+                    continue;
+                }
+
+                let subgroups: Vec<_> = group_lesson
+                    .subgroups
+                    .iter()
+                    .flatten()
+                    .map(|subgroup| {
+                        subgroup
+                            .clone()
+                            .filter(|subgroup| in_cabinet(&subgroup.cabinet))
+                    })
+                    .collect();
+
+                let Some(subgroup) = subgroups.iter().flatten().next() else {
+                    continue;
+                };
+
+                name.get_or_insert_with(|| {
+                    subgroup
+                        .cabinet
+                        .as_deref()
+                        .unwrap_or_default()
+                        .trim()
+                        .to_string()
+                });
+
+                let same_lesson = day
+                    .lessons
+                    .iter_mut()
+                    .find(|lesson| is_same_lesson(lesson, group_lesson));
+
+                match same_lesson {
+                    Some(lesson) => merge_lesson(lesson, subgroups, &group.name),
+                    None => day.lessons.push({
+                        let mut lesson = group_lesson.clone();
+                        lesson.subgroups = Some(subgroups);
+                        lesson.groups = vec![group.name.clone()];
+
+                        lesson
+                    }),
+                }
+            }
+        }
+    }
+
+    let mut days: Vec<Day> = days.into_values().collect();
+
+    for day in &mut days {
+        day.lessons.sort_by(|a, b| {
+            a.time
+                .start
+                .cmp(&b.time.start)
+                .then(a.time.end.cmp(&b.time.end))
+        });
+    }
+
+    Some(ScheduleEntry { name: name?, days })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DayCalendar, LessonBoundaries, LessonType};
+    use chrono::{DateTime, TimeDelta};
+
+    fn lesson(name: &str, cabinet: &str, start: i64) -> Lesson {
+        Lesson {
+            lesson_type: LessonType::Default,
+            range: None,
+            name: Some(name.to_string()),
+            subject_id: None,
+            subject: None,
+            time: LessonBoundaries {
+                start: DateTime::default() + TimeDelta::minutes(start),
+                end: DateTime::default() + TimeDelta::minutes(start + 90),
+            },
+            subgroups: Some(vec![
+                Some(LessonSubGroup {
+                    cabinet: Some(cabinet.to_string()),
+                    teacher: Some("Иванов И.И.".to_string()),
+                }),
+                Some(LessonSubGroup {
+                    cabinet: Some("спортзал".to_string()),
+                    teacher: Some("Петров П.П.".to_string()),
+                }),
+            ]),
+            modifiers: Vec::new(),
+            raw: None,
+            building: None,
+            groups: Vec::new(),
+        }
+    }
+
+    fn group(name: &str, lessons: Vec<Lesson>) -> (String, ScheduleEntry) {
+        let entry = ScheduleEntry {
+            name: name.to_string(),
+            days: vec![Day {
+                name: "Понедельник".to_string(),
+                street: None,
+                date: DateTime::default(),
+                lessons,
+                commutes: Vec::new(),
+                calendar: DayCalendar::default(),
+            }],
+        };
+
+        (name.to_string(), entry)
+    }
+
+    #[test]
+    fn cabinet() {
+        let groups = HashMap::from([
+            group(
+                "ИС-224/23",
+                vec![lesson("Физика", "101", 100), lesson("История", "101", 0)],
+            ),
+            group("ИС-214/23", vec![lesson("История", "101", 0)]),
+            group("ИС-234/23", vec![lesson("Химия", "102", 0)]),
+        ]);
+
+        let entry = cabinet_schedule(&groups, " 101 ").unwrap();
+        let lessons = &entry.days[0].lessons;
+
+        assert_eq!(entry.name, "101");
+        assert_eq!(lessons.len(), 2);
+        assert_eq!(lessons[0].name.as_deref(), Some("История"));
+        assert_eq!(lessons[0].groups, ["ИС-214/23", "ИС-224/23"]);
+        assert_eq!(lessons[1].name.as_deref(), Some("Физика"));
+
+        // only the subgroup in the cabinet is kept
+        let subgroups = lessons[0].subgroups.as_ref().unwrap();
+        assert!(subgroups[0].is_some());
+        assert!(subgroups[1].is_none());
+
+        assert!(cabinet_schedule(&groups, "103").is_none());
+    }
+
+    #[test]
+    fn cabinet_double_booked() {
+        let groups = HashMap::from([
+            group("ИС-214/23", vec![lesson("История", "101", 0)]),
+            group("ИС-224/23", vec![lesson("Физика", "101", 0)]),
+        ]);
+
+        let entry = cabinet_schedule(&groups, "101").unwrap();
+        let lessons = &entry.days[0].lessons;
+
+        assert_eq!(lessons.len(), 2);
+        assert_eq!(lessons[0].groups, ["ИС-214/23"]);
+        assert_eq!(lessons[1].groups, ["ИС-224/23"]);
+    }
+
+    #[test]
+    fn cabinet_teachers() {
+        let mut other = lesson("История", "101", 0);
+        other.subgroups.as_mut().unwrap()[0]
+            .as_mut()
+            .unwrap()
+            .teacher = Some("Сидоров С.С.".to_string());
+
+        let groups = HashMap::from([
+            group("ИС-214/23", vec![lesson("История", "101", 0)]),
+            group("ИС-224/23", vec![other]),
+        ]);
+
+        let entry = cabinet_schedule(&groups, "101").unwrap();
+        let lessons = &entry.days[0].lessons;
+
+        assert_eq!(lessons.len(), 1);
+        assert_eq!(lessons[0].groups, ["ИС-214/23", "ИС-224/23"]);
+
+        let teachers: Vec<_> = lessons[0]
+            .subgroups
+            .iter()
+            .flatten()
+            .flatten()
+            .filter_map(|subgroup| subgroup.teacher.as_deref())
+            .collect();
+
+        assert_eq!(teachers, ["Иванов И.И.", "Сидоров С.С."]);
+    }
+
+    #[test]
+    fn cabinet_name() {
+        let groups = HashMap::from([group("ИС-214/23", vec![lesson("История", "к/101А", 0)])]);
+
+        let entry = cabinet_schedule(&groups, "К/101а").unwrap();
+
+        assert_eq!(entry.name, "к/101А");
+    }
+
+    #[test]
+    fn cabinet_days() {
+        let (name, mut other) = group("ИС-224/23", vec![lesson("Физика", "101", 0)]);
+        other.days[0].name = "Вторник".to_string();
+        other.days[0].date += TimeDelta::days(1);
+
+        let groups = HashMap::from([
+            group("ИС-214/23", vec![lesson("История", "101", 0)]),
+            (name, other),
+        ]);
+
+        let entry = cabinet_schedule(&groups, "101").unwrap();
+
+        assert_eq!(entry.days.len(), 2);
+        assert_eq!(entry.days[0].name, "Понедельник");
+        assert_eq!(entry.days[0].lessons[0].name.as_deref(), Some("История"));
+        assert_eq!(entry.days[1].name, "Вторник");
+        assert_eq!(entry.days[1].lessons[0].name.as_deref(), Some("Физика"));
+    }
+
+    #[test]
+    fn cabinet_subgroups() {
+        let mut first = lesson("Информатика", "101", 0);
+        let mut second = first.clone();
+
+        first.subgroups.as_mut().unwrap()[1] = None;
+        second.subgroups.as_mut().unwrap()[0] = None;
+        second.subgroups.as_mut().unwrap()[1]
+            .as_mut()
+            .unwrap()
+            .cabinet = Some("101".to_string());
+
+        let groups = HashMap::from([group("ИС-214/23", vec![first, second])]);
+
+        let entry = cabinet_schedule(&groups, "101").unwrap();
+        let lessons = &entry.days[0].lessons;
+
+        assert_eq!(lessons.len(), 1);
+        assert_eq!(lessons[0].groups, ["ИС-214/23"]);
+
+        let subgroups = lessons[0].subgroups.as_ref().unwrap();
+        assert_eq!(
+            subgroups[0].as_ref().unwrap().teacher.as_deref(),
+            Some("Иванов И.И.")
+        );
+        assert_eq!(
+            subgroups[1].as_ref().unwrap().teacher.as_deref(),
+            Some("Петров П.П.")
+        );
+    }
+}
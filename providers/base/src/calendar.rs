@@ -0,0 +1,350 @@
+use crate::{Diagnostic, DiagnosticKind, LessonType, ScheduleEntry};
+use chrono::{Datelike, NaiveDate};
+use chrono_tz::Tz;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use utoipa::ToSchema;
+
+/// Kind of the academic calendar period.
+#[derive(Clone, Copy, Hash, Debug, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CalendarPeriodKind {
+    /// Studying period, weeks are counted from its beginning.
+    Semester,
+
+    /// Days without lessons.
+    Holiday,
+
+    /// Industrial or educational practice.
+    Practice,
+}
+
+/// Parity of the study week.
+#[derive(Clone, Copy, Hash, Debug, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum WeekParity {
+    /// Числитель.
+    Numerator,
+
+    /// Знаменатель.
+    Denominator,
+}
+
+impl WeekParity {
+    /// The other parity.
+    pub fn opposite(self) -> Self {
+        match self {
+            WeekParity::Numerator => WeekParity::Denominator,
+            WeekParity::Denominator => WeekParity::Numerator,
+        }
+    }
+}
+
+/// Period of the academic calendar.
+#[derive(Clone, Hash, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct CalendarPeriod {
+    /// Kind of the period.
+    pub kind: CalendarPeriodKind,
+
+    /// Name of the period.
+    #[schema(examples("Осенний семестр"))]
+    pub name: String,
+
+    /// First day of the period.
+    pub start: NaiveDate,
+
+    /// Last day of the period, inclusive.
+    pub end: NaiveDate,
+
+    /// Parity of the first week of the semester.
+    ///
+    /// Only used for semesters, numerator if not specified.
+    pub first_week: Option<WeekParity>,
+}
+
+impl CalendarPeriod {
+    pub fn contains(&self, date: NaiveDate) -> bool {
+        self.start <= date && date <= self.end
+    }
+}
+
+/// Calendar annotations of a single day.
+#[derive(Clone, Hash, Debug, Default, PartialEq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct DayCalendar {
+    /// Name of the semester the day belongs to.
+    pub semester: Option<String>,
+
+    /// Parity of the week within the semester.
+    pub week_parity: Option<WeekParity>,
+
+    /// Name of the holiday, if there are no lessons on this day.
+    pub holiday: Option<String>,
+
+    /// Name of the practice taking place on this day.
+    pub practice: Option<String>,
+}
+
+/// Semesters, holidays and practices of the provider.
+#[derive(Clone, Hash, Debug, Default, Serialize, Deserialize, ToSchema)]
+pub struct AcademicCalendar {
+    /// Periods sorted by their beginning.
+    pub periods: Vec<CalendarPeriod>,
+}
+
+impl AcademicCalendar {
+    pub fn new(mut periods: Vec<CalendarPeriod>) -> Self {
+        periods.sort_by_key(|period| (period.start, period.end));
+
+        Self { periods }
+    }
+
+    fn find(&self, kind: CalendarPeriodKind, date: NaiveDate) -> Option<&CalendarPeriod> {
+        self.periods
+            .iter()
+            .find(|period| period.kind == kind && period.contains(date))
+    }
+
+    /// Annotations of the day with the specified date.
+    pub fn day(&self, date: NaiveDate) -> DayCalendar {
+        let semester = self.find(CalendarPeriodKind::Semester, date);
+
+        let week_parity = semester.map(|semester| {
+            let first_week = semester.first_week.unwrap_or(WeekParity::Numerator);
+
+            let monday = |date: NaiveDate| {
+                date - chrono::Days::new(date.weekday().num_days_from_monday() as u64)
+            };
+
+            let weeks = (monday(date) - monday(semester.start)).num_weeks();
+
+            if weeks % 2 == 0 {
+                first_week
+            } else {
+                first_week.opposite()
+            }
+        });
+
+        DayCalendar {
+            semester: semester.map(|period| period.name.clone()),
+            week_parity,
+            holiday: self
+                .find(CalendarPeriodKind::Holiday, date)
+                .map(|period| period.name.clone()),
+            practice: self
+                .find(CalendarPeriodKind::Practice, date)
+                .map(|period| period.name.clone()),
+        }
+    }
+}
+
+/// Sets [`crate::Day::calendar`] of every day of the entries.
+///
+/// Days of the groups that have lessons on holidays or outside of the semesters are reported,
+/// because the schedule probably lists lessons that won't take place.
+pub(crate) fn annotate_days(
+    groups: &mut HashMap<String, ScheduleEntry>,
+    teachers: &mut HashMap<String, ScheduleEntry>,
+    calendar: &AcademicCalendar,
+    timezone: Tz,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let has_semesters = calendar
+        .periods
+        .iter()
+        .any(|period| period.kind == CalendarPeriodKind::Semester);
+
+    let mut reported: Vec<NaiveDate> = Vec::new();
+
+    let mut days: Vec<_> = groups
+        .values_mut()
+        .flat_map(|group| &mut group.days)
+        .collect();
+    days.sort_by_key(|day| day.date);
+
+    for day in days {
+        let date = day.date.with_timezone(&timezone).date_naive();
+
+        day.calendar = calendar.day(date);
+
+        let has_lessons = day
+            .lessons
+            .iter()
+            .any(|lesson| lesson.lesson_type != LessonType::Break);
+
+        if !has_lessons || reported.contains(&date) {
+            continue;
+        }
+
+        let message = if let Some(holiday) = &day.calendar.holiday {
+            format!("Lessons are listed on {date}, which is a holiday \"{holiday}\".")
+        } else if has_semesters && day.calendar.semester.is_none() {
+            format!("Lessons are listed on {date}, which is outside of any semester.")
+        } else {
+            continue;
+        };
+
+        reported.push(date);
+
+        diagnostics.push(Diagnostic {
+            kind: DiagnosticKind::CalendarMismatch,
+            message,
+        });
+    }
+
+    for day in teachers.values_mut().flat_map(|teacher| &mut teacher.days) {
+        day.calendar = calendar.day(day.date.with_timezone(&timezone).date_naive());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Day, Lesson, LessonBoundaries};
+    use chrono::{DateTime, TimeZone};
+
+    fn date(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+    }
+
+    fn period(
+        kind: CalendarPeriodKind,
+        name: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> CalendarPeriod {
+        CalendarPeriod {
+            kind,
+            name: name.to_string(),
+            start,
+            end,
+            first_week: None,
+        }
+    }
+
+    #[test]
+    fn day() {
+        let mut semester = period(
+            CalendarPeriodKind::Semester,
+            "Осенний семестр",
+            date(9, 1),
+            date(12, 28),
+        );
+        semester.first_week = Some(WeekParity::Denominator);
+
+        let calendar = AcademicCalendar::new(vec![
+            period(
+                CalendarPeriodKind::Holiday,
+                "День народного единства",
+                date(11, 4),
+                date(11, 4),
+            ),
+            semester,
+            period(
+                CalendarPeriodKind::Practice,
+                "Учебная практика",
+                date(12, 15),
+                date(12, 28),
+            ),
+        ]);
+
+        // Wednesday of the first week
+        let first = calendar.day(date(9, 3));
+        assert_eq!(first.semester.as_deref(), Some("Осенний семестр"));
+        assert_eq!(first.week_parity, Some(WeekParity::Denominator));
+        assert_eq!(first.holiday, None);
+
+        // Monday of the second week
+        assert_eq!(
+            calendar.day(date(9, 8)).week_parity,
+            Some(WeekParity::Numerator)
+        );
+
+        let holiday = calendar.day(date(11, 4));
+        assert_eq!(holiday.holiday.as_deref(), Some("День народного единства"));
+
+        let practice = calendar.day(date(12, 16));
+        assert_eq!(practice.practice.as_deref(), Some("Учебная практика"));
+
+        assert_eq!(calendar.day(date(8, 31)), DayCalendar::default());
+    }
+
+    #[test]
+    fn lessons_on_holiday() {
+        let calendar = AcademicCalendar::new(vec![
+            period(
+                CalendarPeriodKind::Semester,
+                "Осенний семестр",
+                date(9, 1),
+                date(12, 28),
+            ),
+            period(
+                CalendarPeriodKind::Holiday,
+                "День народного единства",
+                date(11, 4),
+                date(11, 4),
+            ),
+        ]);
+
+        let timezone = chrono_tz::Europe::Saratov;
+
+        let day = |month: u32, day: u32| Day {
+            name: "Вторник".to_string(),
+            street: None,
+            date: timezone
+                .from_local_datetime(&date(month, day).and_hms_opt(0, 0, 0).unwrap())
+                .unwrap()
+                .to_utc(),
+            lessons: vec![Lesson {
+                lesson_type: LessonType::Default,
+                range: Some([1, 1]),
+                name: Some("История".to_string()),
+                subject_id: None,
+                subject: None,
+                time: LessonBoundaries {
+                    start: DateTime::default(),
+                    end: DateTime::default(),
+                },
+                subgroups: None,
+                modifiers: Vec::new(),
+                raw: None,
+                building: None,
+                groups: Vec::new(),
+            }],
+            commutes: Vec::new(),
+            calendar: DayCalendar::default(),
+        };
+
+        let entry = |name: &str| ScheduleEntry {
+            name: name.to_string(),
+            days: vec![day(11, 3), day(11, 4), day(12, 30)],
+        };
+
+        let mut groups = HashMap::from([
+            ("ИС-214/23".to_string(), entry("ИС-214/23")),
+            ("ИС-224/23".to_string(), entry("ИС-224/23")),
+        ]);
+        let mut diagnostics = Vec::new();
+
+        annotate_days(
+            &mut groups,
+            &mut HashMap::new(),
+            &calendar,
+            timezone,
+            &mut diagnostics,
+        );
+
+        let days = &groups["ИС-214/23"].days;
+        assert_eq!(days[0].calendar.holiday, None);
+        assert_eq!(
+            days[1].calendar.holiday.as_deref(),
+            Some("День народного единства")
+        );
+
+        // one diagnostic per date, not per group
+        assert_eq!(diagnostics.len(), 2);
+        assert!(diagnostics[0].message.contains("2025-11-04"));
+        assert!(diagnostics[1].message.contains("2025-12-30"));
+    }
+}
@@ -0,0 +1,386 @@
+use crate::LessonType::Break;
+use crate::teachers::SCHEDULE_ERROR_TEACHER;
+use crate::{Lesson, LessonBoundaries, ParsedSchedule, ProviderSettings};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use utoipa::ToSchema;
+
+/// Kind of the scheduling conflict.
+#[derive(Clone, Hash, PartialEq, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ConflictKind {
+    /// Teacher has different lessons at the same time.
+    TeacherDoubleBooked,
+
+    /// Different lessons take place in the same cabinet at the same time.
+    CabinetDoubleBooked,
+
+    /// Group has two lessons at the same time.
+    GroupDoubleBooked,
+
+    /// Lesson starts before the beginning or ends after the end of the school day.
+    OutsideDayBoundaries,
+}
+
+/// Error in the schedule that the parser can't detect by looking at a single cell.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct Conflict {
+    /// Kind of the conflict.
+    pub kind: ConflictKind,
+
+    /// Name of the day.
+    pub day: String,
+
+    /// Teacher, cabinet or group the conflict is about.
+    pub subject: String,
+
+    /// Groups whose lessons are involved.
+    pub groups: Vec<String>,
+
+    /// Human-readable description.
+    pub message: String,
+}
+
+/// Lesson of the group occupying a teacher or a cabinet.
+struct Occupation<'a> {
+    group: &'a str,
+    lesson: &'a Lesson,
+}
+
+impl Occupation<'_> {
+    fn describe(&self) -> String {
+        format!(
+            "\"{}\" ({})",
+            self.lesson.name.as_deref().unwrap_or_default(),
+            self.group
+        )
+    }
+}
+
+/// Adds the lesson to the list unless it is already there.
+///
+/// Both subgroups of the lesson can have the same teacher or cabinet.
+fn occupy<'a>(occupations: &mut Vec<Occupation<'a>>, occupation: Occupation<'a>) {
+    if !occupations
+        .last()
+        .is_some_and(|last| std::ptr::eq(last.lesson, occupation.lesson))
+    {
+        occupations.push(occupation);
+    }
+}
+
+fn overlaps(a: &LessonBoundaries, b: &LessonBoundaries) -> bool {
+    a.start < b.end && b.start < a.end
+}
+
+/// Whether two lessons are the same lesson of a stream of groups.
+fn is_joint(a: &Lesson, b: &Lesson) -> bool {
+    a.name == b.name && a.time.start == b.time.start && a.time.end == b.time.end
+}
+
+/// Reports every pair of overlapping lessons of the teacher or the cabinet.
+fn find_double_booked(
+    kind: ConflictKind,
+    day: &str,
+    subject: &str,
+    occupations: &[Occupation],
+    conflicts: &mut Vec<Conflict>,
+) {
+    for (index, a) in occupations.iter().enumerate() {
+        for b in &occupations[index + 1..] {
+            if !overlaps(&a.lesson.time, &b.lesson.time) || is_joint(a.lesson, b.lesson) {
+                continue;
+            }
+
+            let what = match kind {
+                ConflictKind::TeacherDoubleBooked => "Teacher",
+                _ => "Cabinet",
+            };
+
+            conflicts.push(Conflict {
+                kind: kind.clone(),
+                day: day.to_string(),
+                subject: subject.to_string(),
+                groups: vec![a.group.to_string(), b.group.to_string()],
+                message: format!(
+                    "{what} \"{subject}\" has lessons {} and {} at the same time on {day}.",
+                    a.describe(),
+                    b.describe()
+                ),
+            });
+        }
+    }
+}
+
+/// Finds double-booked teachers, cabinets and groups and lessons outside the day boundaries.
+///
+/// The bell schedule is parsed from the same cells as the lessons, so the boundaries
+/// are taken from [`ProviderSettings::day_start`] and [`ProviderSettings::day_end`].
+pub fn find_conflicts(schedule: &ParsedSchedule, settings: &ProviderSettings) -> Vec<Conflict> {
+    let mut conflicts = Vec::new();
+
+    // BTreeMap to keep the order of conflicts stable
+    let groups: BTreeMap<_, _> = schedule.groups.iter().collect();
+
+    let days_count = groups
+        .values()
+        .map(|group| group.days.len())
+        .max()
+        .unwrap_or_default();
+
+    for day_index in 0..days_count {
+        let mut teachers: BTreeMap<&str, Vec<Occupation>> = BTreeMap::new();
+        let mut cabinets: BTreeMap<&str, Vec<Occupation>> = BTreeMap::new();
+        let mut day_name = None;
+
+        for group in groups.values() {
+            let Some(day) = group.days.get(day_index) else {
+                continue;
+            };
+
+            day_name.get_or_insert(day.name.as_str());
+
+            let lessons: Vec<&Lesson> = day
+                .lessons
+                .iter()
+                .filter(|lesson| lesson.lesson_type != Break)
+                .collect();
+
+            for (index, a) in lessons.iter().enumerate() {
+                for b in &lessons[index + 1..] {
+                    if overlaps(&a.time, &b.time) {
+                        conflicts.push(Conflict {
+                            kind: ConflictKind::GroupDoubleBooked,
+                            day: day.name.clone(),
+                            subject: group.name.clone(),
+                            groups: vec![group.name.clone()],
+                            message: format!(
+                                "Group \"{}\" has lessons \"{}\" and \"{}\" at the same time on {}.",
+                                group.name,
+                                a.name.as_deref().unwrap_or_default(),
+                                b.name.as_deref().unwrap_or_default(),
+                                day.name
+                            ),
+                        });
+                    }
+                }
+            }
+
+            let outside = lessons.iter().filter(|lesson| {
+                let start = lesson.time.start.with_timezone(&settings.timezone);
+                let end = lesson.time.end.with_timezone(&settings.timezone);
+
+                start.time() < settings.day_start
+                    || end.time() > settings.day_end
+                    || start.date_naive() != end.date_naive()
+            });
+
+            for lesson in outside {
+                conflicts.push(Conflict {
+                    kind: ConflictKind::OutsideDayBoundaries,
+                    day: day.name.clone(),
+                    subject: group.name.clone(),
+                    groups: vec![group.name.clone()],
+                    message: format!(
+                        "Lesson \"{}\" of group \"{}\" is outside the school day on {}.",
+                        lesson.name.as_deref().unwrap_or_default(),
+                        group.name,
+                        day.name
+                    ),
+                });
+            }
+
+            for lesson in lessons {
+                let occupation = || Occupation {
+                    group: &group.name,
+                    lesson,
+                };
+
+                for subgroup in lesson.subgroups.iter().flatten().flatten() {
+                    if let Some(teacher) = &subgroup.teacher
+                        && teacher != SCHEDULE_ERROR_TEACHER
+                    {
+                        occupy(teachers.entry(teacher).or_default(), occupation());
+                    }
+
+                    if let Some(cabinet) = &subgroup.cabinet {
+                        occupy(cabinets.entry(cabinet).or_default(), occupation());
+                    }
+                }
+            }
+        }
+
+        let day_name = day_name.unwrap_or_default();
+
+        for (teacher, occupations) in teachers {
+            find_double_booked(
+                ConflictKind::TeacherDoubleBooked,
+                day_name,
+                teacher,
+                &occupations,
+                &mut conflicts,
+            );
+        }
+
+        for (cabinet, occupations) in cabinets {
+            find_double_booked(
+                ConflictKind::CabinetDoubleBooked,
+                day_name,
+                cabinet,
+                &occupations,
+                &mut conflicts,
+            );
+        }
+    }
+
+    conflicts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AcademicCalendar, Day, DayCalendar, LessonSubGroup, LessonType, ScheduleEntry};
+    use chrono::{DateTime, NaiveTime, TimeDelta};
+    use std::collections::HashMap;
+
+    /// Boundaries in minutes from 08:00 UTC.
+    fn time(start: i64, end: i64) -> LessonBoundaries {
+        let day_start = DateTime::default() + TimeDelta::hours(8);
+
+        LessonBoundaries {
+            start: day_start + TimeDelta::minutes(start),
+            end: day_start + TimeDelta::minutes(end),
+        }
+    }
+
+    fn lesson(name: &str, teacher: &str, cabinet: &str, start: i64, end: i64) -> Lesson {
+        Lesson {
+            lesson_type: LessonType::Default,
+            range: Some([1, 1]),
+            name: Some(name.to_string()),
+            subject_id: None,
+            subject: None,
+            time: time(start, end),
+            subgroups: Some(vec![Some(LessonSubGroup {
+                cabinet: Some(cabinet.to_string()),
+                teacher: Some(teacher.to_string()),
+            })]),
+            modifiers: Vec::new(),
+            raw: None,
+            building: None,
+            groups: Vec::new(),
+        }
+    }
+
+    fn group(name: &str, lessons: Vec<Lesson>) -> (String, ScheduleEntry) {
+        let entry = ScheduleEntry {
+            name: name.to_string(),
+            days: vec![Day {
+                name: "Понедельник".to_string(),
+                street: None,
+                date: DateTime::default(),
+                lessons,
+                commutes: Vec::new(),
+                calendar: DayCalendar::default(),
+            }],
+        };
+
+        (name.to_string(), entry)
+    }
+
+    fn kinds(groups: Vec<(String, ScheduleEntry)>) -> Vec<ConflictKind> {
+        let schedule = ParsedSchedule {
+            groups: HashMap::from_iter(groups),
+            teachers: HashMap::new(),
+            subjects: HashMap::new(),
+            group_info: HashMap::new(),
+            bells: Vec::new(),
+            calendar: AcademicCalendar::default(),
+            diagnostics: Vec::new(),
+        };
+
+        let settings = ProviderSettings {
+            timezone: chrono_tz::UTC,
+            day_start: NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+            day_end: NaiveTime::from_hms_opt(14, 40, 0).unwrap(),
+            ..ProviderSettings::default()
+        };
+
+        find_conflicts(&schedule, &settings)
+            .into_iter()
+            .map(|conflict| conflict.kind)
+            .collect()
+    }
+
+    #[test]
+    fn joint_lesson_is_not_conflict() {
+        let conflicts = kinds(vec![
+            group(
+                "ИС-214/23",
+                vec![lesson("История", "Иванов И.И.", "101", 0, 90)],
+            ),
+            group(
+                "ИС-224/23",
+                vec![lesson("История", "Иванов И.И.", "101", 0, 90)],
+            ),
+        ]);
+
+        assert!(conflicts.is_empty());
+    }
+
+    #[test]
+    fn double_booked() {
+        let conflicts = kinds(vec![
+            group(
+                "ИС-214/23",
+                vec![lesson("История", "Иванов И.И.", "101", 0, 90)],
+            ),
+            group(
+                "ИС-224/23",
+                vec![lesson("Право", "Иванов И.И.", "102", 60, 150)],
+            ),
+            group(
+                "ИС-234/23",
+                vec![lesson("Физика", "Петров П.П.", "101", 30, 120)],
+            ),
+        ]);
+
+        assert_eq!(
+            conflicts,
+            [
+                ConflictKind::TeacherDoubleBooked,
+                ConflictKind::CabinetDoubleBooked,
+            ]
+        );
+
+        let conflicts = kinds(vec![group(
+            "ИС-214/23",
+            vec![
+                lesson("История", "Иванов И.И.", "101", 0, 90),
+                lesson("Право", "Петров П.П.", "102", 0, 90),
+            ],
+        )]);
+
+        assert_eq!(conflicts, [ConflictKind::GroupDoubleBooked]);
+    }
+
+    #[test]
+    fn outside_day_boundaries() {
+        let conflicts = kinds(vec![group(
+            "ИС-214/23",
+            vec![
+                lesson("История", "Иванов И.И.", "101", -30, 60),
+                lesson("Право", "Иванов И.И.", "101", 90, 180),
+                lesson("Физика", "Иванов И.И.", "101", 360, 450),
+            ],
+        )]);
+
+        assert_eq!(
+            conflicts,
+            [
+                ConflictKind::OutsideDayBoundaries,
+                ConflictKind::OutsideDayBoundaries,
+            ]
+        );
+    }
+}
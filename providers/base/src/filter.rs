@@ -0,0 +1,365 @@
+use crate::teachers::insert_breaks;
+use crate::{Lesson, LessonType, ScheduleEntry};
+use chrono::{Datelike, NaiveDate, Weekday};
+use chrono_tz::Tz;
+
+/// Selection of the days and lessons of a schedule entry.
+///
+/// All specified conditions must hold for the day to be kept.
+#[derive(Clone, Debug, Default)]
+pub struct DayFilter {
+    /// The first date to keep, inclusive.
+    pub from: Option<NaiveDate>,
+
+    /// The last date to keep, inclusive.
+    pub to: Option<NaiveDate>,
+
+    /// Day of the week to keep.
+    pub weekday: Option<Weekday>,
+
+    /// Remove [`LessonType::Break`] pseudo-lessons.
+    pub skip_breaks: bool,
+
+    /// Number of the subgroup, starting from 1.
+    ///
+    /// Split lessons keep only the teacher and the cabinet of this subgroup,
+    /// lessons of the other subgroup only are removed.
+    pub subgroup: Option<u8>,
+}
+
+/// Lesson as seen by the subgroup, `None` if the subgroup doesn't attend it.
+fn select_subgroup(mut lesson: Lesson, subgroup: u8) -> Option<Lesson> {
+    let Some(subgroups) = &lesson.subgroups else {
+        return Some(lesson);
+    };
+
+    // shared by the whole group
+    if subgroups.len() < 2 {
+        return Some(lesson);
+    }
+
+    match subgroups.get(usize::from(subgroup).checked_sub(1)?) {
+        None => Some(lesson),
+        Some(None) => None,
+        Some(Some(own)) => {
+            lesson.subgroups = Some(vec![Some(own.clone())]);
+
+            Some(lesson)
+        }
+    }
+}
+
+impl DayFilter {
+    /// Keeps only the day with the specified date.
+    pub fn date(mut self, date: NaiveDate) -> Self {
+        self.from = Some(self.from.map_or(date, |from| from.max(date)));
+        self.to = Some(self.to.map_or(date, |to| to.min(date)));
+        self
+    }
+
+    /// Copy of the entry with the selected days and lessons.
+    ///
+    /// Dates of the days are compared in the provider timezone.
+    pub fn apply(&self, entry: &ScheduleEntry, timezone: Tz) -> ScheduleEntry {
+        let days = entry
+            .days
+            .iter()
+            .filter(|day| {
+                let date = day.date.with_timezone(&timezone).date_naive();
+
+                self.from.is_none_or(|from| from <= date)
+                    && self.to.is_none_or(|to| date <= to)
+                    && self.weekday.is_none_or(|weekday| date.weekday() == weekday)
+            })
+            .map(|day| {
+                let mut day = day.clone();
+
+                if self.skip_breaks {
+                    day.lessons
+                        .retain(|lesson| lesson.lesson_type != LessonType::Break);
+                }
+
+                if let Some(subgroup) = self.subgroup {
+                    day.lessons = day
+                        .lessons
+                        .into_iter()
+                        .filter(|lesson| lesson.lesson_type != LessonType::Break)
+                        .filter_map(|lesson| select_subgroup(lesson, subgroup))
+                        .collect();
+
+                    // breaks around the removed lessons of the other subgroup would be left hanging
+                    if !self.skip_breaks {
+                        insert_breaks(&mut day);
+                    }
+                }
+
+                day
+            })
+            .collect();
+
+        ScheduleEntry {
+            name: entry.name.clone(),
+            days,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Day, DayCalendar, LessonBoundaries, LessonSubGroup};
+    use chrono::{DateTime, TimeDelta, TimeZone};
+
+    const TIMEZONE: Tz = chrono_tz::Europe::Saratov;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 9, day).unwrap()
+    }
+
+    fn lesson(lesson_type: LessonType) -> Lesson {
+        Lesson {
+            lesson_type,
+            range: None,
+            name: None,
+            subject_id: None,
+            subject: None,
+            time: LessonBoundaries {
+                start: DateTime::default(),
+                end: DateTime::default(),
+            },
+            subgroups: None,
+            modifiers: Vec::new(),
+            raw: None,
+            building: None,
+            groups: Vec::new(),
+        }
+    }
+
+    fn at(mut lesson: Lesson, start: i64, end: i64) -> Lesson {
+        lesson.time = LessonBoundaries {
+            start: DateTime::default() + TimeDelta::minutes(start),
+            end: DateTime::default() + TimeDelta::minutes(end),
+        };
+
+        lesson
+    }
+
+    /// Monday, 1 September 2025 to Saturday, 6 September 2025.
+    fn entry() -> ScheduleEntry {
+        let days = (1..=6)
+            .map(|day| Day {
+                name: String::new(),
+                street: None,
+                // midnight in Saratov is the previous day in UTC
+                date: TIMEZONE
+                    .from_local_datetime(&date(day).and_hms_opt(0, 0, 0).unwrap())
+                    .unwrap()
+                    .to_utc(),
+                lessons: vec![lesson(LessonType::Default), lesson(LessonType::Break)],
+                commutes: Vec::new(),
+                calendar: DayCalendar::default(),
+            })
+            .collect();
+
+        ScheduleEntry {
+            name: "ИС-214/23".to_string(),
+            days,
+        }
+    }
+
+    fn dates(entry: &ScheduleEntry) -> Vec<u32> {
+        entry
+            .days
+            .iter()
+            .map(|day| day.date.with_timezone(&TIMEZONE).day())
+            .collect()
+    }
+
+    #[test]
+    fn filter() {
+        let entry = entry();
+
+        let all = DayFilter::default().apply(&entry, TIMEZONE);
+        assert_eq!(dates(&all), [1, 2, 3, 4, 5, 6]);
+        assert_eq!(all.days[0].lessons.len(), 2);
+
+        let single = DayFilter::default().date(date(3)).apply(&entry, TIMEZONE);
+        assert_eq!(dates(&single), [3]);
+
+        let range = DayFilter {
+            from: Some(date(2)),
+            to: Some(date(4)),
+            ..Default::default()
+        };
+        assert_eq!(dates(&range.apply(&entry, TIMEZONE)), [2, 3, 4]);
+
+        let friday = DayFilter {
+            weekday: Some(Weekday::Fri),
+            skip_breaks: true,
+            ..Default::default()
+        }
+        .apply(&entry, TIMEZONE);
+        assert_eq!(dates(&friday), [5]);
+        assert_eq!(friday.days[0].lessons.len(), 1);
+
+        let outside = DayFilter::default().date(date(7)).apply(&entry, TIMEZONE);
+        assert!(outside.days.is_empty());
+    }
+
+    #[test]
+    fn subgroup() {
+        let teacher = |name: &str, cabinet: &str| {
+            Some(LessonSubGroup {
+                cabinet: Some(cabinet.to_string()),
+                teacher: Some(name.to_string()),
+            })
+        };
+
+        let mut shared = lesson(LessonType::Default);
+        shared.subgroups = Some(vec![teacher("Иванов И.И.", "42")]);
+
+        let mut split = lesson(LessonType::Default);
+        split.subgroups = Some(vec![
+            teacher("Иванов И.И.", "42"),
+            teacher("Петров П.П.", "43"),
+        ]);
+
+        let mut second_only = lesson(LessonType::Default);
+        second_only.subgroups = Some(vec![None, teacher("Петров П.П.", "43")]);
+
+        let mut entry = entry();
+        entry.days[0].lessons = vec![
+            at(shared, 0, 90),
+            at(lesson(LessonType::Break), 90, 100),
+            at(split, 100, 190),
+            at(second_only, 190, 280),
+        ];
+
+        let teachers = |entry: &ScheduleEntry| -> Vec<Option<String>> {
+            entry.days[0]
+                .lessons
+                .iter()
+                .map(|lesson| {
+                    (lesson.lesson_type != LessonType::Break).then(|| {
+                        let subgroups = lesson.subgroups.as_deref().unwrap_or_default();
+
+                        subgroups
+                            .iter()
+                            .flatten()
+                            .filter_map(|subgroup| subgroup.teacher.clone())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    })
+                })
+                .collect()
+        };
+
+        let first = DayFilter {
+            subgroup: Some(1),
+            ..Default::default()
+        }
+        .apply(&entry, TIMEZONE);
+        assert_eq!(
+            teachers(&first),
+            [
+                Some("Иванов И.И.".to_string()),
+                None,
+                Some("Иванов И.И.".to_string()),
+            ]
+        );
+
+        let second = DayFilter {
+            subgroup: Some(2),
+            ..Default::default()
+        }
+        .apply(&entry, TIMEZONE);
+        assert_eq!(
+            teachers(&second),
+            [
+                Some("Иванов И.И.".to_string()),
+                None,
+                Some("Петров П.П.".to_string()),
+                Some("Петров П.П.".to_string()),
+            ]
+        );
+        assert_eq!(
+            second.days[0].lessons[2].subgroups.as_ref().unwrap()[0]
+                .as_ref()
+                .unwrap()
+                .cabinet
+                .as_deref(),
+            Some("43")
+        );
+    }
+
+    #[test]
+    fn subgroup_breaks() {
+        let mut shared = lesson(LessonType::Default);
+        shared.subgroups = Some(vec![Some(LessonSubGroup {
+            cabinet: Some("42".to_string()),
+            teacher: Some("Иванов И.И.".to_string()),
+        })]);
+
+        let mut second_only = lesson(LessonType::Default);
+        second_only.subgroups = Some(vec![
+            None,
+            Some(LessonSubGroup {
+                cabinet: Some("43".to_string()),
+                teacher: Some("Петров П.П.".to_string()),
+            }),
+        ]);
+
+        let mut entry = entry();
+        entry.days[0].lessons = vec![
+            at(lesson(LessonType::Break), 0, 10),
+            at(second_only.clone(), 10, 100),
+            at(lesson(LessonType::Break), 100, 110),
+            at(shared.clone(), 110, 200),
+            at(lesson(LessonType::Break), 200, 210),
+            at(second_only.clone(), 210, 300),
+            at(lesson(LessonType::Break), 300, 310),
+            at(shared, 310, 400),
+            at(lesson(LessonType::Break), 400, 410),
+            at(second_only, 410, 500),
+        ];
+
+        let first = DayFilter {
+            subgroup: Some(1),
+            ..Default::default()
+        }
+        .apply(&entry, TIMEZONE);
+
+        let lessons: Vec<_> = first.days[0]
+            .lessons
+            .iter()
+            .map(|lesson| {
+                (
+                    lesson.lesson_type.clone(),
+                    lesson.time.start,
+                    lesson.time.end,
+                )
+            })
+            .collect();
+
+        let at = |minutes| DateTime::default() + TimeDelta::minutes(minutes);
+
+        // no leading, trailing or back-to-back breaks
+        assert_eq!(
+            lessons,
+            [
+                (LessonType::Default, at(110), at(200)),
+                (LessonType::Break, at(200), at(310)),
+                (LessonType::Default, at(310), at(400)),
+            ]
+        );
+
+        let skipped = DayFilter {
+            subgroup: Some(1),
+            skip_breaks: true,
+            ..Default::default()
+        }
+        .apply(&entry, TIMEZONE);
+
+        assert_eq!(skipped.days[0].lessons.len(), 2);
+    }
+}
@@ -0,0 +1,390 @@
+use crate::{AcademicCalendar, ProviderSettings, ScheduleAliases, ScheduleSnapshot, postprocess};
+use std::collections::VecDeque;
+use std::sync::Arc;
+
+/// Errors of manual snapshot management.
+#[derive(Clone, Debug, PartialEq)]
+pub enum HistoryError {
+    /// There is no snapshot with the requested hash in the history.
+    NotFound,
+
+    /// The active snapshot is the oldest one, there is nothing to roll back to.
+    NoPrevious,
+}
+
+/// Snapshot stored in the history.
+#[derive(Clone)]
+pub struct SnapshotEntry {
+    /// Hash of the snapshot with applied corrections (see [`ScheduleSnapshot::hash`]).
+    ///
+    /// Changes when the corrections or the calendar change, so clients refetch the schedule.
+    pub hash: String,
+
+    /// The snapshot with applied corrections.
+    pub snapshot: Arc<ScheduleSnapshot>,
+
+    /// Whether this snapshot is currently served to clients.
+    pub active: bool,
+
+    /// Whether this snapshot is pinned as active.
+    pub pinned: bool,
+}
+
+struct Entry {
+    /// Hash of the snapshot as it was parsed, identifies the entry.
+    hash: String,
+
+    /// The snapshot as it was parsed.
+    raw: Arc<ScheduleSnapshot>,
+
+    /// The snapshot with applied corrections.
+    processed: Arc<ScheduleSnapshot>,
+}
+
+/// Bounded list of recently parsed snapshots with one of them marked as active.
+///
+/// Snapshots with the same hash are stored only once, a repeated snapshot replaces
+/// the stored copy and becomes the newest one.
+///
+/// Snapshots are stored as they were parsed, so changed [`ScheduleAliases`] can be re-applied to them.
+pub struct SnapshotHistory {
+    /// Stored snapshots, from the oldest to the newest.
+    entries: VecDeque<Entry>,
+
+    /// Hash of the active snapshot as it was parsed.
+    active: String,
+
+    /// If the active snapshot is pinned, new snapshots don't replace it.
+    pinned: bool,
+
+    /// Maximum number of stored snapshots.
+    capacity: usize,
+
+    /// Corrections applied to the stored snapshots.
+    aliases: ScheduleAliases,
+
+    /// Academic calendar the stored snapshots are annotated with.
+    calendar: AcademicCalendar,
+
+    /// Settings of the provider the snapshots belong to.
+    settings: ProviderSettings,
+}
+
+impl SnapshotHistory {
+    pub fn new(snapshot: ScheduleSnapshot, capacity: usize, settings: ProviderSettings) -> Self {
+        let aliases = ScheduleAliases::default();
+        let calendar = AcademicCalendar::default();
+        let entry = Self::entry(snapshot, &aliases, &calendar, &settings);
+
+        Self {
+            active: entry.hash.clone(),
+            entries: VecDeque::from([entry]),
+            pinned: false,
+            capacity: capacity.max(1),
+            aliases,
+            calendar,
+            settings,
+        }
+    }
+
+    fn entry(
+        snapshot: ScheduleSnapshot,
+        aliases: &ScheduleAliases,
+        calendar: &AcademicCalendar,
+        settings: &ProviderSettings,
+    ) -> Entry {
+        let hash = snapshot.hash();
+
+        let mut processed = ScheduleSnapshot {
+            fetched_at: snapshot.fetched_at,
+            updated_at: snapshot.updated_at,
+            url: snapshot.url.clone(),
+            data: postprocess(&snapshot.data, aliases, calendar, settings),
+            cached_hash: None,
+        };
+
+        processed.cached_hash = Some(processed.hash());
+
+        Entry {
+            hash,
+            raw: Arc::new(snapshot),
+            processed: Arc::new(processed),
+        }
+    }
+
+    /// Snapshot served to clients.
+    pub fn active(&self) -> Arc<ScheduleSnapshot> {
+        self.find(&self.active)
+            .expect("Active snapshot must be present in the history")
+            .processed
+            .clone()
+    }
+
+    /// The most recently obtained snapshot as it was parsed.
+    pub fn newest(&self) -> Arc<ScheduleSnapshot> {
+        self.entries.back().unwrap().raw.clone()
+    }
+
+    pub fn is_pinned(&self) -> bool {
+        self.pinned
+    }
+
+    /// Adds a freshly obtained snapshot.
+    ///
+    /// It becomes active unless the current active snapshot is pinned.
+    pub fn push(&mut self, snapshot: ScheduleSnapshot) {
+        let entry = Self::entry(snapshot, &self.aliases, &self.calendar, &self.settings);
+        let hash = entry.hash.clone();
+
+        // the schedule may return to an earlier state, the old copy is moved to the end
+        self.entries.retain(|stored| stored.hash != hash);
+        self.entries.push_back(entry);
+
+        if !self.pinned {
+            self.active = hash;
+        }
+
+        while self.entries.len() > self.capacity {
+            // the active snapshot is never evicted, even if it is the oldest one
+            let index = self
+                .entries
+                .iter()
+                .position(|entry| entry.hash != self.active)
+                .unwrap();
+
+            self.entries.remove(index);
+        }
+    }
+
+    /// Replaces the calendar and re-applies it to all stored snapshots.
+    pub fn set_calendar(&mut self, calendar: AcademicCalendar) {
+        self.calendar = calendar;
+        self.reprocess();
+    }
+
+    /// Applies postprocessing to all stored snapshots again.
+    fn reprocess(&mut self) {
+        for entry in self.entries.iter_mut() {
+            let raw = entry.raw.as_ref().clone();
+            *entry = Self::entry(raw, &self.aliases, &self.calendar, &self.settings);
+        }
+    }
+
+    /// Replaces the corrections and re-applies them to all stored snapshots.
+    pub fn set_aliases(&mut self, aliases: ScheduleAliases) {
+        self.aliases = aliases;
+        self.reprocess();
+    }
+
+    /// List of stored snapshots, from the newest to the oldest.
+    pub fn list(&self) -> Vec<SnapshotEntry> {
+        self.entries
+            .iter()
+            .rev()
+            .map(|entry| {
+                let active = entry.hash == self.active;
+
+                SnapshotEntry {
+                    hash: entry.processed.hash(),
+                    snapshot: entry.processed.clone(),
+                    active,
+                    pinned: active && self.pinned,
+                }
+            })
+            .collect()
+    }
+
+    /// Makes the snapshot with the specified [`SnapshotEntry::hash`] active and stops new snapshots from replacing it.
+    pub fn pin(&mut self, hash: &str) -> Result<Arc<ScheduleSnapshot>, HistoryError> {
+        let entry = self
+            .entries
+            .iter()
+            .find(|entry| entry.processed.hash() == hash)
+            .ok_or(HistoryError::NotFound)?;
+
+        let snapshot = entry.processed.clone();
+
+        self.active = entry.hash.clone();
+        self.pinned = true;
+
+        Ok(snapshot)
+    }
+
+    /// Removes the pin and makes the newest snapshot active again.
+    pub fn unpin(&mut self) -> Arc<ScheduleSnapshot> {
+        let newest = self.entries.back().unwrap();
+
+        self.active = newest.hash.clone();
+        self.pinned = false;
+
+        newest.processed.clone()
+    }
+
+    /// Pins the snapshot that preceded the active one.
+    pub fn rollback(&mut self) -> Result<Arc<ScheduleSnapshot>, HistoryError> {
+        let index = self
+            .entries
+            .iter()
+            .position(|entry| entry.hash == self.active)
+            .unwrap();
+
+        if index == 0 {
+            return Err(HistoryError::NoPrevious);
+        }
+
+        let hash = self.entries[index - 1].processed.hash();
+        self.pin(&hash)
+    }
+
+    fn find(&self, hash: &str) -> Option<&Entry> {
+        self.entries.iter().find(|entry| entry.hash == hash)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        Day, DayCalendar, Lesson, LessonBoundaries, LessonSubGroup, LessonType, ParsedSchedule,
+        ScheduleEntry,
+    };
+    use chrono::{DateTime, Utc};
+    use std::collections::HashMap;
+
+    fn snapshot(group: &str) -> ScheduleSnapshot {
+        ScheduleSnapshot {
+            fetched_at: Utc::now(),
+            updated_at: Utc::now(),
+            url: group.to_string(),
+            data: ParsedSchedule {
+                groups: HashMap::from([(
+                    group.to_string(),
+                    ScheduleEntry {
+                        name: group.to_string(),
+                        days: Vec::new(),
+                    },
+                )]),
+                teachers: HashMap::new(),
+                subjects: HashMap::new(),
+                group_info: HashMap::new(),
+                bells: Vec::new(),
+                calendar: AcademicCalendar::default(),
+                diagnostics: Vec::new(),
+            },
+            cached_hash: None,
+        }
+    }
+
+    #[test]
+    fn pin_stops_replacing() {
+        let mut history = SnapshotHistory::new(snapshot("a"), 10, ProviderSettings::default());
+        let first = history.active().hash();
+
+        history.pin(&first).unwrap();
+        history.push(snapshot("b"));
+
+        assert_eq!(history.active().hash(), first);
+        assert_eq!(history.newest().url, "b");
+
+        history.unpin();
+        assert_eq!(history.active().url, "b");
+    }
+
+    #[test]
+    fn rollback() {
+        let mut history = SnapshotHistory::new(snapshot("a"), 10, ProviderSettings::default());
+
+        assert_eq!(history.rollback().err(), Some(HistoryError::NoPrevious));
+
+        history.push(snapshot("b"));
+        history.push(snapshot("b"));
+        assert_eq!(history.list().len(), 2);
+
+        assert_eq!(history.rollback().unwrap().url, "a");
+        assert!(history.is_pinned());
+    }
+
+    #[test]
+    fn repeated_snapshot() {
+        let mut history = SnapshotHistory::new(snapshot("a"), 10, ProviderSettings::default());
+
+        history.push(snapshot("b"));
+        history.push(snapshot("a"));
+
+        let list = history.list();
+
+        assert_eq!(list.len(), 2);
+        assert_eq!(list[0].snapshot.url, "a");
+        assert!(list[0].active);
+
+        // the previous state is "b", not the older copy of "a"
+        assert_eq!(history.rollback().unwrap().url, "b");
+        assert_eq!(history.rollback().err(), Some(HistoryError::NoPrevious));
+    }
+
+    #[test]
+    fn aliases_change_hash() {
+        let mut snapshot = snapshot("a");
+        let group = snapshot.data.groups.get_mut("a").unwrap();
+
+        group.days.push(Day {
+            name: "Понедельник".to_string(),
+            street: None,
+            date: DateTime::default(),
+            lessons: vec![Lesson {
+                lesson_type: LessonType::Default,
+                range: Some([1, 1]),
+                name: Some("История".to_string()),
+                subject_id: None,
+                subject: None,
+                time: LessonBoundaries {
+                    start: DateTime::default(),
+                    end: DateTime::default(),
+                },
+                subgroups: Some(vec![Some(LessonSubGroup {
+                    cabinet: None,
+                    teacher: Some("Иванов И.И.".to_string()),
+                })]),
+                modifiers: Vec::new(),
+                raw: None,
+                building: None,
+                groups: Vec::new(),
+            }],
+            commutes: Vec::new(),
+            calendar: DayCalendar::default(),
+        });
+
+        let mut history = SnapshotHistory::new(snapshot, 10, ProviderSettings::default());
+        let before = history.active().hash();
+
+        history.set_aliases(ScheduleAliases::default());
+        assert_eq!(history.active().hash(), before);
+
+        history.set_aliases(ScheduleAliases {
+            teachers: HashMap::from([("Иванов И.И.".to_string(), "Петров П.П.".to_string())]),
+            ..Default::default()
+        });
+
+        let after = history.active().hash();
+
+        assert_ne!(after, before);
+        assert_eq!(history.list()[0].hash, after);
+        assert_eq!(history.pin(&after).unwrap().hash(), after);
+    }
+
+    #[test]
+    fn capacity_keeps_active() {
+        let mut history = SnapshotHistory::new(snapshot("a"), 2, ProviderSettings::default());
+        let first = history.active().hash();
+
+        history.pin(&first).unwrap();
+        history.push(snapshot("b"));
+        history.push(snapshot("c"));
+
+        let list = history.list();
+
+        assert_eq!(list.len(), 2);
+        assert_eq!(list[0].snapshot.url, "c");
+        assert!(list[1].active && list[1].pinned);
+    }
+}
@@ -0,0 +1,306 @@
+use crate::hasher::DigestHasher;
+use crate::{Day, Lesson, LessonType, ScheduleEntry};
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use sha1::{Digest, Sha1};
+use std::hash::Hash;
+
+/// Maximum length of a content line in octets, excluding the line break.
+const LINE_LENGTH: usize = 75;
+
+/// Escapes the characters that have a special meaning in the TEXT values.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for char in text.chars() {
+        match char {
+            '\\' => escaped.push_str("\\\\"),
+            ';' => escaped.push_str("\\;"),
+            ',' => escaped.push_str("\\,"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(char),
+        }
+    }
+
+    escaped
+}
+
+/// Writes the content line, folding it into several lines if it is too long.
+///
+/// Lines are split only between characters, so multibyte characters stay intact.
+fn write_line(ical: &mut String, line: &str) {
+    let mut length = 0;
+
+    for char in line.chars() {
+        if length + char.len_utf8() > LINE_LENGTH {
+            ical.push_str("\r\n ");
+            length = 1;
+        }
+
+        ical.push(char);
+        length += char.len_utf8();
+    }
+
+    ical.push_str("\r\n");
+}
+
+fn format_time(time: &DateTime<Utc>) -> String {
+    time.format("%Y%m%dT%H%M%SZ").to_string()
+}
+
+/// Identifier of the lesson that doesn't change between schedule updates.
+///
+/// Only the entry, the time slot and the lesson name are used,
+/// so calendar applications update the event if the cabinet or the teacher is changed.
+fn uid(entry: &ScheduleEntry, lesson: &Lesson) -> String {
+    let mut hasher = DigestHasher::from(Sha1::new());
+
+    entry.name.hash(&mut hasher);
+    lesson.time.start.hash(&mut hasher);
+    lesson.range.hash(&mut hasher);
+    lesson.name.hash(&mut hasher);
+
+    format!(
+        "{}@schedule-parser-rusted",
+        hasher.finalize().to_lowercase()
+    )
+}
+
+fn summary(lesson: &Lesson) -> String {
+    let name = lesson
+        .subject
+        .as_deref()
+        .or(lesson.name.as_deref())
+        .unwrap_or("Занятие");
+
+    match lesson.lesson_type {
+        LessonType::Default | LessonType::Additional => name.to_string(),
+        LessonType::Break => "Перемена".to_string(),
+        LessonType::Consultation => format!("Консультация: {name}"),
+        LessonType::IndependentWork => format!("Самостоятельная работа: {name}"),
+        LessonType::Exam => format!("Зачёт: {name}"),
+        LessonType::ExamWithGrade => format!("Зачёт с оценкой: {name}"),
+        LessonType::ExamDefault => format!("Экзамен: {name}"),
+        LessonType::CourseProject => format!("Курсовой проект: {name}"),
+        LessonType::CourseProjectDefense => format!("Защита курсового проекта: {name}"),
+        LessonType::Practice => format!("Практическое занятие: {name}"),
+        LessonType::DifferentiatedExam => format!("Дифференцированный зачёт: {name}"),
+    }
+}
+
+/// Cabinets of the lesson followed by the address of the building, if it isn't the main one.
+fn location(day: &Day, lesson: &Lesson) -> Option<String> {
+    let mut parts: Vec<&str> = Vec::new();
+
+    for cabinet in lesson
+        .subgroups
+        .iter()
+        .flatten()
+        .flatten()
+        .filter_map(|subgroup| subgroup.cabinet.as_deref())
+    {
+        if !parts.contains(&cabinet) {
+            parts.push(cabinet);
+        }
+    }
+
+    if let Some(street) = &day.street {
+        parts.push(street);
+    }
+
+    (!parts.is_empty()).then(|| parts.join(", "))
+}
+
+/// Teachers of every subgroup and the groups sharing the lesson, one per line.
+fn description(lesson: &Lesson) -> Option<String> {
+    let mut lines = Vec::new();
+
+    if let Some(subgroups) = &lesson.subgroups {
+        let numbered = subgroups.len() > 1;
+
+        for (index, subgroup) in subgroups.iter().enumerate() {
+            let Some(subgroup) = subgroup else {
+                continue;
+            };
+
+            let teacher = subgroup
+                .teacher
+                .as_deref()
+                .unwrap_or("Преподаватель не указан");
+
+            let line = match &subgroup.cabinet {
+                Some(cabinet) => format!("{teacher} ({cabinet})"),
+                None => teacher.to_string(),
+            };
+
+            lines.push(if numbered {
+                format!("{} подгруппа: {line}", index + 1)
+            } else {
+                line
+            });
+        }
+    }
+
+    if !lesson.groups.is_empty() {
+        lines.push(format!("Группы: {}", lesson.groups.join(", ")));
+    }
+
+    (!lines.is_empty()).then(|| lines.join("\n"))
+}
+
+/// Converts the schedule entry into an iCalendar (RFC 5545) document.
+///
+/// Every lesson except breaks becomes an event.
+/// Times are written in UTC, the timezone is only used as a hint for displaying them.
+///
+/// # Arguments
+///
+/// * `entry`: schedule of the group or the teacher.
+/// * `timezone`: timezone of the provider.
+/// * `stamp`: moment the schedule was last updated.
+pub fn to_ical(entry: &ScheduleEntry, timezone: Tz, stamp: DateTime<Utc>) -> String {
+    let mut ical = String::new();
+
+    write_line(&mut ical, "BEGIN:VCALENDAR");
+    write_line(&mut ical, "VERSION:2.0");
+    write_line(&mut ical, "PRODID:-//schedule-parser-rusted//RU");
+    write_line(&mut ical, "CALSCALE:GREGORIAN");
+    write_line(&mut ical, "METHOD:PUBLISH");
+    write_line(&mut ical, &format!("X-WR-CALNAME:{}", escape(&entry.name)));
+    write_line(&mut ical, &format!("X-WR-TIMEZONE:{}", timezone.name()));
+
+    // subscribed calendars are refreshed by the clients, ask them to do it hourly
+    write_line(&mut ical, "REFRESH-INTERVAL;VALUE=DURATION:PT1H");
+    write_line(&mut ical, "X-PUBLISHED-TTL:PT1H");
+
+    let stamp = format_time(&stamp);
+
+    for day in &entry.days {
+        for lesson in &day.lessons {
+            if lesson.lesson_type == LessonType::Break {
+                continue;
+            }
+
+            write_line(&mut ical, "BEGIN:VEVENT");
+            write_line(&mut ical, &format!("UID:{}", uid(entry, lesson)));
+            write_line(&mut ical, &format!("DTSTAMP:{stamp}"));
+            write_line(
+                &mut ical,
+                &format!("DTSTART:{}", format_time(&lesson.time.start)),
+            );
+            write_line(
+                &mut ical,
+                &format!("DTEND:{}", format_time(&lesson.time.end)),
+            );
+            write_line(&mut ical, &format!("SUMMARY:{}", escape(&summary(lesson))));
+
+            if let Some(location) = location(day, lesson) {
+                write_line(&mut ical, &format!("LOCATION:{}", escape(&location)));
+            }
+
+            if let Some(description) = description(lesson) {
+                write_line(&mut ical, &format!("DESCRIPTION:{}", escape(&description)));
+            }
+
+            write_line(&mut ical, "END:VEVENT");
+        }
+    }
+
+    write_line(&mut ical, "END:VCALENDAR");
+
+    ical
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DayCalendar, LessonBoundaries, LessonSubGroup};
+    use chrono::TimeZone;
+
+    fn lesson(name: &str, hour: u32) -> Lesson {
+        Lesson {
+            lesson_type: LessonType::Default,
+            range: Some([1, 1]),
+            name: Some(name.to_string()),
+            subject_id: None,
+            subject: None,
+            time: LessonBoundaries {
+                start: Utc.with_ymd_and_hms(2025, 11, 3, hour, 0, 0).unwrap(),
+                end: Utc.with_ymd_and_hms(2025, 11, 3, hour + 1, 30, 0).unwrap(),
+            },
+            subgroups: Some(vec![
+                Some(LessonSubGroup {
+                    cabinet: Some("42".to_string()),
+                    teacher: Some("Иванов И.И.".to_string()),
+                }),
+                Some(LessonSubGroup {
+                    cabinet: Some("43".to_string()),
+                    teacher: Some("Петров П.П.".to_string()),
+                }),
+            ]),
+            modifiers: Vec::new(),
+            raw: None,
+            building: None,
+            groups: Vec::new(),
+        }
+    }
+
+    fn entry(lessons: Vec<Lesson>) -> ScheduleEntry {
+        ScheduleEntry {
+            name: "ИС-214/23".to_string(),
+            days: vec![Day {
+                name: "Понедельник".to_string(),
+                street: None,
+                date: Utc.with_ymd_and_hms(2025, 11, 3, 0, 0, 0).unwrap(),
+                lessons,
+                commutes: Vec::new(),
+                calendar: DayCalendar::default(),
+            }],
+        }
+    }
+
+    #[test]
+    fn events() {
+        let mut lunch = lesson("Обед", 6);
+        lunch.lesson_type = LessonType::Break;
+
+        let ical = to_ical(
+            &entry(vec![lesson("Информатика, практика", 4), lunch]),
+            chrono_tz::Europe::Saratov,
+            Utc.with_ymd_and_hms(2025, 11, 1, 0, 0, 0).unwrap(),
+        );
+
+        assert!(ical.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert!(ical.ends_with("END:VCALENDAR\r\n"));
+        assert!(ical.contains("X-WR-TIMEZONE:Europe/Saratov\r\n"));
+
+        // breaks are skipped
+        assert_eq!(ical.matches("BEGIN:VEVENT").count(), 1);
+
+        assert!(ical.contains("DTSTART:20251103T040000Z\r\n"));
+        assert!(ical.contains("DTEND:20251103T053000Z\r\n"));
+        assert!(ical.contains("SUMMARY:Информатика\\, практика\r\n"));
+        assert!(ical.contains("LOCATION:42\\, 43\r\n"));
+        assert!(ical.contains("DESCRIPTION:1 подгруппа: Иванов И.И. (42)\\n2"));
+
+        for line in ical.split("\r\n") {
+            assert!(line.len() <= LINE_LENGTH, "{line}");
+        }
+    }
+
+    #[test]
+    fn stable_uid() {
+        let first = entry(vec![lesson("Информатика", 4)]);
+
+        let mut changed = entry(vec![lesson("Информатика", 4)]);
+        changed.days[0].lessons[0].subgroups = None;
+
+        let other = entry(vec![lesson("Информатика", 6)]);
+
+        let first_uid = uid(&first, &first.days[0].lessons[0]);
+
+        assert_eq!(first_uid, uid(&changed, &changed.days[0].lessons[0]));
+        assert_ne!(first_uid, uid(&other, &other.days[0].lessons[0]));
+    }
+}
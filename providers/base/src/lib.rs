@@ -1,20 +1,50 @@
+pub use crate::bells::{Bell, BellDay};
+pub use crate::buildings::{Building, Commute};
+pub use crate::cabinets::cabinet_schedule;
+pub use crate::calendar::{
+    AcademicCalendar, CalendarPeriod, CalendarPeriodKind, DayCalendar, WeekParity,
+};
+pub use crate::conflicts::{Conflict, ConflictKind, find_conflicts};
+pub use crate::filter::DayFilter;
 use crate::hasher::DigestHasher;
+pub use crate::history::{HistoryError, SnapshotEntry, SnapshotHistory};
+pub use crate::ical::to_ical;
+pub use crate::postprocess::{ScheduleAliases, postprocess};
+pub use crate::search::{SearchKind, SearchResult, find_by_name, resolve_name, search};
+pub use crate::subjects::{Subject, normalize_subject_name, subject_key};
+pub use crate::teachers::{convert_groups_to_teachers, link_joint_lessons, normalize_teacher_name};
+pub use crate::upcoming::{Upcoming, upcoming};
 use async_trait::async_trait;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use chrono_tz::Tz;
 use serde::{Deserialize, Serialize};
 use serde_repr::{Deserialize_repr, Serialize_repr};
 use sha1::{Digest, Sha1};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::hash::Hash;
 use std::sync::Arc;
+use std::time::Duration;
 use tokio_util::sync::CancellationToken;
 use utoipa::ToSchema;
 
+mod bells;
+mod buildings;
+mod cabinets;
+mod calendar;
+mod conflicts;
+mod filter;
 mod hasher;
+mod history;
+mod ical;
+mod postprocess;
+mod search;
+mod subjects;
+mod teachers;
+mod upcoming;
 
 // pub(crate) mod internal {
 //     use super::{LessonBoundaries, LessonType};
-//     use chrono::{DateTime, Utc};
+//     use chrono::{DateTime, NaiveDate, Utc};
 //
 //     /// Data cell storing the group name.
 //     pub struct GroupCellInfo {
@@ -108,7 +138,42 @@ pub enum LessonType {
     DifferentiatedExam,
 }
 
-#[derive(Clone, Hash, Debug, Serialize, Deserialize, ToSchema)]
+/// Annotation written in the lesson cell besides the name and teachers.
+#[derive(Clone, Hash, PartialEq, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum LessonModifier {
+    /// Laboratory work.
+    Lab,
+
+    /// Remote lesson.
+    Online,
+
+    /// Lesson takes place only on odd weeks.
+    OddWeeks,
+
+    /// Lesson takes place only on even weeks.
+    EvenWeeks,
+
+    /// Lesson takes place starting from the date.
+    Since {
+        /// The first date.
+        date: NaiveDate,
+    },
+
+    /// Lesson takes place up to the date.
+    Until {
+        /// The last date.
+        date: NaiveDate,
+    },
+
+    /// Text that wasn't recognized.
+    Raw {
+        /// The text itself.
+        text: String,
+    },
+}
+
+#[derive(Clone, Hash, PartialEq, Debug, Serialize, Deserialize, ToSchema)]
 pub struct LessonSubGroup {
     /// Cabinet, if present.
     pub cabinet: Option<String>,
@@ -130,6 +195,12 @@ pub struct Lesson {
     /// Name.
     pub name: Option<String>,
 
+    /// Identifier of the subject in [`ParsedSchedule::subjects`].
+    pub subject_id: Option<String>,
+
+    /// Canonical name of the subject.
+    pub subject: Option<String>,
+
     /// The beginning and end.
     pub time: LessonBoundaries,
 
@@ -137,8 +208,20 @@ pub struct Lesson {
     #[serde(rename = "subgroups")]
     pub subgroups: Option<Vec<Option<LessonSubGroup>>>,
 
-    /// Group name, if this is a schedule for teachers.
-    pub group: Option<String>,
+    /// Annotations found in the lesson cell.
+    pub modifiers: Vec<LessonModifier>,
+
+    /// Original text of the lesson cell.
+    pub raw: Option<String>,
+
+    /// Identifier of the building where the lesson takes place.
+    pub building: Option<String>,
+
+    /// Groups attending the lesson together.
+    ///
+    /// In the teacher schedule these are all groups of the lesson,
+    /// in the group schedule these are other groups sharing it with this one.
+    pub groups: Vec<String>,
 }
 
 #[derive(Clone, Hash, Debug, Serialize, Deserialize, ToSchema)]
@@ -154,6 +237,32 @@ pub struct Day {
 
     /// List of lessons on this day.
     pub lessons: Vec<Lesson>,
+
+    /// Moves between buildings during breaks.
+    pub commutes: Vec<Commute>,
+
+    /// Place of the day in the academic calendar.
+    pub calendar: DayCalendar,
+}
+
+/// Information encoded in the group name.
+#[derive(Clone, Hash, Debug, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct GroupInfo {
+    /// Abbreviation of the specialty.
+    #[schema(examples("ИС"))]
+    pub specialty: String,
+
+    /// Number of the group.
+    #[schema(examples("214"))]
+    pub number: String,
+
+    /// Year the group started studying.
+    #[schema(examples(2023))]
+    pub year: Option<i32>,
+
+    /// Course the group studies at in the week of the schedule.
+    #[schema(examples(3))]
+    pub course: Option<u32>,
 }
 
 #[derive(Clone, Hash, Debug, Serialize, Deserialize, ToSchema)]
@@ -165,6 +274,36 @@ pub struct ScheduleEntry {
     pub days: Vec<Day>,
 }
 
+/// Kind of the problem found while parsing the schedule.
+#[derive(Clone, Hash, PartialEq, Debug, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DiagnosticKind {
+    /// The text after the teachers list doesn't look like any known lesson type.
+    UnknownLessonType,
+
+    /// Teacher name was normalized or merged with a similar one.
+    TeacherMerged,
+
+    /// Teacher name was replaced using the alias table.
+    TeacherAliased,
+
+    /// Teacher has several different lessons at the same time.
+    TeacherOverlap,
+
+    /// Lessons are listed on a holiday or outside of the semesters.
+    CalendarMismatch,
+}
+
+/// Non-fatal problem found while parsing the schedule.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct Diagnostic {
+    /// Kind of the problem.
+    pub kind: DiagnosticKind,
+
+    /// Human-readable description with the position in the source document.
+    pub message: String,
+}
+
 #[derive(Clone)]
 pub struct ParsedSchedule {
     /// List of groups.
@@ -172,6 +311,23 @@ pub struct ParsedSchedule {
 
     /// List of teachers.
     pub teachers: HashMap<String, ScheduleEntry>,
+
+    /// Subject catalogue by subject identifiers.
+    pub subjects: HashMap<String, Subject>,
+
+    /// Information extracted from the group names, by group names.
+    ///
+    /// Groups with names in an unknown format are absent.
+    pub group_info: HashMap<String, GroupInfo>,
+
+    /// Lesson slots of every day, by day index.
+    pub bells: Vec<BellDay>,
+
+    /// Academic calendar the days are annotated with.
+    pub calendar: AcademicCalendar,
+
+    /// Problems found while parsing.
+    pub diagnostics: Vec<Diagnostic>,
 }
 
 /// Represents a snapshot of the schedule parsed from an XLS file.
@@ -192,6 +348,11 @@ pub struct ScheduleSnapshot {
 
     /// Parsed schedule data in the application's internal representation.
     pub data: ParsedSchedule,
+
+    /// Hash computed in advance, returned by [`ScheduleSnapshot::hash`] instead of hashing the data again.
+    ///
+    /// Set by [`SnapshotHistory`] for postprocessed snapshots.
+    pub cached_hash: Option<String>,
 }
 
 impl ScheduleSnapshot {
@@ -200,10 +361,18 @@ impl ScheduleSnapshot {
     /// The hash does not depend on the dates.
     /// If the application is restarted, but the file with source schedule will remain unchanged, then the hash will not change.
     pub fn hash(&self) -> String {
+        if let Some(hash) = &self.cached_hash {
+            return hash.clone();
+        }
+
         let mut hasher = DigestHasher::from(Sha1::new());
 
-        self.data.teachers.iter().for_each(|e| e.hash(&mut hasher));
-        self.data.groups.iter().for_each(|e| e.hash(&mut hasher));
+        // sorted, so equal data gives the same hash regardless of the map order
+        let teachers: BTreeMap<_, _> = self.data.teachers.iter().collect();
+        let groups: BTreeMap<_, _> = self.data.groups.iter().collect();
+
+        teachers.iter().for_each(|e| e.hash(&mut hasher));
+        groups.iter().for_each(|e| e.hash(&mut hasher));
 
         hasher.finalize()
     }
@@ -215,6 +384,37 @@ impl ScheduleSnapshot {
     }
 }
 
+/// Settings common to every provider instance.
+#[derive(Clone, Debug)]
+pub struct ProviderSettings {
+    /// How often the auto-update task checks for a new schedule.
+    pub update_interval: Duration,
+
+    /// Timezone in which the schedule is written.
+    pub timezone: Tz,
+
+    /// Buildings where lessons take place, the first one is the main building.
+    pub buildings: Vec<Building>,
+
+    /// Earliest time a lesson can start at.
+    pub day_start: NaiveTime,
+
+    /// Latest time a lesson can end at.
+    pub day_end: NaiveTime,
+}
+
+impl Default for ProviderSettings {
+    fn default() -> Self {
+        Self {
+            update_interval: Duration::from_secs(60 * 30),
+            timezone: chrono_tz::Europe::Saratov,
+            buildings: Vec::new(),
+            day_start: NaiveTime::from_hms_opt(8, 0, 0).unwrap(),
+            day_end: NaiveTime::from_hms_opt(21, 0, 0).unwrap(),
+        }
+    }
+}
+
 #[async_trait]
 pub trait ScheduleProvider
 where
@@ -227,5 +427,27 @@ where
         cancellation_token: CancellationToken,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>>;
 
+    /// Settings the provider was created with.
+    fn settings(&self) -> &ProviderSettings;
+
+    /// Returns the active snapshot.
     async fn get_schedule(&self) -> Arc<ScheduleSnapshot>;
+
+    /// Returns recently obtained snapshots, from the newest to the oldest.
+    async fn get_snapshots(&self) -> Vec<SnapshotEntry>;
+
+    /// Makes the snapshot with the specified hash active until [`ScheduleProvider::unpin_snapshot`] is called.
+    async fn pin_snapshot(&self, hash: &str) -> Result<Arc<ScheduleSnapshot>, HistoryError>;
+
+    /// Lets auto-update replace the active snapshot again and activates the newest one.
+    async fn unpin_snapshot(&self) -> Arc<ScheduleSnapshot>;
+
+    /// Pins the snapshot that preceded the active one.
+    async fn rollback_snapshot(&self) -> Result<Arc<ScheduleSnapshot>, HistoryError>;
+
+    /// Replaces the administrator corrections and re-applies them to the stored snapshots.
+    async fn set_aliases(&self, aliases: ScheduleAliases);
+
+    /// Replaces the academic calendar and re-applies it to the stored snapshots.
+    async fn set_calendar(&self, calendar: AcademicCalendar);
 }
@@ -0,0 +1,256 @@
+use crate::buildings::{assign_buildings, find_commutes};
+use crate::calendar::annotate_days;
+use crate::subjects::{assign_subjects, build_subject_catalogue};
+use crate::teachers::{
+    SCHEDULE_ERROR_TEACHER, cluster_teacher_names, convert_groups_to_teachers, link_joint_lessons,
+    normalize_teacher_name,
+};
+use crate::{
+    AcademicCalendar, Diagnostic, DiagnosticKind, LessonType, ParsedSchedule, ProviderSettings,
+};
+use std::collections::{BTreeMap, HashMap};
+
+/// Manual corrections of the parsed schedule managed by administrators.
+#[derive(Clone, Debug, Default)]
+pub struct ScheduleAliases {
+    /// Canonical teacher names by their alternative spellings.
+    pub teachers: HashMap<String, String>,
+
+    /// Canonical subject names by their alternative spellings.
+    pub subjects: HashMap<String, String>,
+}
+
+/// Final name for every teacher name found in the schedule.
+fn resolve_teacher_names(
+    schedule: &ParsedSchedule,
+    aliases: &ScheduleAliases,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> HashMap<String, String> {
+    // BTreeMap to keep the order of diagnostics stable
+    let mut raw_names: BTreeMap<&str, usize> = BTreeMap::new();
+
+    let subgroups = schedule
+        .groups
+        .values()
+        .flat_map(|group| &group.days)
+        .flat_map(|day| &day.lessons)
+        .filter(|lesson| lesson.lesson_type != LessonType::Break)
+        .flat_map(|lesson| lesson.subgroups.iter().flatten().flatten());
+
+    for subgroup in subgroups {
+        if let Some(teacher) = &subgroup.teacher
+            && teacher != SCHEDULE_ERROR_TEACHER
+        {
+            *raw_names.entry(teacher).or_default() += 1;
+        }
+    }
+
+    let mut counts: HashMap<String, usize> = HashMap::new();
+
+    for (name, count) in &raw_names {
+        *counts.entry(normalize_teacher_name(name)).or_default() += count;
+    }
+
+    let merges = cluster_teacher_names(&counts);
+
+    let aliases: HashMap<String, &String> = aliases
+        .teachers
+        .iter()
+        .map(|(alias, canonical)| (normalize_teacher_name(alias), canonical))
+        .collect();
+
+    let mut result = HashMap::new();
+
+    for raw_name in raw_names.into_keys() {
+        let normalized = normalize_teacher_name(raw_name);
+        let merged = merges.get(&normalized).unwrap_or(&normalized);
+
+        // the alias may be written as the name is displayed in the sheet,
+        // before it is merged with similar names
+        let name = match aliases.get(&normalized).or_else(|| aliases.get(merged)) {
+            Some(canonical) => {
+                diagnostics.push(Diagnostic {
+                    kind: DiagnosticKind::TeacherAliased,
+                    message: format!(
+                        "Teacher \"{raw_name}\" is renamed to \"{canonical}\" by alias."
+                    ),
+                });
+
+                (*canonical).clone()
+            }
+            None => {
+                if merged != raw_name {
+                    diagnostics.push(Diagnostic {
+                        kind: DiagnosticKind::TeacherMerged,
+                        message: format!("Teacher \"{raw_name}\" is merged into \"{merged}\"."),
+                    });
+                }
+
+                merged.clone()
+            }
+        };
+
+        if name != raw_name {
+            result.insert(raw_name.to_string(), name);
+        }
+    }
+
+    result
+}
+
+/// Applies normalization stages and administrator corrections to the freshly parsed schedule.
+///
+/// # Arguments
+///
+/// * `schedule`: the schedule as it was parsed.
+/// * `aliases`: administrator corrections.
+/// * `calendar`: academic calendar of the provider.
+/// * `settings`: settings of the provider.
+pub fn postprocess(
+    schedule: &ParsedSchedule,
+    aliases: &ScheduleAliases,
+    calendar: &AcademicCalendar,
+    settings: &ProviderSettings,
+) -> ParsedSchedule {
+    let mut diagnostics = schedule.diagnostics.clone();
+    let mut groups = schedule.groups.clone();
+
+    let teacher_names = resolve_teacher_names(schedule, aliases, &mut diagnostics);
+
+    let subgroups = groups
+        .values_mut()
+        .flat_map(|group| &mut group.days)
+        .flat_map(|day| &mut day.lessons)
+        .flat_map(|lesson| lesson.subgroups.iter_mut().flatten().flatten());
+
+    for subgroup in subgroups {
+        if let Some(teacher) = &mut subgroup.teacher
+            && let Some(name) = teacher_names.get(teacher)
+        {
+            *teacher = name.clone();
+        }
+    }
+
+    let (subjects, subject_ids) = build_subject_catalogue(&groups, &aliases.subjects);
+
+    assign_subjects(&mut groups, &subjects, &subject_ids);
+    assign_buildings(&mut groups, &settings.buildings);
+
+    // teacher lessons are built again to get the renames, subjects and buildings,
+    // so are the overlaps
+    diagnostics.retain(|diagnostic| diagnostic.kind != DiagnosticKind::TeacherOverlap);
+
+    let mut teachers = convert_groups_to_teachers(&groups, &mut diagnostics);
+    link_joint_lessons(&mut groups, &teachers);
+
+    find_commutes(&mut groups);
+    find_commutes(&mut teachers);
+
+    diagnostics.retain(|diagnostic| diagnostic.kind != DiagnosticKind::CalendarMismatch);
+    annotate_days(
+        &mut groups,
+        &mut teachers,
+        calendar,
+        settings.timezone,
+        &mut diagnostics,
+    );
+
+    ParsedSchedule {
+        teachers,
+        groups,
+        subjects,
+        group_info: schedule.group_info.clone(),
+        bells: schedule.bells.clone(),
+        calendar: calendar.clone(),
+        diagnostics,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Day, DayCalendar, Lesson, LessonBoundaries, LessonSubGroup, ScheduleEntry};
+    use chrono::{DateTime, TimeDelta};
+
+    fn lesson(teacher: &str) -> Lesson {
+        Lesson {
+            lesson_type: LessonType::Default,
+            range: Some([1, 1]),
+            name: Some("История".to_string()),
+            subject_id: None,
+            subject: None,
+            time: LessonBoundaries {
+                start: DateTime::default(),
+                end: DateTime::default() + TimeDelta::minutes(90),
+            },
+            subgroups: Some(vec![Some(LessonSubGroup {
+                cabinet: Some("101".to_string()),
+                teacher: Some(teacher.to_string()),
+            })]),
+            modifiers: Vec::new(),
+            raw: None,
+            building: None,
+            groups: Vec::new(),
+        }
+    }
+
+    fn schedule(teachers: &[&str]) -> ParsedSchedule {
+        let entry = ScheduleEntry {
+            name: "ИС-214/23".to_string(),
+            days: vec![Day {
+                name: "Понедельник".to_string(),
+                street: None,
+                date: DateTime::default(),
+                lessons: teachers.iter().map(|teacher| lesson(teacher)).collect(),
+                commutes: Vec::new(),
+                calendar: DayCalendar::default(),
+            }],
+        };
+
+        ParsedSchedule {
+            groups: HashMap::from([(entry.name.clone(), entry)]),
+            teachers: HashMap::new(),
+            subjects: HashMap::new(),
+            group_info: HashMap::new(),
+            bells: Vec::new(),
+            calendar: AcademicCalendar::default(),
+            diagnostics: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn alias_before_merge() {
+        let mut teachers = vec!["Хомченко Н.Е."; 10];
+        teachers.push("Хоменко Н.Е.");
+
+        let schedule = schedule(&teachers);
+
+        // without the alias the rare spelling is merged into the frequent one
+        let names = resolve_teacher_names(&schedule, &ScheduleAliases::default(), &mut Vec::new());
+        assert_eq!(names["Хоменко Н.Е."], "Хомченко Н.Е.");
+
+        let aliases = ScheduleAliases {
+            teachers: HashMap::from([("Хоменко Н.Е.".to_string(), "Хоменко Н.Е.".to_string())]),
+            ..Default::default()
+        };
+
+        let names = resolve_teacher_names(&schedule, &aliases, &mut Vec::new());
+        assert!(!names.contains_key("Хоменко Н.Е."));
+    }
+
+    #[test]
+    fn alias_hyphenated() {
+        let schedule = schedule(&["ПЕТРОВ-ВОДКИН А.А."]);
+
+        let aliases = ScheduleAliases {
+            teachers: HashMap::from([(
+                "Петров-Водкин А.А.".to_string(),
+                "Петров-Водкин Антон Андреевич".to_string(),
+            )]),
+            ..Default::default()
+        };
+
+        let names = resolve_teacher_names(&schedule, &aliases, &mut Vec::new());
+        assert_eq!(names["ПЕТРОВ-ВОДКИН А.А."], "Петров-Водкин Антон Андреевич");
+    }
+}
@@ -0,0 +1,304 @@
+use crate::LessonType::Break;
+use crate::ParsedSchedule;
+use serde::Serialize;
+use std::collections::{BTreeSet, HashMap};
+use utoipa::ToSchema;
+
+/// Kind of the found entity.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SearchKind {
+    Group,
+    Teacher,
+    Cabinet,
+    Subject,
+}
+
+/// Entity matching the search query.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+pub struct SearchResult {
+    /// Kind of the entity.
+    pub kind: SearchKind,
+
+    /// Name of the entity as it is used in the schedule.
+    pub name: String,
+
+    /// How well the name matches the query, from 0 to 1.
+    pub score: f64,
+}
+
+/// Latin spelling of a Cyrillic letter.
+fn transliterate(c: char) -> Option<&'static str> {
+    Some(match c {
+        'а' => "a",
+        'б' => "b",
+        'в' => "v",
+        'г' => "g",
+        'д' => "d",
+        'е' | 'ё' | 'э' => "e",
+        'ж' => "zh",
+        'з' => "z",
+        'и' | 'й' => "i",
+        'к' => "k",
+        'л' => "l",
+        'м' => "m",
+        'н' => "n",
+        'о' => "o",
+        'п' => "p",
+        'р' => "r",
+        'с' => "s",
+        'т' => "t",
+        'у' => "u",
+        'ф' => "f",
+        'х' => "h",
+        'ц' => "c",
+        'ч' => "ch",
+        'ш' => "sh",
+        'щ' => "sch",
+        'ъ' | 'ь' => "",
+        'ы' => "y",
+        'ю' => "yu",
+        'я' => "ya",
+        _ => return None,
+    })
+}
+
+/// Lowercase Latin letters and digits of the text, so that "ИС-214/23" and "is 214" are comparable.
+fn fold(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+
+    for c in text.chars().flat_map(char::to_lowercase) {
+        if let Some(latin) = transliterate(c) {
+            result.push_str(latin);
+        } else if c.is_alphanumeric() {
+            result.push(c);
+        }
+    }
+
+    result
+}
+
+/// How well the name matches the already folded query, `None` if it doesn't match at all.
+fn score(query: &str, name: &str) -> Option<f64> {
+    let name = fold(name);
+
+    if name.is_empty() {
+        return None;
+    }
+
+    let coverage = query.chars().count() as f64 / name.chars().count() as f64;
+
+    if name == query {
+        return Some(1.0);
+    }
+
+    if name.starts_with(query) {
+        return Some(0.6 + 0.3 * coverage);
+    }
+
+    if name.contains(query) {
+        return Some(0.3 + 0.3 * coverage);
+    }
+
+    // typos are looked for in the beginning of the name of the same length as the query
+    let prefix: String = name.chars().take(query.chars().count()).collect();
+    let distance = strsim::levenshtein(query, &prefix);
+    let allowed = (query.chars().count() / 4).max(1);
+
+    (query.chars().count() >= 3 && distance <= allowed)
+        .then(|| 0.5 * (1.0 - distance as f64 / query.chars().count() as f64) * coverage.min(1.0))
+}
+
+/// Finds the name that differs from the query only in case, punctuation or the alphabet.
+///
+/// If several names match, the smallest one is returned, so the result doesn't depend on the order of the names.
+pub fn resolve_name<'a>(
+    names: impl IntoIterator<Item = &'a String>,
+    query: &str,
+) -> Option<&'a String> {
+    let query = fold(query);
+
+    names.into_iter().filter(|name| fold(name) == query).min()
+}
+
+/// Finds the entry with exactly this name, then the one found by [`resolve_name`].
+pub fn find_by_name<'a, V>(
+    entries: &'a HashMap<String, V>,
+    name: &str,
+) -> Option<(&'a String, &'a V)> {
+    entries
+        .get_key_value(name)
+        .or_else(|| entries.get_key_value(resolve_name(entries.keys(), name)?))
+}
+
+/// Finds groups, teachers, cabinets and subjects with names similar to the query.
+///
+/// The comparison ignores case, punctuation and the alphabet of the letters and tolerates typos.
+///
+/// # Arguments
+///
+/// * `schedule`: schedule to search in.
+/// * `query`: text entered by the user.
+/// * `limit`: maximum number of results.
+///
+/// returns: results sorted from the best match.
+pub fn search(schedule: &ParsedSchedule, query: &str, limit: usize) -> Vec<SearchResult> {
+    let query = fold(query);
+
+    if query.is_empty() {
+        return Vec::new();
+    }
+
+    let cabinets: BTreeSet<&str> = schedule
+        .groups
+        .values()
+        .flat_map(|group| &group.days)
+        .flat_map(|day| &day.lessons)
+        .filter(|lesson| lesson.lesson_type != Break)
+        .flat_map(|lesson| lesson.subgroups.iter().flatten().flatten())
+        .filter_map(|subgroup| subgroup.cabinet.as_deref())
+        .collect();
+
+    let candidates = schedule
+        .groups
+        .keys()
+        .map(|name| (SearchKind::Group, name.as_str()))
+        .chain(
+            schedule
+                .teachers
+                .keys()
+                .map(|name| (SearchKind::Teacher, name.as_str())),
+        )
+        .chain(cabinets.into_iter().map(|name| (SearchKind::Cabinet, name)))
+        .chain(
+            schedule
+                .subjects
+                .values()
+                .map(|subject| (SearchKind::Subject, subject.name.as_str())),
+        );
+
+    let mut results: Vec<SearchResult> = candidates
+        .filter_map(|(kind, name)| {
+            Some(SearchResult {
+                kind,
+                name: name.to_string(),
+                score: score(&query, name)?,
+            })
+        })
+        .collect();
+
+    results.sort_by(|a, b| {
+        b.score
+            .total_cmp(&a.score)
+            .then(a.kind.cmp(&b.kind))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    results.truncate(limit);
+
+    results
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{AcademicCalendar, ScheduleEntry};
+
+    fn schedule() -> ParsedSchedule {
+        let entry = |name: &str| {
+            (
+                name.to_string(),
+                ScheduleEntry {
+                    name: name.to_string(),
+                    days: Vec::new(),
+                },
+            )
+        };
+
+        ParsedSchedule {
+            groups: HashMap::from([entry("ИС-214/23"), entry("ИС-224/23"), entry("ЖКХ-101/24")]),
+            teachers: HashMap::from([entry("Иванов И.И."), entry("Иваненко П.С.")]),
+            subjects: HashMap::new(),
+            group_info: HashMap::new(),
+            bells: Vec::new(),
+            calendar: AcademicCalendar::default(),
+            diagnostics: Vec::new(),
+        }
+    }
+
+    fn names(query: &str) -> Vec<String> {
+        search(&schedule(), query, 10)
+            .into_iter()
+            .map(|result| result.name)
+            .collect()
+    }
+
+    #[test]
+    fn fold_names() {
+        assert_eq!(fold("ИС-214/23"), "is21423");
+        assert_eq!(fold("Иванов И.И."), "ivanovii");
+        assert_eq!(fold("is 214"), "is214");
+    }
+
+    #[test]
+    fn search_names() {
+        // "ИС-224/23" is one typo away
+        assert_eq!(names("is-214"), ["ИС-214/23", "ИС-224/23"]);
+        assert_eq!(names("ИС214/23")[0], "ИС-214/23");
+        assert_eq!(names("иванов"), ["Иванов И.И."]);
+        assert_eq!(names("иван")[0], "Иванов И.И.");
+
+        // typo
+        assert_eq!(names("ивонов"), ["Иванов И.И."]);
+
+        let results = search(&schedule(), "ис", 10);
+        assert_eq!(results.len(), 2);
+        assert!(
+            results
+                .iter()
+                .all(|result| result.kind == SearchKind::Group)
+        );
+
+        assert!(names("-/").is_empty());
+    }
+
+    #[test]
+    fn resolve() {
+        let schedule = schedule();
+
+        assert_eq!(
+            resolve_name(schedule.groups.keys(), "ис 214-23").map(String::as_str),
+            Some("ИС-214/23")
+        );
+        assert_eq!(resolve_name(schedule.groups.keys(), "ис-214"), None);
+
+        let names = ["ис 214 23".to_string(), "ИС-214/23".to_string()];
+
+        assert_eq!(resolve_name(&names, "is-214-23"), Some(&names[1]));
+        assert_eq!(
+            resolve_name(names.iter().rev(), "is-214-23"),
+            Some(&names[1])
+        );
+    }
+
+    #[test]
+    fn find() {
+        let mut entries =
+            HashMap::from([("ИС-214/23".to_string(), 1), ("ис 214 23".to_string(), 2)]);
+
+        assert_eq!(
+            find_by_name(&entries, "ис 214 23"),
+            Some((&"ис 214 23".to_string(), &2))
+        );
+        assert_eq!(
+            find_by_name(&entries, "ИС 214.23"),
+            Some((&"ИС-214/23".to_string(), &1))
+        );
+
+        entries.remove("ИС-214/23");
+        assert_eq!(
+            find_by_name(&entries, "ИС 214.23"),
+            Some((&"ис 214 23".to_string(), &2))
+        );
+        assert_eq!(find_by_name(&entries, "ИС-215"), None);
+    }
+}
@@ -0,0 +1,357 @@
+use crate::LessonType::Break;
+use crate::ScheduleEntry;
+use crate::hasher::DigestHasher;
+use serde::{Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+use utoipa::ToSchema;
+
+/// Subject from the catalogue of the schedule.
+#[derive(Clone, Debug, Serialize, Deserialize, ToSchema)]
+pub struct Subject {
+    /// Identifier, stays the same when another spelling becomes the canonical name.
+    pub id: String,
+
+    /// Canonical name.
+    pub name: String,
+
+    /// All spellings of the name found in the schedule.
+    pub spellings: Vec<String>,
+}
+
+/// Cleans up the lesson name written in the cell.
+///
+/// Whitespace is collapsed, trailing punctuation is removed
+/// and words glued to the preceding code ("МДК.05.03Тестирование") are separated.
+pub fn normalize_subject_name(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut result = String::with_capacity(name.len());
+
+    for (index, c) in chars.iter().enumerate() {
+        result.push(*c);
+
+        let next = chars.get(index + 1);
+        let after_next = chars.get(index + 2);
+
+        // "03Тестирование", "02.Производственная", but not "УП.ПМ"
+        let glued = next.is_some_and(|next| next.is_alphabetic())
+            && (c.is_ascii_digit()
+                || (*c == '.'
+                    && next.is_some_and(|next| next.is_uppercase())
+                    && after_next.is_some_and(|c| c.is_lowercase())));
+
+        if glued {
+            result.push(' ');
+        }
+    }
+
+    result
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .trim_end_matches(['.', ',', ';', ':', ' '])
+        .to_string()
+}
+
+/// Letters and digits of the name in lower case, used to compare spellings.
+pub fn subject_key(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .map(|c| if c == 'ё' { 'е' } else { c })
+        .collect()
+}
+
+/// Whether `short` is the same name as `long` with some words abbreviated ("Иностр. язык").
+fn is_abbreviation(short: &str, long: &str) -> bool {
+    let short: Vec<&str> = short.split_whitespace().collect();
+    let long: Vec<&str> = long.split_whitespace().collect();
+
+    if short.len() != long.len() {
+        return false;
+    }
+
+    let mut abbreviated = false;
+
+    for (short, long) in short.iter().zip(&long) {
+        let is_shortened = short.ends_with('.');
+        let (short, long) = (subject_key(short), subject_key(long));
+
+        if short == long {
+            continue;
+        }
+
+        if !is_shortened || short.chars().count() < 2 || !long.starts_with(&short) {
+            return false;
+        }
+
+        abbreviated = true;
+    }
+
+    abbreviated
+}
+
+/// Whether two normalized names most likely belong to the same subject.
+fn is_same_subject(a: &str, b: &str) -> bool {
+    let (a_key, b_key) = (subject_key(a), subject_key(b));
+
+    if a_key == b_key {
+        return true;
+    }
+
+    if is_abbreviation(a, b) || is_abbreviation(b, a) {
+        return true;
+    }
+
+    // "МДК.08.01" and "МДК.08.02" are different subjects
+    let digits = |key: &str| key.chars().filter(char::is_ascii_digit).collect::<String>();
+
+    if digits(&a_key) != digits(&b_key) {
+        return false;
+    }
+
+    let length = a_key.chars().count().min(b_key.chars().count());
+    let max_distance = match length {
+        0..6 => 0,
+        6..16 => 1,
+        _ => 2,
+    };
+
+    strsim::levenshtein(&a_key, &b_key) <= max_distance
+}
+
+/// Stable identifier of the subject with this [`subject_key`].
+fn subject_id(key: &str) -> String {
+    let mut hasher = DigestHasher::from(Sha1::new());
+    key.hash(&mut hasher);
+
+    hasher.finalize()[..16].to_string()
+}
+
+/// Builds the subject catalogue from all lesson names of the groups.
+///
+/// # Arguments
+///
+/// * `groups`: schedule of groups.
+/// * `aliases`: canonical names by their alternative spellings set by administrators.
+///
+/// returns: the catalogue and the subject identifier for every lesson name.
+pub fn build_subject_catalogue(
+    groups: &HashMap<String, ScheduleEntry>,
+    aliases: &HashMap<String, String>,
+) -> (HashMap<String, Subject>, HashMap<String, String>) {
+    let mut raw_names: BTreeMap<&str, usize> = BTreeMap::new();
+
+    let lessons = groups
+        .values()
+        .flat_map(|group| &group.days)
+        .flat_map(|day| &day.lessons)
+        .filter(|lesson| lesson.lesson_type != Break);
+
+    for lesson in lessons {
+        if let Some(name) = &lesson.name {
+            *raw_names.entry(name).or_default() += 1;
+        }
+    }
+
+    let mut counts: BTreeMap<String, usize> = BTreeMap::new();
+
+    for (name, count) in &raw_names {
+        *counts.entry(normalize_subject_name(name)).or_default() += count;
+    }
+
+    let names: Vec<&String> = counts.keys().collect();
+    let mut clusters: Vec<Vec<&String>> = Vec::new();
+
+    for name in names {
+        match clusters
+            .iter_mut()
+            .find(|cluster| cluster.iter().any(|other| is_same_subject(name, other)))
+        {
+            Some(cluster) => cluster.push(name),
+            None => clusters.push(vec![name]),
+        }
+    }
+
+    let aliases: HashMap<String, &String> = aliases
+        .iter()
+        .map(|(alias, canonical)| (subject_key(alias), canonical))
+        .collect();
+
+    let mut catalogue: HashMap<String, Subject> = HashMap::new();
+    let mut subject_ids: HashMap<String, String> = HashMap::new();
+
+    for cluster in clusters {
+        // the most frequent and then the most complete spelling wins
+        let canonical = *cluster
+            .iter()
+            .max_by(|a, b| {
+                counts[a.as_str()]
+                    .cmp(&counts[b.as_str()])
+                    .then(a.len().cmp(&b.len()))
+                    .then_with(|| b.cmp(a))
+            })
+            .unwrap();
+
+        let alias = cluster
+            .iter()
+            .find_map(|name| aliases.get(&subject_key(name)));
+
+        let name = alias.map_or(canonical, |alias| *alias).clone();
+
+        // the canonical name depends on the frequencies, the longest key of the cluster doesn't
+        let key = match alias {
+            Some(alias) => subject_key(alias),
+            None => cluster
+                .iter()
+                .map(|name| subject_key(name))
+                .max_by(|a, b| a.len().cmp(&b.len()).then_with(|| b.cmp(a)))
+                .unwrap(),
+        };
+
+        let id = subject_id(&key);
+
+        let subject = catalogue.entry(id.clone()).or_insert_with(|| Subject {
+            id: id.clone(),
+            name,
+            spellings: Vec::new(),
+        });
+
+        let spellings = raw_names
+            .keys()
+            .filter(|raw_name| cluster.contains(&&normalize_subject_name(raw_name)));
+
+        for raw_name in spellings {
+            subject.spellings.push(raw_name.to_string());
+            subject_ids.insert(raw_name.to_string(), id.clone());
+        }
+
+        subject.spellings.sort();
+    }
+
+    (catalogue, subject_ids)
+}
+
+/// Sets [`Lesson::subject_id`] and [`Lesson::subject`] of every lesson.
+pub fn assign_subjects(
+    entries: &mut HashMap<String, ScheduleEntry>,
+    catalogue: &HashMap<String, Subject>,
+    subject_ids: &HashMap<String, String>,
+) {
+    let lessons = entries
+        .values_mut()
+        .flat_map(|entry| &mut entry.days)
+        .flat_map(|day| &mut day.lessons);
+
+    for lesson in lessons {
+        let subject = lesson
+            .name
+            .as_ref()
+            .and_then(|name| subject_ids.get(name))
+            .and_then(|id| catalogue.get(id));
+
+        lesson.subject_id = subject.map(|subject| subject.id.clone());
+        lesson.subject = subject.map(|subject| subject.name.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Day, DayCalendar, Lesson, LessonBoundaries, LessonType};
+    use chrono::DateTime;
+
+    #[test]
+    fn normalize() {
+        assert_eq!(
+            normalize_subject_name("Компьютерные сети. "),
+            "Компьютерные сети"
+        );
+        assert_eq!(
+            normalize_subject_name("МДК.05.03Тестирование информационных систем "),
+            "МДК.05.03 Тестирование информационных систем"
+        );
+        assert_eq!(
+            normalize_subject_name("ПП.02.Производственная практика"),
+            "ПП.02. Производственная практика"
+        );
+        assert_eq!(
+            normalize_subject_name("УП.ПМ.05 Разработка"),
+            "УП.ПМ.05 Разработка"
+        );
+    }
+
+    fn groups(names: &[(&str, usize)]) -> HashMap<String, ScheduleEntry> {
+        let lessons = names
+            .iter()
+            .flat_map(|(name, count)| std::iter::repeat_n(*name, *count))
+            .map(|name| Lesson {
+                lesson_type: LessonType::Default,
+                range: Some([1, 1]),
+                name: Some(name.to_string()),
+                subject_id: None,
+                subject: None,
+                time: LessonBoundaries {
+                    start: DateTime::default(),
+                    end: DateTime::default(),
+                },
+                subgroups: None,
+                modifiers: Vec::new(),
+                raw: None,
+                building: None,
+                groups: Vec::new(),
+            })
+            .collect();
+
+        let entry = ScheduleEntry {
+            name: "ИС-214/23".to_string(),
+            days: vec![Day {
+                name: "Понедельник".to_string(),
+                street: None,
+                date: DateTime::default(),
+                lessons,
+                commutes: Vec::new(),
+                calendar: DayCalendar::default(),
+            }],
+        };
+
+        HashMap::from([(entry.name.clone(), entry)])
+    }
+
+    #[test]
+    fn stable_id() {
+        let subject = |names: &[(&str, usize)]| {
+            let (catalogue, _) = build_subject_catalogue(&groups(names), &HashMap::new());
+            assert_eq!(catalogue.len(), 1);
+
+            catalogue.into_values().next().unwrap()
+        };
+
+        let full = subject(&[("Иностранный язык", 3), ("Иностр. язык", 1)]);
+        let short = subject(&[("Иностранный язык", 1), ("Иностр. язык", 3)]);
+
+        assert_eq!(full.name, "Иностранный язык");
+        assert_eq!(short.name, "Иностр. язык");
+        assert_eq!(full.id, short.id);
+    }
+
+    #[test]
+    fn same_subject() {
+        assert!(is_same_subject("Биология", "Билогия"));
+        assert!(is_same_subject(
+            "Физическая культура",
+            "Физизческая культура"
+        ));
+        assert!(is_same_subject(
+            "Россия - моя история",
+            "Россия -моя история"
+        ));
+        assert!(is_same_subject("Иностр. язык", "Иностранный язык"));
+        assert!(!is_same_subject("Информ язык", "Информационный язык"));
+
+        assert!(!is_same_subject("МДК.08.01 Дизайн", "МДК.08.02 Дизайн"));
+        assert!(!is_same_subject("Физика", "Химия"));
+        assert!(!is_same_subject("Литература", "Родная литература"));
+    }
+}
@@ -0,0 +1,705 @@
+use crate::LessonType::Break;
+use crate::{
+    Day, DayCalendar, Diagnostic, DiagnosticKind, Lesson, LessonBoundaries, ScheduleEntry,
+};
+use chrono::{DateTime, Utc};
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap};
+
+/// Placeholder written in the schedule instead of the teacher name.
+pub const SCHEDULE_ERROR_TEACHER: &str = "Ошибка в расписании";
+
+/// Latin letters that look the same as Cyrillic ones.
+const LOOKALIKES: [(char, char); 19] = [
+    ('A', 'А'),
+    ('B', 'В'),
+    ('C', 'С'),
+    ('E', 'Е'),
+    ('H', 'Н'),
+    ('K', 'К'),
+    ('M', 'М'),
+    ('O', 'О'),
+    ('P', 'Р'),
+    ('T', 'Т'),
+    ('X', 'Х'),
+    ('Y', 'У'),
+    ('a', 'а'),
+    ('c', 'с'),
+    ('e', 'е'),
+    ('k', 'к'),
+    ('o', 'о'),
+    ('p', 'р'),
+    ('x', 'х'),
+];
+
+/// Brings the teacher name to the "Фамилия И.О." form.
+///
+/// Latin lookalike letters are replaced with Cyrillic ones, whitespace and dots between initials
+/// are normalized. Names without initials are returned with the normalized surname only.
+pub fn normalize_teacher_name(name: &str) -> String {
+    let name: String = name
+        .chars()
+        .map(|c| {
+            LOOKALIKES
+                .iter()
+                .find(|(latin, _)| *latin == c)
+                .map_or(c, |(_, cyrillic)| *cyrillic)
+        })
+        .collect();
+
+    let mut words = name
+        .split(|c: char| c.is_whitespace() || c == '.')
+        .filter(|word| !word.is_empty());
+
+    let Some(surname) = words.next() else {
+        return String::new();
+    };
+
+    // every part of a double surname is capitalized
+    let surname = surname
+        .split('-')
+        .map(|part| {
+            let mut chars = part.chars();
+
+            chars
+                .next()
+                .into_iter()
+                .flat_map(char::to_uppercase)
+                .chain(chars.flat_map(char::to_lowercase))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("-");
+
+    // "ИО" is two initials, "Иван" is one.
+    let initials: String = words
+        .flat_map(|word| {
+            let initials: Vec<char> = if word.chars().all(char::is_uppercase) {
+                word.chars().collect()
+            } else {
+                word.chars().take(1).flat_map(char::to_uppercase).collect()
+            };
+
+            initials
+        })
+        .map(|initial| format!("{initial}."))
+        .collect();
+
+    if initials.is_empty() {
+        surname
+    } else {
+        format!("{surname} {initials}")
+    }
+}
+
+/// Splits the normalized name into the surname and initials.
+fn split_name(name: &str) -> (&str, &str) {
+    name.split_once(' ').unwrap_or((name, ""))
+}
+
+/// Whether two normalized names most likely belong to the same teacher.
+fn is_same_teacher(a: &str, b: &str) -> bool {
+    let (a_surname, a_initials) = split_name(a);
+    let (b_surname, b_initials) = split_name(b);
+
+    if a_surname == b_surname {
+        return !a_initials.is_empty()
+            && !b_initials.is_empty()
+            && (a_initials.starts_with(b_initials) || b_initials.starts_with(a_initials));
+    }
+
+    if a_initials != b_initials || a_surname.chars().count().min(b_surname.chars().count()) < 5 {
+        return false;
+    }
+
+    // "Иванов" and "Иванова" are different people more often than a typo.
+    let feminine = |short: &str, long: &str| long.strip_suffix('а') == Some(short);
+
+    !feminine(a_surname, b_surname)
+        && !feminine(b_surname, a_surname)
+        && strsim::levenshtein(a_surname, b_surname) <= 1
+}
+
+/// Groups near-duplicate normalized names and picks a canonical one for each group.
+///
+/// A name with partial initials is merged only if there is exactly one name it can be completed to.
+///
+/// # Arguments
+///
+/// * `counts`: normalized names with the number of lessons of each of them.
+///
+/// returns: canonical name for every name that should be replaced.
+pub fn cluster_teacher_names(counts: &HashMap<String, usize>) -> HashMap<String, String> {
+    let mut names: Vec<&String> = counts.keys().collect();
+    names.sort();
+
+    let mut parents: Vec<usize> = (0..names.len()).collect();
+
+    fn root(parents: &mut [usize], mut index: usize) -> usize {
+        while parents[index] != index {
+            parents[index] = parents[parents[index]];
+            index = parents[index];
+        }
+
+        index
+    }
+
+    let initials_len = |i: usize| split_name(names[i]).1.len();
+
+    // number of names that partial initials can be completed to
+    let completions: Vec<usize> = (0..names.len())
+        .map(|i| {
+            (0..names.len())
+                .filter(|j| initials_len(*j) > initials_len(i))
+                .filter(|j| is_same_teacher(names[i], names[*j]))
+                .count()
+        })
+        .collect();
+
+    for i in 0..names.len() {
+        for j in (i + 1)..names.len() {
+            if !is_same_teacher(names[i], names[j]) {
+                continue;
+            }
+
+            let partial = match initials_len(i).cmp(&initials_len(j)) {
+                Ordering::Less => Some(i),
+                Ordering::Greater => Some(j),
+                Ordering::Equal => None,
+            };
+
+            if partial.is_some_and(|partial| completions[partial] > 1) {
+                continue;
+            }
+
+            let (a, b) = (root(&mut parents, i), root(&mut parents, j));
+            parents[a] = b;
+        }
+    }
+
+    let mut clusters: HashMap<usize, Vec<&String>> = HashMap::new();
+
+    for (i, name) in names.iter().enumerate() {
+        clusters
+            .entry(root(&mut parents, i))
+            .or_default()
+            .push(name);
+    }
+
+    let mut result = HashMap::new();
+
+    for cluster in clusters.into_values().filter(|cluster| cluster.len() > 1) {
+        // the most complete and the most frequent spelling wins
+        let canonical = *cluster
+            .iter()
+            .max_by(|a, b| {
+                let key = |name: &str| (split_name(name).1.len(), counts[name]);
+
+                key(a).cmp(&key(b)).then_with(|| b.cmp(a))
+            })
+            .unwrap();
+
+        for name in cluster {
+            if name != canonical {
+                result.insert(name.clone(), canonical.clone());
+            }
+        }
+    }
+
+    result
+}
+
+/// Conversion of the list of couples of groups in the list of lessons of teachers.
+///
+/// Overlapping lessons of the same teacher are reported to `diagnostics`.
+pub fn convert_groups_to_teachers(
+    groups: &HashMap<String, ScheduleEntry>,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> HashMap<String, ScheduleEntry> {
+    let mut teachers: HashMap<String, ScheduleEntry> = HashMap::new();
+
+    let empty_days: Vec<Day> = groups
+        .values()
+        .next()
+        .unwrap()
+        .days
+        .iter()
+        .map(|day| Day {
+            name: day.name.clone(),
+            street: day.street.clone(),
+            date: day.date,
+            lessons: vec![],
+            commutes: Vec::new(),
+            calendar: DayCalendar::default(),
+        })
+        .collect();
+
+    for group in groups.values() {
+        for (index, day) in group.days.iter().enumerate() {
+            for group_lesson in &day.lessons {
+                if group_lesson.lesson_type == Break {
+                    continue;
+                }
+
+                if group_lesson.subgroups.is_none() {
+                    continue;
+                }
+
+                let subgroups = group_lesson.subgroups.as_ref().unwrap();
+
+                for subgroup in subgroups {
+                    let teacher = match subgroup {
+                        None => continue,
+                        Some(subgroup) => match &subgroup.teacher {
+                            None => continue,
+                            Some(teacher) => teacher,
+                        },
+                    };
+
+                    if teacher == SCHEDULE_ERROR_TEACHER {
+                        continue;
+                    }
+
+                    if !teachers.contains_key(teacher) {
+                        teachers.insert(
+                            teacher.clone(),
+                            ScheduleEntry {
+                                name: teacher.clone(),
+                                days: empty_days.to_vec(),
+                            },
+                        );
+                    }
+
+                    let teacher_day = teachers
+                        .get_mut(teacher)
+                        .unwrap()
+                        .days
+                        .get_mut(index)
+                        .unwrap();
+
+                    // the same lesson of a stream of groups
+                    let joint_lesson = teacher_day.lessons.iter_mut().find(|lesson| {
+                        is_same_lesson(lesson, group_lesson)
+                            && teacher_cabinets(lesson, teacher)
+                                == teacher_cabinets(group_lesson, teacher)
+                    });
+
+                    match joint_lesson {
+                        // the teacher may teach both subgroups of the lesson
+                        Some(lesson) => {
+                            if !lesson.groups.contains(&group.name) {
+                                lesson.groups.push(group.name.clone());
+                            }
+                        }
+                        None => teacher_day.lessons.push({
+                            let mut lesson = group_lesson.clone();
+                            lesson.groups = vec![group.name.clone()];
+
+                            lesson
+                        }),
+                    }
+                }
+            }
+        }
+    }
+
+    // BTreeMap to keep the order of diagnostics stable
+    let sorted_teachers: BTreeMap<_, _> = teachers.iter_mut().collect();
+
+    for teacher in sorted_teachers.into_values() {
+        for day in &mut teacher.days {
+            for lesson in &mut day.lessons {
+                lesson.groups.sort();
+            }
+
+            // additional lessons have no index, so only the time is reliable
+            day.lessons.sort_by(|a, b| {
+                a.time
+                    .start
+                    .cmp(&b.time.start)
+                    .then(a.time.end.cmp(&b.time.end))
+                    .then_with(|| a.name.cmp(&b.name))
+                    .then_with(|| a.groups.cmp(&b.groups))
+            });
+
+            diagnostics.extend(find_overlaps(&teacher.name, day));
+            insert_breaks(day);
+        }
+    }
+
+    teachers
+}
+
+/// Reports lessons of the teacher that take place at the same time.
+///
+/// Lessons of the day must be sorted by their start.
+fn find_overlaps(teacher: &str, day: &Day) -> Vec<Diagnostic> {
+    let describe = |lesson: &Lesson| {
+        format!(
+            "\"{}\" ({})",
+            lesson.name.as_deref().unwrap_or_default(),
+            lesson.groups.join(", ")
+        )
+    };
+
+    day.lessons
+        .windows(2)
+        .filter(|pair| pair[1].time.start < pair[0].time.end)
+        .map(|pair| Diagnostic {
+            kind: DiagnosticKind::TeacherOverlap,
+            message: format!(
+                "Teacher \"{teacher}\" has overlapping lessons {} and {} on {}.",
+                describe(&pair[0]),
+                describe(&pair[1]),
+                day.name
+            ),
+        })
+        .collect()
+}
+
+/// Inserts breaks between consecutive lessons the same way group days have them.
+///
+/// Lessons of the day must be sorted by their start.
+pub(crate) fn insert_breaks(day: &mut Day) {
+    let mut lessons = Vec::with_capacity(day.lessons.len() * 2);
+    let mut end: Option<DateTime<Utc>> = None;
+
+    for lesson in day.lessons.drain(..) {
+        if let Some(end) = end
+            && end < lesson.time.start
+        {
+            lessons.push(Lesson {
+                lesson_type: Break,
+                range: None,
+                name: None,
+                subject_id: None,
+                subject: None,
+                time: LessonBoundaries {
+                    start: end,
+                    end: lesson.time.start,
+                },
+                subgroups: Some(Vec::new()),
+                modifiers: Vec::new(),
+                raw: None,
+                building: None,
+                groups: Vec::new(),
+            });
+        }
+
+        end = end.max(Some(lesson.time.end));
+        lessons.push(lesson);
+    }
+
+    day.lessons = lessons;
+}
+
+/// Whether two lessons take place at the same time and have the same name.
+pub(crate) fn is_same_lesson(a: &Lesson, b: &Lesson) -> bool {
+    a.time.start == b.time.start && a.time.end == b.time.end && a.name == b.name
+}
+
+/// Cabinets of every subgroup the teacher conducts the lesson for.
+///
+/// Empty if the teacher doesn't conduct the lesson.
+fn teacher_cabinets<'a>(lesson: &'a Lesson, teacher: &str) -> Vec<&'a Option<String>> {
+    lesson
+        .subgroups
+        .iter()
+        .flatten()
+        .flatten()
+        .filter(|subgroup| subgroup.teacher.as_deref() == Some(teacher))
+        .map(|subgroup| &subgroup.cabinet)
+        .collect()
+}
+
+/// Fills [`Lesson::groups`] of the group lessons with other groups attending them.
+///
+/// # Arguments
+///
+/// * `groups`: schedule of groups.
+/// * `teachers`: schedule of teachers built from it by [`convert_groups_to_teachers`].
+pub fn link_joint_lessons(
+    groups: &mut HashMap<String, ScheduleEntry>,
+    teachers: &HashMap<String, ScheduleEntry>,
+) {
+    for group in groups.values_mut() {
+        for lesson in group.days.iter_mut().flat_map(|day| &mut day.lessons) {
+            lesson.groups.clear();
+        }
+    }
+
+    for teacher in teachers.values() {
+        for (index, day) in teacher.days.iter().enumerate() {
+            for joint_lesson in day.lessons.iter().filter(|lesson| lesson.groups.len() > 1) {
+                for name in &joint_lesson.groups {
+                    let Some(group) = groups.get_mut(name) else {
+                        continue;
+                    };
+
+                    let lessons = group.days[index].lessons.iter_mut().filter(|lesson| {
+                        is_same_lesson(lesson, joint_lesson)
+                            && teacher_cabinets(lesson, &teacher.name)
+                                == teacher_cabinets(joint_lesson, &teacher.name)
+                    });
+
+                    for lesson in lessons {
+                        let others = joint_lesson.groups.iter().filter(|other| *other != name);
+
+                        for other in others {
+                            if !lesson.groups.contains(other) {
+                                lesson.groups.push(other.clone());
+                            }
+                        }
+
+                        lesson.groups.sort();
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LessonSubGroup, LessonType};
+    use chrono::TimeDelta;
+
+    #[test]
+    fn normalize() {
+        assert_eq!(normalize_teacher_name("Хомченко Н.Е."), "Хомченко Н.Е.");
+        assert_eq!(normalize_teacher_name("Хомченко  Н. Е"), "Хомченко Н.Е.");
+        assert_eq!(normalize_teacher_name("Хомченко НЕ"), "Хомченко Н.Е.");
+        // Latin "X", "o" and "E"
+        assert_eq!(normalize_teacher_name("Xoмченко Н.E."), "Хомченко Н.Е.");
+        assert_eq!(normalize_teacher_name("Хомченко"), "Хомченко");
+        assert_eq!(
+            normalize_teacher_name("Петров-Водкин А.А."),
+            "Петров-Водкин А.А."
+        );
+        assert_eq!(
+            normalize_teacher_name("ПЕТРОВ-ВОДКИН  А. А"),
+            "Петров-Водкин А.А."
+        );
+    }
+
+    #[test]
+    fn cluster() {
+        let counts = HashMap::from([
+            ("Хомченко Н.Е.".to_string(), 10),
+            ("Хоменко Н.Е.".to_string(), 1),
+            ("Хомченко Н.".to_string(), 2),
+            ("Иванов И.".to_string(), 1),
+            ("Иванов И.А.".to_string(), 3),
+            ("Иванов И.Б.".to_string(), 3),
+            ("Петров А.А.".to_string(), 3),
+            ("Петрова А.А.".to_string(), 3),
+        ]);
+
+        let merges = cluster_teacher_names(&counts);
+
+        assert_eq!(merges.len(), 2);
+        assert_eq!(merges["Хоменко Н.Е."], "Хомченко Н.Е.");
+        assert_eq!(merges["Хомченко Н."], "Хомченко Н.Е.");
+    }
+
+    fn lesson(name: &str, teacher: &str, cabinet: &str) -> Lesson {
+        Lesson {
+            lesson_type: LessonType::Default,
+            range: Some([1, 1]),
+            name: Some(name.to_string()),
+            subject_id: None,
+            subject: None,
+            time: LessonBoundaries {
+                start: DateTime::default(),
+                end: DateTime::default() + TimeDelta::minutes(90),
+            },
+            subgroups: Some(vec![Some(LessonSubGroup {
+                cabinet: Some(cabinet.to_string()),
+                teacher: Some(teacher.to_string()),
+            })]),
+            modifiers: Vec::new(),
+            raw: None,
+            building: None,
+            groups: Vec::new(),
+        }
+    }
+
+    fn at(mut lesson: Lesson, start: i64, end: i64) -> Lesson {
+        lesson.time = LessonBoundaries {
+            start: DateTime::default() + TimeDelta::minutes(start),
+            end: DateTime::default() + TimeDelta::minutes(end),
+        };
+
+        lesson
+    }
+
+    fn group(name: &str, lessons: Vec<Lesson>) -> (String, ScheduleEntry) {
+        let entry = ScheduleEntry {
+            name: name.to_string(),
+            days: vec![Day {
+                name: "Понедельник".to_string(),
+                street: None,
+                date: DateTime::default(),
+                lessons,
+                commutes: Vec::new(),
+                calendar: DayCalendar::default(),
+            }],
+        };
+
+        (name.to_string(), entry)
+    }
+
+    #[test]
+    fn joint_lessons() {
+        let mut groups = HashMap::from([
+            group("ИС-214/23", vec![lesson("История", "Иванов И.И.", "101")]),
+            group("ИС-224/23", vec![lesson("История", "Иванов И.И.", "101")]),
+            group("ИС-234/23", vec![lesson("История", "Иванов И.И.", "102")]),
+        ]);
+
+        let teachers = convert_groups_to_teachers(&groups, &mut Vec::new());
+        link_joint_lessons(&mut groups, &teachers);
+
+        let lessons = &teachers["Иванов И.И."].days[0].lessons;
+        assert_eq!(lessons.len(), 2);
+
+        let joint = lessons.iter().find(|lesson| lesson.groups.len() == 2);
+        assert_eq!(joint.unwrap().groups, ["ИС-214/23", "ИС-224/23"]);
+
+        assert_eq!(groups["ИС-214/23"].days[0].lessons[0].groups, ["ИС-224/23"]);
+        assert_eq!(groups["ИС-224/23"].days[0].lessons[0].groups, ["ИС-214/23"]);
+        assert!(groups["ИС-234/23"].days[0].lessons[0].groups.is_empty());
+    }
+
+    #[test]
+    fn both_subgroups() {
+        let both = |first: &str, second: &str| {
+            let mut lesson = lesson("Информатика", "Иванов И.И.", first);
+            let subgroups = lesson.subgroups.as_mut().unwrap();
+
+            subgroups.push(subgroups[0].clone());
+            subgroups[1].as_mut().unwrap().cabinet = Some(second.to_string());
+
+            lesson
+        };
+
+        let mut groups = HashMap::from([
+            group("ИС-214/23", vec![both("101", "102")]),
+            group("ИС-224/23", vec![both("101", "102")]),
+            group("ИС-234/23", vec![both("101", "103")]),
+        ]);
+
+        let teachers = convert_groups_to_teachers(&groups, &mut Vec::new());
+        link_joint_lessons(&mut groups, &teachers);
+
+        let lessons = &teachers["Иванов И.И."].days[0].lessons;
+        assert_eq!(lessons.len(), 2);
+
+        let groups_of = |index: usize| lessons[index].groups.clone();
+        let mut all = vec![groups_of(0), groups_of(1)];
+        all.sort();
+
+        // the second cabinet of "ИС-234/23" differs, so the lesson isn't joint
+        assert_eq!(all, [vec!["ИС-214/23", "ИС-224/23"], vec!["ИС-234/23"]]);
+        assert_eq!(groups["ИС-214/23"].days[0].lessons[0].groups, ["ИС-224/23"]);
+        assert!(groups["ИС-234/23"].days[0].lessons[0].groups.is_empty());
+    }
+
+    #[test]
+    fn order_by_start() {
+        let mut additional = at(lesson("О важном", "Иванов И.И.", "101"), 0, 30);
+        additional.lesson_type = LessonType::Additional;
+        additional.range = None;
+
+        let groups = HashMap::from([
+            group(
+                "ИС-214/23",
+                vec![at(lesson("История", "Иванов И.И.", "101"), 120, 210)],
+            ),
+            group("ИС-224/23", vec![additional]),
+            group(
+                "ИС-234/23",
+                vec![at(lesson("Право", "Иванов И.И.", "101"), 40, 110)],
+            ),
+        ]);
+
+        let teachers = convert_groups_to_teachers(&groups, &mut Vec::new());
+
+        let names: Vec<_> = teachers["Иванов И.И."].days[0]
+            .lessons
+            .iter()
+            .filter(|lesson| lesson.lesson_type != Break)
+            .map(|lesson| lesson.name.as_deref().unwrap())
+            .collect();
+
+        assert_eq!(names, ["О важном", "Право", "История"]);
+    }
+
+    #[test]
+    fn breaks() {
+        let groups = HashMap::from([
+            group(
+                "ИС-214/23",
+                vec![
+                    at(lesson("История", "Иванов И.И.", "101"), 0, 90),
+                    at(lesson("Право", "Петров П.П.", "102"), 100, 190),
+                ],
+            ),
+            group(
+                "ИС-224/23",
+                vec![at(lesson("Право", "Иванов И.И.", "101"), 200, 290)],
+            ),
+        ]);
+
+        let teachers = convert_groups_to_teachers(&groups, &mut Vec::new());
+        let lessons = &teachers["Иванов И.И."].days[0].lessons;
+
+        assert_eq!(lessons.len(), 3);
+        assert_eq!(lessons[1].lesson_type, Break);
+        assert_eq!(lessons[1].time.start, lessons[0].time.end);
+        assert_eq!(lessons[1].time.end, lessons[2].time.start);
+
+        assert_eq!(teachers["Петров П.П."].days[0].lessons.len(), 1);
+    }
+
+    #[test]
+    fn back_to_back() {
+        let groups = HashMap::from([group(
+            "ИС-214/23",
+            vec![
+                at(lesson("История", "Иванов И.И.", "101"), 0, 90),
+                at(lesson("Право", "Иванов И.И.", "101"), 90, 180),
+            ],
+        )]);
+
+        let teachers = convert_groups_to_teachers(&groups, &mut Vec::new());
+        let lessons = &teachers["Иванов И.И."].days[0].lessons;
+
+        assert_eq!(lessons.len(), 2);
+        assert!(lessons.iter().all(|lesson| lesson.lesson_type != Break));
+    }
+
+    #[test]
+    fn overlaps() {
+        let groups = HashMap::from([
+            group(
+                "ИС-214/23",
+                vec![at(lesson("История", "Иванов И.И.", "101"), 0, 90)],
+            ),
+            group(
+                "ИС-224/23",
+                vec![at(lesson("Право", "Иванов И.И.", "102"), 60, 150)],
+            ),
+        ]);
+
+        let mut diagnostics = Vec::new();
+        let teachers = convert_groups_to_teachers(&groups, &mut diagnostics);
+
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].kind, DiagnosticKind::TeacherOverlap);
+
+        // no break between overlapping lessons
+        assert_eq!(teachers["Иванов И.И."].days[0].lessons.len(), 2);
+    }
+}
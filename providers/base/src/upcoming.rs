@@ -0,0 +1,173 @@
+use crate::{Lesson, LessonType, ScheduleEntry};
+use chrono::{DateTime, Utc};
+use chrono_tz::Tz;
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// Lessons of the group or teacher around the current moment.
+#[derive(Clone, Debug, Serialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Upcoming {
+    /// Name of the group or teacher.
+    pub name: String,
+
+    /// Lesson or break taking place right now.
+    pub current: Option<Lesson>,
+
+    /// The nearest lesson that hasn't started yet, possibly on one of the next days.
+    pub next: Option<Lesson>,
+
+    /// Seconds left until the current lesson or break ends,
+    /// or until the next lesson starts if there is nothing right now.
+    pub remaining: Option<i64>,
+
+    /// Lessons and breaks of today that haven't started yet.
+    pub rest_of_today: Vec<Lesson>,
+}
+
+/// Finds the current and the next lessons of the entry.
+///
+/// # Arguments
+///
+/// * `entry`: schedule of the group or teacher.
+/// * `now`: the current moment.
+/// * `timezone`: timezone of the provider, used to find today's lessons.
+pub fn upcoming(entry: &ScheduleEntry, now: DateTime<Utc>, timezone: Tz) -> Upcoming {
+    let lessons = || entry.days.iter().flat_map(|day| &day.lessons);
+
+    // a lesson wins over the break written at the same time
+    let current = lessons()
+        .filter(|lesson| lesson.time.start <= now && now < lesson.time.end)
+        .min_by_key(|lesson| lesson.lesson_type == LessonType::Break)
+        .cloned();
+
+    let next = lessons()
+        .filter(|lesson| lesson.lesson_type != LessonType::Break && lesson.time.start > now)
+        .min_by_key(|lesson| lesson.time.start)
+        .cloned();
+
+    let remaining = match (&current, &next) {
+        (Some(current), _) => Some((current.time.end - now).num_seconds()),
+        (None, Some(next)) => Some((next.time.start - now).num_seconds()),
+        (None, None) => None,
+    };
+
+    let today = now.with_timezone(&timezone).date_naive();
+
+    let rest_of_today = entry
+        .days
+        .iter()
+        .filter(|day| day.date.with_timezone(&timezone).date_naive() == today)
+        .flat_map(|day| &day.lessons)
+        .filter(|lesson| lesson.time.start > now)
+        .cloned()
+        .collect();
+
+    Upcoming {
+        name: entry.name.clone(),
+        current,
+        next,
+        remaining,
+        rest_of_today,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Day, DayCalendar, LessonBoundaries};
+    use chrono::{TimeDelta, TimeZone};
+
+    const TIMEZONE: Tz = chrono_tz::Europe::Saratov;
+
+    fn at(day: u32, hour: u32, minute: u32) -> DateTime<Utc> {
+        TIMEZONE
+            .with_ymd_and_hms(2025, 9, day, hour, minute, 0)
+            .unwrap()
+            .to_utc()
+    }
+
+    fn lesson(lesson_type: LessonType, name: &str, start: DateTime<Utc>, minutes: i64) -> Lesson {
+        Lesson {
+            lesson_type,
+            range: None,
+            name: Some(name.to_string()),
+            subject_id: None,
+            subject: None,
+            time: LessonBoundaries {
+                start,
+                end: start + TimeDelta::minutes(minutes),
+            },
+            subgroups: None,
+            modifiers: Vec::new(),
+            raw: None,
+            building: None,
+            groups: Vec::new(),
+        }
+    }
+
+    fn entry() -> ScheduleEntry {
+        let day = |day: u32, lessons: Vec<Lesson>| Day {
+            name: "Понедельник".to_string(),
+            street: None,
+            date: at(day, 0, 0),
+            lessons,
+            commutes: Vec::new(),
+            calendar: DayCalendar::default(),
+        };
+
+        ScheduleEntry {
+            name: "ИС-214/23".to_string(),
+            days: vec![
+                day(
+                    1,
+                    vec![
+                        lesson(LessonType::Default, "История", at(1, 8, 0), 90),
+                        lesson(LessonType::Break, "", at(1, 9, 30), 10),
+                        lesson(LessonType::Default, "Физика", at(1, 9, 40), 90),
+                    ],
+                ),
+                day(
+                    2,
+                    vec![lesson(LessonType::Default, "Химия", at(2, 8, 0), 90)],
+                ),
+            ],
+        }
+    }
+
+    fn name(lesson: &Option<Lesson>) -> Option<&str> {
+        lesson.as_ref().and_then(|lesson| lesson.name.as_deref())
+    }
+
+    #[test]
+    fn during_lesson() {
+        let upcoming = upcoming(&entry(), at(1, 9, 0), TIMEZONE);
+
+        assert_eq!(name(&upcoming.current), Some("История"));
+        assert_eq!(name(&upcoming.next), Some("Физика"));
+        assert_eq!(upcoming.remaining, Some(30 * 60));
+        assert_eq!(upcoming.rest_of_today.len(), 2);
+    }
+
+    #[test]
+    fn during_break() {
+        let upcoming = upcoming(&entry(), at(1, 9, 35), TIMEZONE);
+
+        assert_eq!(
+            upcoming.current.map(|lesson| lesson.lesson_type),
+            Some(LessonType::Break)
+        );
+        assert_eq!(name(&upcoming.next), Some("Физика"));
+        assert_eq!(upcoming.remaining, Some(5 * 60));
+    }
+
+    #[test]
+    fn after_lessons() {
+        let upcoming = upcoming(&entry(), at(1, 18, 0), TIMEZONE);
+
+        assert!(upcoming.current.is_none());
+        assert_eq!(name(&upcoming.next), Some("Химия"));
+        assert_eq!(upcoming.remaining, Some(14 * 60 * 60));
+        assert!(upcoming.rest_of_today.is_empty());
+    }
+}
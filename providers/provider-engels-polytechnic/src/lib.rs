@@ -1,9 +1,12 @@
+pub use crate::parser::SheetLayout;
 pub use crate::updater::{UpdateSource, Updater};
 use async_trait::async_trait;
-use base::{ScheduleProvider, ScheduleSnapshot};
+use base::{
+    AcademicCalendar, HistoryError, ProviderSettings, ScheduleAliases, ScheduleProvider,
+    ScheduleSnapshot, SnapshotEntry, SnapshotHistory,
+};
 use std::ops::DerefMut;
 use std::sync::Arc;
-use std::time::Duration;
 use tokio::sync::RwLock;
 use tokio::time::interval;
 use tokio_util::sync::CancellationToken;
@@ -17,28 +20,35 @@ pub mod test_utils {
     pub use crate::parser::test_utils::test_result;
 }
 
+/// Number of snapshots kept for pinning and rollbacks.
+const HISTORY_SIZE: usize = 10;
+
 pub struct EngelsPolytechnicProvider {
     updater: Updater,
-    snapshot: Arc<ScheduleSnapshot>,
+    history: SnapshotHistory,
 }
 
 impl EngelsPolytechnicProvider {
     pub async fn get(
         update_source: UpdateSource,
+        settings: ProviderSettings,
+        layout: SheetLayout,
     ) -> Result<Arc<dyn ScheduleProvider>, crate::updater::Error> {
-        let (updater, snapshot) = Updater::new(update_source).await?;
+        let (updater, snapshot) = Updater::new(update_source, layout, settings.timezone).await?;
 
         Ok(Arc::new(Wrapper {
             inner: RwLock::new(Self {
                 updater,
-                snapshot: Arc::new(snapshot),
+                history: SnapshotHistory::new(snapshot, HISTORY_SIZE, settings.clone()),
             }),
+            settings,
         }))
     }
 }
 
 pub struct Wrapper {
     inner: RwLock<EngelsPolytechnicProvider>,
+    settings: ProviderSettings,
 }
 
 #[async_trait]
@@ -47,7 +57,7 @@ impl ScheduleProvider for Wrapper {
         &self,
         cancellation_token: CancellationToken,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
-        let mut ticker = interval(Duration::from_secs(60 * 30));
+        let mut ticker = interval(self.settings.update_interval);
         ticker.tick().await; // bc we already have the latest schedule, when instantiating provider
 
         loop {
@@ -58,9 +68,9 @@ impl ScheduleProvider for Wrapper {
 
                     log::info!("Updating schedule...");
 
-                    match this.updater.update(&this.snapshot).await {
+                    match this.updater.update(&this.history.newest()).await {
                         Ok(snapshot) => {
-                            this.snapshot = Arc::new(snapshot);
+                            this.history.push(snapshot);
                         },
 
                         Err(updater::Error::EmptyUri) => {},
@@ -78,7 +88,35 @@ impl ScheduleProvider for Wrapper {
         }
     }
 
+    fn settings(&self) -> &ProviderSettings {
+        &self.settings
+    }
+
     async fn get_schedule(&self) -> Arc<ScheduleSnapshot> {
-        self.inner.read().await.snapshot.clone()
+        self.inner.read().await.history.active()
+    }
+
+    async fn get_snapshots(&self) -> Vec<SnapshotEntry> {
+        self.inner.read().await.history.list()
+    }
+
+    async fn pin_snapshot(&self, hash: &str) -> Result<Arc<ScheduleSnapshot>, HistoryError> {
+        self.inner.write().await.history.pin(hash)
+    }
+
+    async fn unpin_snapshot(&self) -> Arc<ScheduleSnapshot> {
+        self.inner.write().await.history.unpin()
+    }
+
+    async fn rollback_snapshot(&self) -> Result<Arc<ScheduleSnapshot>, HistoryError> {
+        self.inner.write().await.history.rollback()
+    }
+
+    async fn set_aliases(&self, aliases: ScheduleAliases) {
+        self.inner.write().await.history.set_aliases(aliases)
+    }
+
+    async fn set_calendar(&self, calendar: AcademicCalendar) {
+        self.inner.write().await.history.set_calendar(calendar)
     }
 }
@@ -0,0 +1,91 @@
+use base::GroupInfo;
+use chrono::{Datelike, NaiveDate};
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// "ИС-214/23": specialty, number and the last digits of the intake year.
+static GROUP_NAME: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"^(?<specialty>[А-ЯЁA-Z]+)-(?<number>[0-9]+)(?:/(?<year>[0-9]{2}|[0-9]{4}))?$")
+        .unwrap()
+});
+
+/// Extracts the specialty, number, intake year and course from the group name.
+///
+/// # Arguments
+///
+/// * `name`: group name without spaces.
+/// * `date`: date of the schedule week, the course is calculated for it.
+///
+/// returns: `None` if the name doesn't follow the usual format.
+pub fn parse_group_name(name: &str, date: NaiveDate) -> Option<GroupInfo> {
+    let name = name.to_uppercase();
+    let captures = GROUP_NAME.captures(&name)?;
+
+    let specialty = captures["specialty"].to_string();
+    let number = captures["number"].to_string();
+
+    let year = match captures.name("year") {
+        Some(year) => {
+            let year: i32 = year.as_str().parse().ok()?;
+
+            Some(if year < 100 { 2000 + year } else { year })
+        }
+        None => None,
+    };
+
+    // the academic year starts in September
+    let academic_year = if date.month() >= 9 {
+        date.year()
+    } else {
+        date.year() - 1
+    };
+
+    let course = match year {
+        Some(year) => u32::try_from(academic_year - year + 1).ok(),
+        None => number.chars().next()?.to_digit(10),
+    }
+    .filter(|course| (1..=6).contains(course));
+
+    Some(GroupInfo {
+        specialty,
+        number,
+        year,
+        course,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, 1).unwrap()
+    }
+
+    #[test]
+    fn group_name() {
+        let info = parse_group_name("ИС-214/23", date(2025, 10)).unwrap();
+        assert_eq!(info.specialty, "ИС");
+        assert_eq!(info.number, "214");
+        assert_eq!(info.year, Some(2023));
+        assert_eq!(info.course, Some(3));
+
+        // spring semester of the same academic year
+        let info = parse_group_name("ИС-214/23", date(2026, 3)).unwrap();
+        assert_eq!(info.course, Some(3));
+
+        let info = parse_group_name("ЖКХ-101", date(2025, 10)).unwrap();
+        assert_eq!(info.specialty, "ЖКХ");
+        assert_eq!(info.year, None);
+        assert_eq!(info.course, Some(1));
+
+        assert!(parse_group_name("Консультации", date(2025, 10)).is_none());
+    }
+
+    #[test]
+    fn non_ascii_digits() {
+        // Arabic-Indic and full-width digits are not group numbers
+        assert!(parse_group_name("ИС-٢١٤/٢٣", date(2025, 10)).is_none());
+        assert!(parse_group_name("ИС-２１４", date(2025, 10)).is_none());
+    }
+}
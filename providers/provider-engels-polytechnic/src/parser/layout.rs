@@ -0,0 +1,138 @@
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+use regex::Regex;
+use serde::{Deserialize, Deserializer};
+
+/// Description of the schedule sheet structure.
+///
+/// All fields are optional in the config, missing ones are taken from the Engels Polytechnic sheet.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct SheetLayout {
+    /// Column with day names and dates.
+    pub day_column: u32,
+
+    /// Column with lesson numbers and times.
+    pub time_column: u32,
+
+    /// Offset of the first group column from the first column of the sheet.
+    pub group_column_offset: u32,
+
+    /// Offset of the cabinet column from the column of its group.
+    pub cabinet_offset: u32,
+
+    /// Pattern of the day cell with `name` and `date` groups.
+    ///
+    /// The first cell in the day column that doesn't match it ends the week.
+    #[serde(deserialize_with = "deserialize_regex")]
+    pub day_pattern: Regex,
+
+    /// Format of the `date` group of [`SheetLayout::day_pattern`] in the chrono syntax.
+    pub date_format: String,
+
+    /// Pattern of the time cell with `start_hour`, `start_minute`, `end_hour` and `end_minute` groups.
+    #[serde(deserialize_with = "deserialize_regex")]
+    pub time_pattern: Regex,
+
+    /// Text in the time cell marking a numbered lesson, the number is the first character of the cell.
+    ///
+    /// Cells without it describe additional lessons.
+    pub lesson_marker: String,
+
+    /// Pattern of the group cell containing an address of another building instead of a lesson.
+    #[serde(deserialize_with = "deserialize_regex")]
+    pub street_pattern: Regex,
+}
+
+impl Default for SheetLayout {
+    fn default() -> Self {
+        Self {
+            day_column: 0,
+            time_column: 1,
+            group_column_offset: 2,
+            cabinet_offset: 1,
+            day_pattern: Regex::new(r"(?s)^(?<name>\S{5,})\s(?<date>.*)$").unwrap(),
+            date_format: "%d.%m.%Y".to_string(),
+            time_pattern: Regex::new(
+                r"(?<start_hour>\d+)\.(?<start_minute>\d+)-(?<end_hour>\d+)\.(?<end_minute>\d+)",
+            )
+            .unwrap(),
+            lesson_marker: "пара".to_string(),
+            street_pattern: Regex::new(r"^[А-Я][а-я]+[,\s]+д\.\s\d+$").unwrap(),
+        }
+    }
+}
+
+impl SheetLayout {
+    /// Splits the day cell into the day name and its date.
+    ///
+    /// Returns `None` if the cell is not a day cell.
+    /// The date is `None` if it can't be parsed.
+    pub fn parse_day(&self, text: &str) -> Option<(String, Option<DateTime<Utc>>)> {
+        let captures = self.day_pattern.captures(text)?;
+
+        let name = captures.name("name")?.as_str().to_string();
+        let date = captures
+            .name("date")
+            .and_then(|date| NaiveDate::parse_from_str(date.as_str(), &self.date_format).ok())
+            .map(|date| date.and_time(NaiveTime::default()).and_utc());
+
+        Some((name, date))
+    }
+
+    /// Reads the local start and end time of the lesson from the time cell.
+    pub fn parse_time(&self, text: &str) -> Option<(NaiveTime, NaiveTime)> {
+        let captures = self.time_pattern.captures(text)?;
+
+        let get = |name: &str| captures.name(name)?.as_str().parse::<u32>().ok();
+
+        Some((
+            NaiveTime::from_hms_opt(get("start_hour")?, get("start_minute")?, 0)?,
+            NaiveTime::from_hms_opt(get("end_hour")?, get("end_minute")?, 0)?,
+        ))
+    }
+}
+
+fn deserialize_regex<'de, D>(deserializer: D) -> Result<Regex, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let pattern = String::deserialize(deserializer)?;
+
+    Regex::new(&pattern).map_err(serde::de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_day() {
+        let layout = SheetLayout::default();
+
+        let (name, date) = layout.parse_day("Понедельник 01.09.2025").unwrap();
+        assert_eq!(name, "Понедельник");
+        assert_eq!(
+            date.unwrap().date_naive(),
+            NaiveDate::from_ymd_opt(2025, 9, 1).unwrap()
+        );
+
+        let (_, date) = layout.parse_day("Вторник 02.09").unwrap();
+        assert!(date.is_none());
+
+        assert!(layout.parse_day("Зам. директора").is_none());
+    }
+
+    #[test]
+    fn parse_time() {
+        let layout = SheetLayout::default();
+
+        assert_eq!(
+            layout.parse_time("1 пара\n8.30-10.00"),
+            Some((
+                NaiveTime::from_hms_opt(8, 30, 0).unwrap(),
+                NaiveTime::from_hms_opt(10, 0, 0).unwrap()
+            ))
+        );
+        assert_eq!(layout.parse_time("Классный час"), None);
+    }
+}
@@ -1,20 +1,28 @@
 pub use self::error::{Error, Result};
+pub use self::layout::SheetLayout;
 use crate::or_continue;
+use crate::parser::group_name::parse_group_name;
 use crate::parser::worksheet::{CellPos, CellRange, WorkSheet};
 use crate::parser::LessonParseResult::{Lessons, Street};
 use base::LessonType::Break;
+use crate::parser::modifiers::extract_modifiers;
 use base::{
-    Day, Lesson, LessonBoundaries, LessonSubGroup, LessonType, ParsedSchedule, ScheduleEntry,
+    convert_groups_to_teachers, link_joint_lessons, AcademicCalendar, Bell, BellDay, Day,
+    DayCalendar, Diagnostic, DiagnosticKind, Lesson, LessonBoundaries, LessonModifier,
+    LessonSubGroup, LessonType, ParsedSchedule, ScheduleEntry,
 };
 use calamine::{open_workbook_from_rs, Reader, Xls};
-use chrono::{DateTime, Duration, NaiveDate, NaiveTime, Utc};
-use regex::Regex;
+use chrono::{DateTime, Duration, NaiveDate, TimeZone, Utc};
+use chrono_tz::Tz;
 use std::collections::HashMap;
 use std::io::Cursor;
 use std::sync::LazyLock;
 
 mod error;
+mod group_name;
+mod layout;
 mod macros;
+mod modifiers;
 mod worksheet;
 
 /// Data cell storing the group name.
@@ -31,9 +39,6 @@ pub struct DayMarkup {
     /// Line index.
     pub row: u32,
 
-    /// Column index.
-    pub column: u32,
-
     /// Day name.
     pub name: String,
 
@@ -62,7 +67,7 @@ pub struct BoundariesData {
 }
 
 /// Obtaining a "skeleton" schedule from the working sheet.
-fn parse_markup(worksheet: &WorkSheet) -> Result<WorkSheetMarkup> {
+fn parse_markup(worksheet: &WorkSheet, layout: &SheetLayout) -> Result<WorkSheetMarkup> {
     struct PartialDayMarkup {
         row: u32,
         name: String,
@@ -80,14 +85,14 @@ fn parse_markup(worksheet: &WorkSheet) -> Result<WorkSheetMarkup> {
     while row < end_row {
         row += 1;
 
-        let day_full_name = or_continue!(worksheet.get_string_from_cell(row, 0));
+        let day_full_name = or_continue!(worksheet.get_string_from_cell(row, layout.day_column));
 
         // parse groups row when days column will found
         if groups.is_empty() {
             // переход на предыдущую строку
             row -= 1;
 
-            for column in (start_col + 2)..=end_col {
+            for column in (start_col + layout.group_column_offset)..=end_col {
                 groups.push(GroupMarkup {
                     column,
                     name: or_continue!(worksheet.get_string_from_cell(row, column))
@@ -99,26 +104,8 @@ fn parse_markup(worksheet: &WorkSheet) -> Result<WorkSheetMarkup> {
             row += 1;
         }
 
-        let (day_name, day_date) = {
-            let space_index = match day_full_name.find(' ') {
-                Some(index) => {
-                    if index < 10 {
-                        break;
-                    } else {
-                        index
-                    }
-                }
-                None => break,
-            };
-
-            let name = day_full_name[..space_index].to_string();
-
-            let date_slice = &day_full_name[space_index + 1..];
-            let date = NaiveDate::parse_from_str(date_slice, "%d.%m.%Y")
-                .map(|date| date.and_time(NaiveTime::default()).and_utc())
-                .ok();
-
-            (name, date)
+        let Some((day_name, day_date)) = layout.parse_day(&day_full_name) else {
+            break;
         };
 
         days.push(PartialDayMarkup {
@@ -149,7 +136,6 @@ fn parse_markup(worksheet: &WorkSheet) -> Result<WorkSheetMarkup> {
         .into_iter()
         .map(|day| DayMarkup {
             row: day.row,
-            column: 0,
             name: day.name,
             date: day.date.unwrap(),
         })
@@ -207,19 +193,18 @@ fn parse_lesson(
     day_boundaries: &[BoundariesData],
     lesson_boundaries: &BoundariesData,
     group_column: u32,
+    layout: &SheetLayout,
+    diagnostics: &mut Vec<Diagnostic>,
 ) -> Result<LessonParseResult> {
     let row = lesson_boundaries.range.start.row;
 
-    let name = {
+    let raw = {
         let cell_data = match worksheet.get_string_from_cell(row, group_column) {
             Some(x) => x,
             None => return Ok(Lessons(Vec::new())),
         };
 
-        static OTHER_STREET_RE: LazyLock<Regex> =
-            LazyLock::new(|| Regex::new(r"^[А-Я][а-я]+[,\s]+д\.\s\d+$").unwrap());
-
-        if OTHER_STREET_RE.is_match(&cell_data) {
+        if layout.street_pattern.is_match(&cell_data) {
             return Ok(Street(cell_data));
         }
 
@@ -243,12 +228,9 @@ fn parse_lesson(
             .first()
             .ok_or(Error::LessonTimeNotFound(CellPos::new(row, group_column)))?;
 
-        let range: Option<[u8; 2]> = if lesson_boundaries.default_index.is_some() {
-            let default = lesson_boundaries.default_index.unwrap() as u8;
-            Some([default, end_time.default_index.unwrap() as u8])
-        } else {
-            None
-        };
+        let range: Option<[u8; 2]> = lesson_boundaries
+            .default_index
+            .map(|default| [default as u8, end_time.default_index.unwrap() as u8]);
 
         let time = LessonBoundaries {
             start: lesson_boundaries.time_range.start,
@@ -262,13 +244,14 @@ fn parse_lesson(
         name,
         mut subgroups,
         r#type: lesson_type,
-    } = parse_name_and_subgroups(&name, row, group_column)?;
+        modifiers,
+    } = parse_name_and_subgroups(&raw, day.date.date_naive(), row, group_column, diagnostics)?;
 
     {
         let cabinets: Vec<String> = parse_cabinets(
             worksheet,
             (lesson_cell_range.start.row, lesson_cell_range.end.row),
-            group_column + 1,
+            group_column + layout.cabinet_offset,
         );
 
         let cab_count = cabinets.len();
@@ -308,13 +291,18 @@ fn parse_lesson(
         lesson_type: lesson_type.unwrap_or(lesson_boundaries.lesson_type.clone()),
         range: default_range,
         name: Some(name),
+        subject_id: None,
+        subject: None,
         time: lesson_time,
         subgroups: if subgroups.len() == 2 && subgroups.iter().all(|x| x.is_none()) {
             None
         } else {
             Some(subgroups)
         },
-        group: None,
+        modifiers,
+        raw: Some(raw),
+        building: None,
+        groups: Vec::new(),
     };
 
     let prev_lesson = if day.lessons.is_empty() {
@@ -328,12 +316,17 @@ fn parse_lesson(
             lesson_type: Break,
             range: None,
             name: None,
+            subject_id: None,
+            subject: None,
             time: LessonBoundaries {
                 start: prev_lesson.time.end,
                 end: lesson.time.start,
             },
             subgroups: Some(Vec::new()),
-            group: None,
+            modifiers: Vec::new(),
+            raw: None,
+            building: None,
+            groups: Vec::new(),
         },
         lesson,
     ])))
@@ -361,15 +354,45 @@ fn parse_cabinets(worksheet: &WorkSheet, row_range: (u32, u32), column: u32) ->
     cabinets
 }
 
+/// Whether the character is kept in the text the lesson name is parsed from.
+fn is_name_char(c: &char) -> bool {
+    c.is_whitespace()
+        || c.is_ascii_digit()
+        || (*c >= 'а' && *c <= 'я')
+        || (*c >= 'А' && *c <= 'Я')
+        || *c == '.'
+        || *c == '-'
+}
+
+/// Part of the cell text that follows the first `count` characters kept by [`is_name_char`].
+fn original_tail(original: &str, count: usize) -> &str {
+    if count == 0 {
+        return original;
+    }
+
+    original
+        .char_indices()
+        .filter(|(_, c)| is_name_char(c))
+        .nth(count - 1)
+        .map_or("", |(i, c)| &original[i + c.len_utf8()..])
+}
+
 struct ParsedLessonName {
     name: String,
     subgroups: Vec<Option<LessonSubGroup>>,
     r#type: Option<LessonType>,
+    modifiers: Vec<LessonModifier>,
 }
 
 //noinspection GrazieInspection
 /// Getting the "pure" name of the lesson and list of teachers from the text of the lesson cell.
-fn parse_name_and_subgroups(text: &str, row: u32, column: u32) -> Result<ParsedLessonName> {
+fn parse_name_and_subgroups(
+    text: &str,
+    date: NaiveDate,
+    row: u32,
+    column: u32,
+    diagnostics: &mut Vec<Diagnostic>,
+) -> Result<ParsedLessonName> {
     // Части названия пары:
     // 1. Само название.
     // 2. Список преподавателей и подгрупп.
@@ -378,26 +401,22 @@ fn parse_name_and_subgroups(text: &str, row: u32, column: u32) -> Result<ParsedL
     // Регулярное выражение для получения ФИО преподавателей и номеров подгрупп (aka. второй части).
     static NAME_RE: LazyLock<fancy_regex::Regex> = LazyLock::new(|| {
         fancy_regex::Regex::new(
-            r"([А-Я][а-я]+(?:[\s.]*[А-Я]){1,2})(?=[^А-Яа-я])[.\s]*(?:\(?(\d)[\sа-я]*\)?)?",
+            r"([А-Я][а-я]+(?:[\s.]*[А-Я]){1,2})(?=[^А-Яа-я]|$)[.\s]*(?:\(?(\d)[\sа-я]*\)?)?",
         )
         .unwrap()
     });
 
-    let text = text
+    let (original, mut modifiers) = extract_modifiers(text, date);
+
+    let text = original
         .chars()
-        .filter(|c: &char| {
-            c.is_whitespace()
-                || c.is_ascii_digit()
-                || (*c >= 'а' && *c <= 'я')
-                || (*c >= 'А' && *c <= 'Я')
-                || *c == '.'
-                || *c == '-'
-        })
+        .filter(is_name_char)
         .collect::<String>()
         .replace(r"\s+", " ");
 
     let mut lesson_name: Option<&str> = None;
     let mut extra: Option<&str> = None;
+    let mut extra_start: Option<usize> = None;
 
     let mut shared_subgroup = true;
     let mut subgroups: [Option<LessonSubGroup>; 2] = [None, None];
@@ -410,6 +429,7 @@ fn parse_name_and_subgroups(text: &str, row: u32, column: u32) -> Result<ParsedL
         }
 
         extra = Some(&text[capture.get(0).unwrap().end()..]);
+        extra_start = Some(capture.get(0).unwrap().end());
 
         let teacher_name = {
             let clean = capture
@@ -485,20 +505,21 @@ fn parse_name_and_subgroups(text: &str, row: u32, column: u32) -> Result<ParsedL
         let result = guess_lesson_type(extra);
 
         if result.is_none() {
-            #[cfg(not(debug_assertions))]
-            sentry::capture_error(&Error::UnknownLessonType {
+            let error = Error::UnknownLessonType {
                 r#type: extra.to_string(),
                 pos: CellPos::new(row, column),
+            };
+
+            diagnostics.push(Diagnostic {
+                kind: DiagnosticKind::UnknownLessonType,
+                message: error.to_string(),
             });
 
+            #[cfg(not(debug_assertions))]
+            sentry::capture_error(&error);
+
             #[cfg(debug_assertions)]
-            log::warn!(
-                "{}",
-                Error::UnknownLessonType {
-                    r#type: extra.to_string(),
-                    pos: CellPos::new(row, column),
-                }
-            );
+            log::warn!("{}", error);
         }
 
         result
@@ -506,39 +527,58 @@ fn parse_name_and_subgroups(text: &str, row: u32, column: u32) -> Result<ParsedL
         None
     };
 
+    // the filtered text has lost the latin letters and punctuation of the remainder,
+    // without teachers the extra text is a part of the name
+    if lesson_type.is_none()
+        && let Some(start) = extra_start
+    {
+        // the spaces matched after the teacher may stand for the removed characters
+        let extra = original_tail(&original, text[..start].trim_end().chars().count())
+            .trim_matches(|c: char| c.is_whitespace() || c == '.');
+
+        // the closing bracket of the subgroup number isn't an annotation
+        if extra.chars().any(char::is_alphanumeric) {
+            modifiers.push(LessonModifier::Raw {
+                text: extra.to_string(),
+            });
+        }
+    }
+
     Ok(ParsedLessonName {
         name: lesson_name.unwrap_or(&text).to_string(),
         subgroups,
         r#type: lesson_type,
+        modifiers,
     })
 }
 
-/// Getting the start and end of a pair from a cell in the first column of a document.
+/// Getting the start and end of a pair from a cell in the time column of a document.
 ///
 /// # Arguments
 ///
 /// * `cell_data`: text in cell.
 /// * `date`: date of the current day.
-fn parse_lesson_boundaries_cell(cell_data: &str, date: DateTime<Utc>) -> Option<LessonBoundaries> {
-    static TIME_RE: LazyLock<Regex> =
-        LazyLock::new(|| Regex::new(r"(\d+\.\d+)-(\d+\.\d+)").unwrap());
-
-    let parse_res = TIME_RE.captures(cell_data)?;
-
-    let start_match = parse_res.get(1).unwrap().as_str();
-    let start_parts: Vec<&str> = start_match.split(".").collect();
-
-    let end_match = parse_res.get(2).unwrap().as_str();
-    let end_parts: Vec<&str> = end_match.split(".").collect();
-
-    static GET_TIME: fn(DateTime<Utc>, &Vec<&str>) -> DateTime<Utc> = |date, parts| {
-        date + Duration::hours(parts[0].parse::<i64>().unwrap() - 4)
-            + Duration::minutes(parts[1].parse::<i64>().unwrap())
+/// * `layout`: structure of the document.
+/// * `timezone`: timezone in which the times are written.
+fn parse_lesson_boundaries_cell(
+    cell_data: &str,
+    date: DateTime<Utc>,
+    layout: &SheetLayout,
+    timezone: Tz,
+) -> Option<LessonBoundaries> {
+    let (start, end) = layout.parse_time(cell_data)?;
+    let date = date.date_naive();
+
+    let to_utc = |time| {
+        timezone
+            .from_local_datetime(&date.and_time(time))
+            .earliest()
+            .map(|time| time.to_utc())
     };
 
     Some(LessonBoundaries {
-        start: GET_TIME(date, &start_parts),
-        end: GET_TIME(date, &end_parts),
+        start: to_utc(start)?,
+        end: to_utc(end)?,
     })
 }
 
@@ -549,13 +589,17 @@ fn parse_lesson_boundaries_cell(cell_data: &str, date: DateTime<Utc>) -> Option<
 /// * `worksheet`: document.
 /// * `date`: date of the current day.
 /// * `row_range`: row boundaries of the current day.
-/// * `column`: column with the required data.
+/// * `layout`: structure of the document.
+/// * `timezone`: timezone in which the times are written.
 fn parse_day_boundaries(
     worksheet: &WorkSheet,
     date: DateTime<Utc>,
     row_range: (u32, u32),
-    column: u32,
+    layout: &SheetLayout,
+    timezone: Tz,
 ) -> Result<Vec<BoundariesData>> {
+    let column = layout.time_column;
+
     let mut day_times: Vec<BoundariesData> = Vec::new();
 
     for row in row_range.0..row_range.1 {
@@ -565,11 +609,11 @@ fn parse_day_boundaries(
             continue;
         };
 
-        let lesson_time = parse_lesson_boundaries_cell(&time_cell, date)
+        let lesson_time = parse_lesson_boundaries_cell(&time_cell, date, layout, timezone)
             .ok_or(Error::NoLessonBoundaries(CellPos::new(row, column)))?;
 
         // type
-        let lesson_type = if time_cell.contains("пара") {
+        let lesson_type = if time_cell.contains(&layout.lesson_marker) {
             LessonType::Default
         } else {
             LessonType::Additional
@@ -607,14 +651,17 @@ fn parse_day_boundaries(
 ///
 /// * `worksheet`: document.
 /// * `week_markup`: markup of the current week.
+/// * `layout`: structure of the document.
+/// * `timezone`: timezone in which the times are written.
 fn parse_week_boundaries(
     worksheet: &WorkSheet,
     week_markup: &[DayMarkup],
+    layout: &SheetLayout,
+    timezone: Tz,
 ) -> Result<Vec<Vec<BoundariesData>>> {
     let mut result: Vec<Vec<BoundariesData>> = Vec::new();
 
     let worksheet_end_row = worksheet.end().unwrap().0;
-    let lesson_time_column = week_markup[0].column + 1;
 
     for day_index in 0..week_markup.len() {
         let day_markup = &week_markup[day_index];
@@ -631,7 +678,8 @@ fn parse_week_boundaries(
             worksheet,
             day_markup.date,
             (day_markup.row, end_row),
-            lesson_time_column,
+            layout,
+            timezone,
         )?;
 
         result.push(day_boundaries);
@@ -640,98 +688,16 @@ fn parse_week_boundaries(
     Ok(result)
 }
 
-/// Conversion of the list of couples of groups in the list of lessons of teachers.
-fn convert_groups_to_teachers(
-    groups: &HashMap<String, ScheduleEntry>,
-) -> HashMap<String, ScheduleEntry> {
-    let mut teachers: HashMap<String, ScheduleEntry> = HashMap::new();
-
-    let empty_days: Vec<Day> = groups
-        .values()
-        .next()
-        .unwrap()
-        .days
-        .iter()
-        .map(|day| Day {
-            name: day.name.clone(),
-            street: day.street.clone(),
-            date: day.date,
-            lessons: vec![],
-        })
-        .collect();
-
-    for group in groups.values() {
-        for (index, day) in group.days.iter().enumerate() {
-            for group_lesson in &day.lessons {
-                if group_lesson.lesson_type == Break {
-                    continue;
-                }
-
-                if group_lesson.subgroups.is_none() {
-                    continue;
-                }
-
-                let subgroups = group_lesson.subgroups.as_ref().unwrap();
-
-                for subgroup in subgroups {
-                    let teacher = match subgroup {
-                        None => continue,
-                        Some(subgroup) => match &subgroup.teacher {
-                            None => continue,
-                            Some(teacher) => teacher,
-                        },
-                    };
-
-                    if teacher == "Ошибка в расписании" {
-                        continue;
-                    }
-
-                    if !teachers.contains_key(teacher) {
-                        teachers.insert(
-                            teacher.clone(),
-                            ScheduleEntry {
-                                name: teacher.clone(),
-                                days: empty_days.to_vec(),
-                            },
-                        );
-                    }
-
-                    let teacher_day = teachers
-                        .get_mut(teacher)
-                        .unwrap()
-                        .days
-                        .get_mut(index)
-                        .unwrap();
-
-                    teacher_day.lessons.push({
-                        let mut lesson = group_lesson.clone();
-                        lesson.group = Some(group.name.clone());
-
-                        lesson
-                    });
-                }
-            }
-        }
-    }
-
-    teachers.iter_mut().for_each(|(_, teacher)| {
-        teacher.days.iter_mut().for_each(|day| {
-            day.lessons
-                .sort_by(|a, b| a.range.as_ref().unwrap()[1].cmp(&b.range.as_ref().unwrap()[1]))
-        })
-    });
-
-    teachers
-}
-
 /// Reading XLS Document from the buffer and converting it into the schedule ready to use.
 ///
 /// # Arguments
 ///
 /// * `buffer`: XLS data containing schedule.
+/// * `layout`: structure of the document.
+/// * `timezone`: timezone in which the times are written.
 ///
 /// returns: Result<ParseResult, Error>
-pub fn parse_xls(buffer: &Vec<u8>) -> Result<ParsedSchedule> {
+pub fn parse_xls(buffer: &Vec<u8>, layout: &SheetLayout, timezone: Tz) -> Result<ParsedSchedule> {
     let cursor = Cursor::new(&buffer);
     let mut workbook: Xls<_> = open_workbook_from_rs(cursor)?;
 
@@ -755,11 +721,12 @@ pub fn parse_xls(buffer: &Vec<u8>) -> Result<ParsedSchedule> {
     let WorkSheetMarkup {
         days: week_markup,
         groups: groups_markup,
-    } = parse_markup(&worksheet)?;
+    } = parse_markup(&worksheet, layout)?;
 
-    let week_boundaries = parse_week_boundaries(&worksheet, &week_markup)?;
+    let week_boundaries = parse_week_boundaries(&worksheet, &week_markup, layout, timezone)?;
 
     let mut groups: HashMap<String, ScheduleEntry> = HashMap::new();
+    let mut diagnostics: Vec<Diagnostic> = Vec::new();
 
     for group_markup in groups_markup {
         let mut group = ScheduleEntry {
@@ -775,6 +742,8 @@ pub fn parse_xls(buffer: &Vec<u8>) -> Result<ParsedSchedule> {
                 street: None,
                 date: day_markup.date,
                 lessons: Vec::new(),
+                commutes: Vec::new(),
+                calendar: DayCalendar::default(),
             };
 
             let day_boundaries = &week_boundaries[day_index];
@@ -786,6 +755,8 @@ pub fn parse_xls(buffer: &Vec<u8>) -> Result<ParsedSchedule> {
                     day_boundaries,
                     lesson_boundaries,
                     group_markup.column,
+                    layout,
+                    &mut diagnostics,
                 )? {
                     Lessons(lesson) => day.lessons.append(lesson),
                     Street(street) => day.street = Some(street.to_owned()),
@@ -798,9 +769,46 @@ pub fn parse_xls(buffer: &Vec<u8>) -> Result<ParsedSchedule> {
         groups.insert(group.name.clone(), group);
     }
 
+    let teachers = convert_groups_to_teachers(&groups, &mut diagnostics);
+    link_joint_lessons(&mut groups, &teachers);
+
+    let group_info = match week_markup.first() {
+        Some(day_markup) => {
+            let date = day_markup.date.with_timezone(&timezone).date_naive();
+
+            groups
+                .keys()
+                .filter_map(|name| Some((name.clone(), parse_group_name(name, date)?)))
+                .collect()
+        }
+        None => HashMap::new(),
+    };
+
+    let bells = week_markup
+        .iter()
+        .zip(week_boundaries)
+        .map(|(day_markup, day_boundaries)| BellDay {
+            name: day_markup.name.clone(),
+            date: day_markup.date,
+            bells: day_boundaries
+                .into_iter()
+                .map(|boundaries| Bell {
+                    index: boundaries.default_index,
+                    lesson_type: boundaries.lesson_type,
+                    time: boundaries.time_range,
+                })
+                .collect(),
+        })
+        .collect();
+
     Ok(ParsedSchedule {
-        teachers: convert_groups_to_teachers(&groups),
+        teachers,
         groups,
+        subjects: HashMap::new(),
+        group_info,
+        bells,
+        calendar: AcademicCalendar::default(),
+        diagnostics,
     })
 }
 
@@ -810,7 +818,11 @@ pub mod test_utils {
     use base::ParsedSchedule;
 
     pub fn test_result() -> Result<ParsedSchedule> {
-        parse_xls(&include_bytes!("../../../../test-data/engels-polytechnic.xls").to_vec())
+        parse_xls(
+            &include_bytes!("../../../../test-data/engels-polytechnic.xls").to_vec(),
+            &SheetLayout::default(),
+            chrono_tz::Europe::Saratov,
+        )
     }
 }
 
@@ -856,4 +868,55 @@ pub mod tests {
             Some("43".to_string())
         );
     }
+
+    #[test]
+    fn raw_remainder() {
+        let parse = |text| {
+            let date = chrono::NaiveDate::from_ymd_opt(2025, 9, 1).unwrap();
+
+            super::parse_name_and_subgroups(text, date, 0, 0, &mut Vec::new())
+                .unwrap()
+                .modifiers
+        };
+
+        let raw = |text: &str| base::LessonModifier::Raw {
+            text: text.to_string(),
+        };
+
+        assert_eq!(
+            parse("Информатика Иванов И.И. (MS Teams!)"),
+            vec![raw("(MS Teams!)")]
+        );
+        assert_eq!(parse("Информатика Иванов И.И. ДО"), vec![raw("ДО")]);
+        assert_eq!(parse("Информатика Иванов И.И."), vec![]);
+        assert_eq!(parse("Информатика Иванов И.Н"), vec![]);
+        assert_eq!(parse("Информатика Иванов И.И. (1 подгруппа)"), vec![]);
+    }
+
+    #[test]
+    fn bells() {
+        let result = super::test_utils::test_result().unwrap();
+
+        let group = result.groups.get("ИС-214/23").unwrap();
+        assert_eq!(result.bells.len(), group.days.len());
+
+        for (bell_day, day) in result.bells.iter().zip(&group.days) {
+            assert_eq!(bell_day.date, day.date);
+            assert_ne!(bell_day.bells.len(), 0);
+
+            let boundaries = bell_day.boundaries().unwrap();
+
+            for lesson in &day.lessons {
+                assert!(boundaries.start <= lesson.time.start);
+                assert!(lesson.time.end <= boundaries.end);
+            }
+        }
+
+        let first = result.bells[0]
+            .bells
+            .iter()
+            .find(|bell| bell.lesson_type == base::LessonType::Default)
+            .unwrap();
+        assert_eq!(first.index, Some(1));
+    }
 }
@@ -0,0 +1,178 @@
+use base::LessonModifier;
+use chrono::{Datelike, NaiveDate};
+use regex::{Captures, Regex};
+use std::ops::Range;
+use std::sync::LazyLock;
+
+type Recognizer = fn(&Captures, NaiveDate) -> Option<LessonModifier>;
+
+/// Patterns of the known annotations with functions converting their matches.
+static PATTERNS: LazyLock<Vec<(Regex, Recognizer)>> = LazyLock::new(|| {
+    let pattern = |pattern: &str| Regex::new(pattern).unwrap();
+
+    vec![
+        (
+            pattern(r"(?i)\(\s*лаб[а-я.\s]*\)|\bлаб\b\.?"),
+            |_, _| Some(LessonModifier::Lab),
+        ),
+        (
+            pattern(r"(?i)\(?\b(?:дистанционно|дистант|онлайн|online)\b\)?"),
+            |_, _| Some(LessonModifier::Online),
+        ),
+        (
+            pattern(r"(?i)\(?\b(?:(?<number>[12])\s*|(?<parity>не)?четн[а-я]*\s+)нед[а-я]*\.?\)?"),
+            |captures, _| {
+                let odd = match captures.name("number") {
+                    Some(number) => number.as_str() == "1",
+                    None => captures.name("parity").is_some(),
+                };
+
+                Some(if odd {
+                    LessonModifier::OddWeeks
+                } else {
+                    LessonModifier::EvenWeeks
+                })
+            },
+        ),
+        (
+            pattern(
+                r"(?i)\(?\b(?<kind>с|по)\s*(?<day>\d{1,2})\.(?<month>\d{1,2})(?:\.(?<year>\d{2,4}))?\)?",
+            ),
+            |captures, near| {
+                let date = parse_date(captures, near)?;
+
+                Some(if captures["kind"].to_lowercase() == "с" {
+                    LessonModifier::Since { date }
+                } else {
+                    LessonModifier::Until { date }
+                })
+            },
+        ),
+    ]
+});
+
+/// Reads the date from the match, picking the year closest to `near` if it's omitted.
+fn parse_date(captures: &Captures, near: NaiveDate) -> Option<NaiveDate> {
+    let day = captures["day"].parse().ok()?;
+    let month = captures["month"].parse().ok()?;
+
+    if let Some(year) = captures.name("year") {
+        let year: i32 = year.as_str().parse().ok()?;
+        let year = if year < 100 { year + 2000 } else { year };
+
+        return NaiveDate::from_ymd_opt(year, month, day);
+    }
+
+    (near.year() - 1..=near.year() + 1)
+        .filter_map(|year| NaiveDate::from_ymd_opt(year, month, day))
+        .min_by_key(|date| (*date - near).num_days().abs())
+}
+
+/// Finds known annotations in the lesson cell.
+///
+/// # Arguments
+///
+/// * `text`: text of the lesson cell.
+/// * `date`: date of the lesson, used to guess the year of dates in annotations.
+///
+/// returns: text without the recognized annotations and the annotations in the order of appearance.
+pub fn extract_modifiers(text: &str, date: NaiveDate) -> (String, Vec<LessonModifier>) {
+    let mut found: Vec<(Range<usize>, LessonModifier)> = Vec::new();
+
+    for (pattern, recognize) in PATTERNS.iter() {
+        for captures in pattern.captures_iter(text) {
+            let range = captures.get(0).unwrap().range();
+
+            if found
+                .iter()
+                .any(|(other, _)| other.start < range.end && range.start < other.end)
+            {
+                continue;
+            }
+
+            if let Some(modifier) = recognize(&captures, date) {
+                found.push((range, modifier));
+            }
+        }
+    }
+
+    found.sort_by_key(|(range, _)| range.start);
+
+    let mut rest = String::with_capacity(text.len());
+    let mut position = 0;
+
+    for (range, _) in &found {
+        rest.push_str(&text[position..range.start]);
+        rest.push(' ');
+
+        position = range.end;
+    }
+
+    rest.push_str(&text[position..]);
+
+    (
+        rest,
+        found.into_iter().map(|(_, modifier)| modifier).collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn extract(text: &str) -> (String, Vec<LessonModifier>) {
+        let (text, modifiers) =
+            extract_modifiers(text, NaiveDate::from_ymd_opt(2025, 9, 1).unwrap());
+
+        (
+            text.split_whitespace().collect::<Vec<_>>().join(" "),
+            modifiers,
+        )
+    }
+
+    #[test]
+    fn modifiers() {
+        assert_eq!(
+            extract("Физика (лаб) Иванов И.И. дистант"),
+            (
+                "Физика Иванов И.И.".to_string(),
+                vec![LessonModifier::Lab, LessonModifier::Online]
+            )
+        );
+
+        assert_eq!(
+            extract("История Петров П.П. 1 нед."),
+            (
+                "История Петров П.П.".to_string(),
+                vec![LessonModifier::OddWeeks]
+            )
+        );
+
+        assert_eq!(
+            extract("История Петров П.П. (четная неделя)").1,
+            [LessonModifier::EvenWeeks]
+        );
+
+        assert_eq!(
+            extract("Право Сидоров С.С. с 15.09 по 20.01").1,
+            [
+                LessonModifier::Since {
+                    date: NaiveDate::from_ymd_opt(2025, 9, 15).unwrap()
+                },
+                LessonModifier::Until {
+                    date: NaiveDate::from_ymd_opt(2026, 1, 20).unwrap()
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn no_modifiers() {
+        let text = "Производственная практика ПП.ПМ.05 \"Газовая сварка (наплавка)\" Щербаков С.В.";
+
+        assert_eq!(
+            extract_modifiers(text, NaiveDate::default()),
+            (text.to_string(), vec![])
+        );
+    }
+}
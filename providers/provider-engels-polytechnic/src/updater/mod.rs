@@ -1,11 +1,12 @@
 pub use self::error::{Error, Result};
-use crate::parser::parse_xls;
+use crate::parser::{SheetLayout, parse_xls};
 use crate::xls_downloader::{FetchError, XlsDownloader};
 use base::ScheduleSnapshot;
+use chrono_tz::Tz;
 mod error;
 
 pub enum UpdateSource {
-    Prepared(ScheduleSnapshot),
+    Prepared(Box<ScheduleSnapshot>),
 
     Url(String),
 
@@ -18,6 +19,8 @@ pub enum UpdateSource {
 pub struct Updater {
     downloader: XlsDownloader,
     update_source: UpdateSource,
+    layout: SheetLayout,
+    timezone: Tz,
 }
 
 impl Updater {
@@ -31,9 +34,16 @@ impl Updater {
     ///
     /// * `downloader`: A mutable reference to an `XLSDownloader` implementation used to fetch and parse the schedule data.
     /// * `url`: The source URL pointing to the XLS file containing schedule data.
+    /// * `layout`: Structure of the XLS file.
+    /// * `timezone`: Timezone in which the schedule is written.
     ///
     /// returns: Result<ScheduleSnapshot, SnapshotCreationError>
-    async fn new_snapshot(downloader: &mut XlsDownloader, url: String) -> Result<ScheduleSnapshot> {
+    async fn new_snapshot(
+        downloader: &mut XlsDownloader,
+        url: String,
+        layout: &SheetLayout,
+        timezone: Tz,
+    ) -> Result<ScheduleSnapshot> {
         let head_result = downloader.set_url(&url).await.map_err(|error| {
             if let FetchError::Reqwest(error) = &error {
                 sentry::capture_error(&error);
@@ -59,13 +69,14 @@ impl Updater {
             .data
             .unwrap();
 
-        let parse_result = parse_xls(&xls_data)?;
+        let parse_result = parse_xls(&xls_data, layout, timezone)?;
 
         Ok(ScheduleSnapshot {
             fetched_at: head_result.requested_at,
             updated_at: head_result.uploaded_at,
             url,
             data: parse_result,
+            cached_hash: None,
         })
     }
 
@@ -136,20 +147,28 @@ impl Updater {
     ///
     /// * `downloader`: Mutable reference to an `XLSDownloader` implementation used to fetch and parse the schedule
     /// * `app_env`: Reference to the application environment containing either a predefined URL or Yandex Cloud credentials
+    /// * `layout`: Structure of the XLS file
+    /// * `timezone`: Timezone in which the schedule is written
     ///
     /// # Returns
     ///
     /// Returns `Ok(())` if the snapshot was successfully initialized, or an `Error` if:
     /// - URL query to Yandex Cloud failed ([`QueryUrlError`])
     /// - Schedule snapshot creation failed ([`SnapshotCreationError`])
-    pub async fn new(update_source: UpdateSource) -> Result<(Self, ScheduleSnapshot)> {
+    pub async fn new(
+        update_source: UpdateSource,
+        layout: SheetLayout,
+        timezone: Tz,
+    ) -> Result<(Self, ScheduleSnapshot)> {
         let mut this = Updater {
             downloader: XlsDownloader::new(),
             update_source,
+            layout,
+            timezone,
         };
 
         if let UpdateSource::Prepared(snapshot) = &this.update_source {
-            let snapshot = snapshot.clone();
+            let snapshot = snapshot.as_ref().clone();
             return Ok((this, snapshot));
         }
 
@@ -170,7 +189,8 @@ impl Updater {
 
         log::info!("For the initial setup, a link {} will be used", url);
 
-        let snapshot = Self::new_snapshot(&mut this.downloader, url).await?;
+        let snapshot =
+            Self::new_snapshot(&mut this.downloader, url, &this.layout, this.timezone).await?;
         log::info!("Schedule snapshot successfully created!");
 
         Ok((this, snapshot))
@@ -195,7 +215,7 @@ impl Updater {
         current_snapshot: &ScheduleSnapshot,
     ) -> Result<ScheduleSnapshot> {
         if let UpdateSource::Prepared(snapshot) = &self.update_source {
-            let mut snapshot = snapshot.clone();
+            let mut snapshot = snapshot.as_ref().clone();
             snapshot.update();
             return Ok(snapshot);
         }
@@ -209,7 +229,14 @@ impl Updater {
             _ => unreachable!(),
         };
 
-        let snapshot = match Self::new_snapshot(&mut self.downloader, url).await {
+        let snapshot = match Self::new_snapshot(
+            &mut self.downloader,
+            url,
+            &self.layout,
+            self.timezone,
+        )
+        .await
+        {
             Ok(snapshot) => snapshot,
             Err(Error::SameETag) => {
                 let mut clone = current_snapshot.clone();
@@ -164,10 +164,9 @@ impl XlsDownloader {
     }
 
     pub async fn fetch(&self, head: bool) -> FetchResult {
-        if self.url.is_none() {
-            Err(FetchError::NoUrlProvided)
-        } else {
-            Self::fetch_specified(self.url.as_ref().unwrap(), head).await
+        match &self.url {
+            None => Err(FetchError::NoUrlProvided),
+            Some(url) => Self::fetch_specified(url, head).await,
         }
     }
 
@@ -1,6 +1,13 @@
 pub use base;
 
+mod registry;
+
+pub use registry::{
+    ProviderConfig, ProviderKind, ProviderRegistry, RegistryConfig, RegistryError, SourceConfig,
+};
+
 pub use provider_engels_polytechnic::EngelsPolytechnicProvider;
+pub use provider_engels_polytechnic::SheetLayout;
 pub use provider_engels_polytechnic::UpdateSource as EngelsPolytechnicUpdateSource;
 
 #[cfg(feature = "test")]
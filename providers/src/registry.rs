@@ -0,0 +1,666 @@
+use crate::SheetLayout;
+use base::{Building, ProviderSettings, ScheduleProvider};
+use chrono::NaiveTime;
+use chrono_tz::Tz;
+use derive_more::{Display, Error};
+use serde::Deserialize;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// First segments of the fixed `/schedule/...` routes.
+///
+/// A provider with such an identifier would be shadowed by them or would shadow them.
+const RESERVED_IDS: &[&str] = &[
+    "batch",
+    "bells",
+    "buildings",
+    "cache-status",
+    "calendar",
+    "feed",
+    "followed",
+    "group",
+    "group-facets",
+    "group-names",
+    "ics",
+    "now",
+    "search",
+    "subject",
+    "subject-names",
+    "teacher",
+    "teacher-names",
+];
+
+#[derive(Debug, Display, Error)]
+pub enum RegistryError {
+    /// The configuration file can't be read.
+    #[display("Failed to read providers config {path}: {source}")]
+    Read {
+        #[error(not(source))]
+        path: String,
+        source: std::io::Error,
+    },
+
+    /// The configuration file is not a valid TOML or doesn't match the expected structure.
+    #[display("Invalid providers config: {_0}")]
+    Parse(toml::de::Error),
+
+    /// No providers are described.
+    #[display("Providers config must describe at least one provider")]
+    Empty,
+
+    /// Identifier is empty or contains characters that can't be used in the url path.
+    #[display(
+        "Invalid provider id {_0:?}: only lowercase latin letters, digits, '_' and '-' are allowed"
+    )]
+    InvalidId(#[error(not(source))] String),
+
+    /// Identifier is the same as the name of a schedule route.
+    #[display("Provider id {_0:?} is reserved by a schedule route")]
+    ReservedId(#[error(not(source))] String),
+
+    /// Two providers have the same identifier.
+    #[display("Provider id {_0:?} is used more than once")]
+    DuplicateId(#[error(not(source))] String),
+
+    /// More than one provider is marked as default.
+    #[display("Only one provider can be marked as default, found {_0:?} and {_1:?}")]
+    MultipleDefaults(#[error(not(source))] String, #[error(not(source))] String),
+
+    /// The sheet layout file can't be read or is invalid.
+    #[display("Invalid sheet layout {path} of provider {provider:?}: {message}")]
+    Layout {
+        #[error(not(source))]
+        provider: String,
+        #[error(not(source))]
+        path: String,
+        #[error(not(source))]
+        message: String,
+    },
+
+    /// Two buildings of the provider have the same identifier.
+    #[display("Provider {provider:?} has more than one building with id {building:?}")]
+    DuplicateBuilding {
+        #[error(not(source))]
+        provider: String,
+        #[error(not(source))]
+        building: String,
+    },
+
+    /// The grid provider has no sheet layout to parse the schedule with.
+    #[display("Provider {_0:?} of kind grid requires a layout")]
+    MissingLayout(#[error(not(source))] String),
+
+    /// The source can't be used by the provider kind.
+    #[display("Provider {_0:?} of kind grid supports only the url source")]
+    UnsupportedSource(#[error(not(source))] String),
+
+    /// Update interval of the provider is zero.
+    #[display("Provider {_0:?} has zero update interval")]
+    ZeroInterval(#[error(not(source))] String),
+
+    /// The school day of the provider doesn't end after it starts.
+    #[display("Provider {_0:?} has day_end not after day_start")]
+    InvalidDay(#[error(not(source))] String),
+
+    /// A required value of the update source is empty.
+    #[display("Provider {provider:?} has empty {field}")]
+    EmptyValue {
+        #[error(not(source))]
+        provider: String,
+        #[error(not(source))]
+        field: &'static str,
+    },
+
+    /// A value refers to an environment variable that is not set.
+    #[display("Provider {provider:?} refers to unset environment variable {variable}")]
+    MissingVariable {
+        #[error(not(source))]
+        provider: String,
+        #[error(not(source))]
+        variable: String,
+    },
+
+    /// The provider failed to obtain its first schedule.
+    #[display("Failed to create provider {id:?}: {source}")]
+    Instantiate {
+        #[error(not(source))]
+        id: String,
+        source: Box<dyn std::error::Error + Send + Sync>,
+    },
+}
+
+/// Provider implementation to instantiate.
+#[derive(Clone, Copy, Debug, PartialEq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ProviderKind {
+    /// [`crate::EngelsPolytechnicProvider`].
+    EngelsPolytechnic,
+
+    /// Schedule file of any college parsed by the sheet layout from [`ProviderConfig::layout`].
+    ///
+    /// Downloads the file by a direct link only, as finding the link on the site is Engels specific.
+    Grid,
+}
+
+/// Where the provider gets the schedule from.
+///
+/// String values starting with `$` are read from the environment variable with that name.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "kebab-case", deny_unknown_fields)]
+pub enum SourceConfig {
+    /// Direct link to the schedule file.
+    Url { url: String },
+
+    /// Link to the schedule file is obtained from the site using Yandex Cloud Function.
+    GrabFromSite {
+        yandex_api_key: String,
+        yandex_func_id: String,
+    },
+}
+
+/// Description of a single provider instance.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ProviderConfig {
+    /// Identifier used in the `/schedule/{provider}/...` paths.
+    pub id: String,
+
+    /// Provider implementation.
+    pub kind: ProviderKind,
+
+    /// Use this provider when the request doesn't specify one.
+    ///
+    /// If no provider is marked, the first one is used.
+    #[serde(default)]
+    pub default: bool,
+
+    /// Where to get the schedule from.
+    pub source: SourceConfig,
+
+    /// Auto-update interval in seconds.
+    #[serde(default = "ProviderConfig::default_update_interval")]
+    pub update_interval: u64,
+
+    /// Timezone in which the schedule is written (IANA name, e.g. `Europe/Saratov`).
+    #[serde(default = "ProviderConfig::default_timezone")]
+    pub timezone: Tz,
+
+    /// Earliest time a lesson can start at, local to [`ProviderConfig::timezone`] (e.g. `08:00:00`).
+    #[serde(default = "ProviderConfig::default_day_start")]
+    pub day_start: NaiveTime,
+
+    /// Latest time a lesson can end at, local to [`ProviderConfig::timezone`].
+    #[serde(default = "ProviderConfig::default_day_end")]
+    pub day_end: NaiveTime,
+
+    /// Path to the TOML or JSON file describing the sheet layout.
+    ///
+    /// Relative paths in the config file are resolved against the directory of that file.
+    /// If not specified, the Engels Polytechnic layout is used, the grid provider requires it.
+    #[serde(default)]
+    pub layout: Option<PathBuf>,
+
+    /// Buildings where the lessons take place.
+    #[serde(default, rename = "building")]
+    pub buildings: Vec<Building>,
+}
+
+impl ProviderConfig {
+    fn default_update_interval() -> u64 {
+        ProviderSettings::default().update_interval.as_secs()
+    }
+
+    fn default_timezone() -> Tz {
+        ProviderSettings::default().timezone
+    }
+
+    fn default_day_start() -> NaiveTime {
+        ProviderSettings::default().day_start
+    }
+
+    fn default_day_end() -> NaiveTime {
+        ProviderSettings::default().day_end
+    }
+
+    fn settings(&self) -> ProviderSettings {
+        ProviderSettings {
+            update_interval: Duration::from_secs(self.update_interval),
+            timezone: self.timezone,
+            buildings: self.buildings.clone(),
+            day_start: self.day_start,
+            day_end: self.day_end,
+        }
+    }
+
+    /// Resolves the `$VARIABLE` references and checks that the value is not empty.
+    fn resolve(&self, field: &'static str, value: &str) -> Result<String, RegistryError> {
+        let value = match value.strip_prefix('$') {
+            Some(variable) => {
+                std::env::var(variable).map_err(|_| RegistryError::MissingVariable {
+                    provider: self.id.clone(),
+                    variable: variable.to_string(),
+                })?
+            }
+            None => value.to_string(),
+        };
+
+        if value.trim().is_empty() {
+            return Err(RegistryError::EmptyValue {
+                provider: self.id.clone(),
+                field,
+            });
+        }
+
+        Ok(value)
+    }
+
+    /// Reads the sheet layout file.
+    fn load_layout(&self) -> Result<SheetLayout, RegistryError> {
+        let Some(path) = &self.layout else {
+            return Ok(SheetLayout::default());
+        };
+
+        let error = |message: String| RegistryError::Layout {
+            provider: self.id.clone(),
+            path: path.display().to_string(),
+            message,
+        };
+
+        let content = std::fs::read_to_string(path).map_err(|e| error(e.to_string()))?;
+
+        if path.extension().is_some_and(|ext| ext == "json") {
+            serde_json::from_str(&content).map_err(|e| error(e.to_string()))
+        } else {
+            toml::from_str(&content).map_err(|e| error(e.to_string()))
+        }
+    }
+
+    fn validate(&self) -> Result<SheetLayout, RegistryError> {
+        if self.id.is_empty()
+            || !self
+                .id
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '-')
+        {
+            return Err(RegistryError::InvalidId(self.id.clone()));
+        }
+
+        if RESERVED_IDS.contains(&self.id.as_str()) {
+            return Err(RegistryError::ReservedId(self.id.clone()));
+        }
+
+        if self.update_interval == 0 {
+            return Err(RegistryError::ZeroInterval(self.id.clone()));
+        }
+
+        if self.day_end <= self.day_start {
+            return Err(RegistryError::InvalidDay(self.id.clone()));
+        }
+
+        let mut buildings = HashSet::new();
+
+        for building in &self.buildings {
+            if !buildings.insert(building.id.as_str()) {
+                return Err(RegistryError::DuplicateBuilding {
+                    provider: self.id.clone(),
+                    building: building.id.clone(),
+                });
+            }
+        }
+
+        if self.kind == ProviderKind::Grid {
+            if self.layout.is_none() {
+                return Err(RegistryError::MissingLayout(self.id.clone()));
+            }
+
+            if !matches!(self.source, SourceConfig::Url { .. }) {
+                return Err(RegistryError::UnsupportedSource(self.id.clone()));
+            }
+        }
+
+        match &self.source {
+            SourceConfig::Url { url } => {
+                self.resolve("url", url)?;
+            }
+            SourceConfig::GrabFromSite {
+                yandex_api_key,
+                yandex_func_id,
+            } => {
+                self.resolve("yandex_api_key", yandex_api_key)?;
+                self.resolve("yandex_func_id", yandex_func_id)?;
+            }
+        }
+
+        self.load_layout()
+    }
+
+    async fn instantiate(
+        &self,
+        layout: &SheetLayout,
+    ) -> Result<Arc<dyn ScheduleProvider>, RegistryError> {
+        let result = match self.kind {
+            ProviderKind::EngelsPolytechnic => {
+                let source = match &self.source {
+                    SourceConfig::Url { url } => {
+                        crate::EngelsPolytechnicUpdateSource::Url(self.resolve("url", url)?)
+                    }
+                    SourceConfig::GrabFromSite {
+                        yandex_api_key,
+                        yandex_func_id,
+                    } => crate::EngelsPolytechnicUpdateSource::GrabFromSite {
+                        yandex_api_key: self.resolve("yandex_api_key", yandex_api_key)?,
+                        yandex_func_id: self.resolve("yandex_func_id", yandex_func_id)?,
+                    },
+                };
+
+                crate::EngelsPolytechnicProvider::get(source, self.settings(), layout.clone())
+                    .await
+                    .map_err(|error| Box::new(error) as _)
+            }
+            ProviderKind::Grid => {
+                // the source is checked by the validation
+                let SourceConfig::Url { url } = &self.source else {
+                    unreachable!()
+                };
+
+                let source = crate::EngelsPolytechnicUpdateSource::Url(self.resolve("url", url)?);
+
+                // the parser of the Engels provider is driven by the layout only
+                crate::EngelsPolytechnicProvider::get(source, self.settings(), layout.clone())
+                    .await
+                    .map_err(|error| Box::new(error) as _)
+            }
+        };
+
+        result.map_err(|source| RegistryError::Instantiate {
+            id: self.id.clone(),
+            source,
+        })
+    }
+}
+
+/// Contents of the providers config file.
+///
+/// ```toml
+/// [[provider]]
+/// id = "eng_polytechnic"
+/// kind = "engels-polytechnic"
+/// default = true
+/// update_interval = 1800
+/// timezone = "Europe/Saratov"
+/// source = { type = "grab-from-site", yandex_api_key = "$YANDEX_CLOUD_API_KEY", yandex_func_id = "$YANDEX_CLOUD_FUNC_ID" }
+/// ```
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RegistryConfig {
+    #[serde(rename = "provider", default)]
+    pub providers: Vec<ProviderConfig>,
+}
+
+/// Validated set of provider descriptions.
+pub struct ProviderRegistry {
+    providers: Vec<(ProviderConfig, SheetLayout)>,
+    default: String,
+}
+
+impl ProviderRegistry {
+    /// Reads and validates the config file.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, RegistryError> {
+        let path = path.as_ref();
+
+        let content = std::fs::read_to_string(path).map_err(|source| RegistryError::Read {
+            path: path.display().to_string(),
+            source,
+        })?;
+
+        let mut config: RegistryConfig = toml::from_str(&content).map_err(RegistryError::Parse)?;
+
+        // the same config must work regardless of the directory the server is started from
+        if let Some(directory) = path.parent() {
+            for provider in &mut config.providers {
+                if let Some(layout) = &mut provider.layout
+                    && layout.is_relative()
+                {
+                    *layout = directory.join(&*layout);
+                }
+            }
+        }
+
+        Self::new(config)
+    }
+
+    /// Parses and validates the config.
+    pub fn from_toml(content: &str) -> Result<Self, RegistryError> {
+        let config: RegistryConfig = toml::from_str(content).map_err(RegistryError::Parse)?;
+
+        Self::new(config)
+    }
+
+    /// Validates the config.
+    pub fn new(config: RegistryConfig) -> Result<Self, RegistryError> {
+        if config.providers.is_empty() {
+            return Err(RegistryError::Empty);
+        }
+
+        let mut ids = HashSet::new();
+        let mut default: Option<&str> = None;
+        let mut layouts = Vec::with_capacity(config.providers.len());
+
+        for provider in &config.providers {
+            layouts.push(provider.validate()?);
+
+            if !ids.insert(provider.id.as_str()) {
+                return Err(RegistryError::DuplicateId(provider.id.clone()));
+            }
+
+            if provider.default {
+                if let Some(default) = default {
+                    return Err(RegistryError::MultipleDefaults(
+                        default.to_string(),
+                        provider.id.clone(),
+                    ));
+                }
+
+                default = Some(&provider.id);
+            }
+        }
+
+        let default = default.unwrap_or(&config.providers[0].id).to_string();
+
+        Ok(Self {
+            providers: config.providers.into_iter().zip(layouts).collect(),
+            default,
+        })
+    }
+
+    /// Identifier of the default provider.
+    pub fn default_provider(&self) -> &str {
+        &self.default
+    }
+
+    /// Creates all described providers.
+    pub async fn instantiate(
+        &self,
+    ) -> Result<HashMap<String, Arc<dyn ScheduleProvider>>, RegistryError> {
+        let mut providers = HashMap::with_capacity(self.providers.len());
+
+        for (config, layout) in &self.providers {
+            providers.insert(config.id.clone(), config.instantiate(layout).await?);
+        }
+
+        Ok(providers)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid() {
+        let registry = ProviderRegistry::from_toml(
+            r#"
+            [[provider]]
+            id = "first"
+            kind = "engels-polytechnic"
+            source = { type = "url", url = "https://example.com/schedule.xls" }
+
+            [[provider]]
+            id = "second"
+            kind = "engels-polytechnic"
+            default = true
+            update_interval = 600
+            timezone = "Europe/Moscow"
+            day_start = "07:30:00"
+            source = { type = "grab-from-site", yandex_api_key = "key", yandex_func_id = "func" }
+
+            [[provider.building]]
+            id = "main"
+            address = "Пушкина 13"
+            cabinet_prefixes = ["1", "2"]
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(registry.default_provider(), "second");
+        assert_eq!(registry.providers[0].0.timezone, chrono_tz::Europe::Saratov);
+        assert_eq!(
+            registry.providers[1].0.settings().update_interval.as_secs(),
+            600
+        );
+        assert_eq!(
+            registry.providers[1].0.settings().day_start,
+            NaiveTime::from_hms_opt(7, 30, 0).unwrap()
+        );
+        assert!(registry.providers[0].0.buildings.is_empty());
+        assert_eq!(registry.providers[1].0.settings().buildings[0].id, "main");
+    }
+
+    #[test]
+    fn invalid() {
+        let provider = |id: &str, extra: &str| {
+            format!(
+                "[[provider]]\nid = \"{id}\"\nkind = \"engels-polytechnic\"\nsource = {{ type = \"url\", url = \"https://example.com\" }}\n{extra}\n"
+            )
+        };
+
+        let check = |content: String, expected: fn(&RegistryError) -> bool| {
+            let error = ProviderRegistry::from_toml(&content).err().unwrap();
+            assert!(expected(&error), "unexpected error: {error}");
+        };
+
+        check(String::new(), |e| matches!(e, RegistryError::Empty));
+        check(provider("Bad Id", ""), |e| {
+            matches!(e, RegistryError::InvalidId(_))
+        });
+        check(provider("now", ""), |e| {
+            matches!(e, RegistryError::ReservedId(_))
+        });
+        check(provider("a", "") + &provider("a", ""), |e| {
+            matches!(e, RegistryError::DuplicateId(_))
+        });
+        check(
+            provider("a", "default = true") + &provider("b", "default = true"),
+            |e| matches!(e, RegistryError::MultipleDefaults(..)),
+        );
+        check(provider("a", "update_interval = 0"), |e| {
+            matches!(e, RegistryError::ZeroInterval(_))
+        });
+        check(
+            provider("a", "day_start = \"18:00:00\"\nday_end = \"08:00:00\""),
+            |e| matches!(e, RegistryError::InvalidDay(_)),
+        );
+        check(
+            provider(
+                "a",
+                "[[provider.building]]\nid = \"b\"\naddress = \"x\"\n[[provider.building]]\nid = \"b\"\naddress = \"y\"",
+            ),
+            |e| matches!(e, RegistryError::DuplicateBuilding { .. }),
+        );
+        check(provider("a", "timezone = \"Mars/Olympus\""), |e| {
+            matches!(e, RegistryError::Parse(_))
+        });
+        check(provider("a", "layout = \"missing.toml\""), |e| {
+            matches!(e, RegistryError::Layout { .. })
+        });
+        check(
+            "[[provider]]\nid = \"a\"\nkind = \"engels-polytechnic\"\nsource = { type = \"url\", url = \"$SCHEDULE_REGISTRY_TEST_UNSET\" }".to_string(),
+            |e| matches!(e, RegistryError::MissingVariable { .. }),
+        );
+        check(
+            "[[provider]]\nid = \"a\"\nkind = \"grid\"\nsource = { type = \"url\", url = \"https://example.com\" }".to_string(),
+            |e| matches!(e, RegistryError::MissingLayout(_)),
+        );
+    }
+
+    #[test]
+    fn grid() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/../layout.example.toml");
+
+        let registry = ProviderRegistry::from_toml(&format!(
+            "[[provider]]\nid = \"a\"\nkind = \"grid\"\nlayout = {path:?}\nsource = {{ type = \"url\", url = \"https://example.com\" }}"
+        ))
+        .unwrap();
+
+        assert_eq!(registry.providers[0].0.kind, ProviderKind::Grid);
+
+        let error = ProviderRegistry::from_toml(&format!(
+            "[[provider]]\nid = \"a\"\nkind = \"grid\"\nlayout = {path:?}\nsource = {{ type = \"grab-from-site\", yandex_api_key = \"key\", yandex_func_id = \"func\" }}"
+        ))
+        .err()
+        .unwrap();
+
+        assert!(matches!(error, RegistryError::UnsupportedSource(_)));
+    }
+
+    #[test]
+    fn layout() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/../layout.example.toml");
+
+        let registry = ProviderRegistry::from_toml(&format!(
+            "[[provider]]\nid = \"a\"\nkind = \"engels-polytechnic\"\nlayout = {path:?}\nsource = {{ type = \"url\", url = \"https://example.com\" }}"
+        ))
+        .unwrap();
+
+        let layout = &registry.providers[0].1;
+        let default = SheetLayout::default();
+
+        assert_eq!(layout.group_column_offset, default.group_column_offset);
+        assert_eq!(layout.day_pattern.as_str(), default.day_pattern.as_str());
+        assert_eq!(layout.time_pattern.as_str(), default.time_pattern.as_str());
+        assert_eq!(
+            layout.street_pattern.as_str(),
+            default.street_pattern.as_str()
+        );
+    }
+
+    #[test]
+    fn relative_layout() {
+        let directory = std::env::temp_dir().join(format!("registry-{}", std::process::id()));
+        std::fs::create_dir_all(&directory).unwrap();
+
+        std::fs::copy(
+            concat!(env!("CARGO_MANIFEST_DIR"), "/../layout.example.toml"),
+            directory.join("layout.toml"),
+        )
+        .unwrap();
+
+        let config = directory.join("providers.toml");
+        std::fs::write(
+            &config,
+            "[[provider]]\nid = \"a\"\nkind = \"engels-polytechnic\"\nlayout = \"layout.toml\"\nsource = { type = \"url\", url = \"https://example.com\" }",
+        )
+        .unwrap();
+
+        // the working directory of the tests is the crate directory, not the config one
+        let registry = ProviderRegistry::from_file(&config);
+        std::fs::remove_dir_all(&directory).unwrap();
+
+        assert_eq!(
+            registry.unwrap().providers[0].0.layout,
+            Some(directory.join("layout.toml"))
+        );
+    }
+}
@@ -1,2 +1,3 @@
 pub mod authorized_user;
 pub mod base;
+pub mod provider;
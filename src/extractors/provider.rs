@@ -0,0 +1,86 @@
+use crate::extractors::base::FromRequestAsync;
+use crate::state::AppState;
+use actix_macros::MiddlewareError;
+use actix_web::body::BoxBody;
+use actix_web::dev::Payload;
+use actix_web::{HttpRequest, web};
+use derive_more::Display;
+use providers::base::{ScheduleProvider, ScheduleSnapshot};
+use serde::{Deserialize, Serialize};
+use std::ops::Deref;
+use std::sync::Arc;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Display, MiddlewareError)]
+#[status_code = "actix_web::http::StatusCode::NOT_FOUND"]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Error {
+    /// There is no provider with the identifier from the path.
+    #[display("Unknown schedule provider")]
+    UnknownProvider,
+}
+
+/// Provider selected by the `{provider}` path segment.
+///
+/// If the route has no such segment, the default provider is used.
+pub struct Provider {
+    /// The provider itself.
+    pub provider: Arc<dyn ScheduleProvider>,
+}
+
+impl Deref for Provider {
+    type Target = dyn ScheduleProvider;
+
+    fn deref(&self) -> &Self::Target {
+        self.provider.as_ref()
+    }
+}
+
+impl FromRequestAsync for Provider {
+    type Error = Error;
+
+    async fn from_request_async(
+        req: &HttpRequest,
+        _payload: &mut Payload,
+    ) -> Result<Self, Self::Error> {
+        let app_state = req.app_data::<web::Data<AppState>>().unwrap();
+
+        let id = req
+            .match_info()
+            .get("provider")
+            .unwrap_or(app_state.get_default_provider());
+
+        match app_state.get_provider(id) {
+            Some(provider) => Ok(Self { provider }),
+            None => Err(Error::UnknownProvider),
+        }
+    }
+}
+
+/// Active snapshot of the [`Provider`].
+pub struct ProviderSnapshot {
+    /// Active snapshot of the provider.
+    pub snapshot: Arc<ScheduleSnapshot>,
+}
+
+impl Deref for ProviderSnapshot {
+    type Target = ScheduleSnapshot;
+
+    fn deref(&self) -> &Self::Target {
+        &self.snapshot
+    }
+}
+
+impl FromRequestAsync for ProviderSnapshot {
+    type Error = Error;
+
+    async fn from_request_async(
+        req: &HttpRequest,
+        payload: &mut Payload,
+    ) -> Result<Self, Self::Error> {
+        let provider = Provider::from_request_async(req, payload).await?;
+
+        Ok(Self {
+            snapshot: provider.get_schedule().await,
+        })
+    }
+}
@@ -31,6 +31,42 @@ pub fn get_api_scope<
         let service_user_scope =
             utoipa_actix_web::scope("/service-users").service(routes::admin::service_users::create);
 
+        let schedule_scope = utoipa_actix_web::scope("/schedule").service(
+            utoipa_actix_web::scope("/{provider}")
+                .service(routes::admin::schedule::snapshots)
+                .service(routes::admin::schedule::conflicts)
+                .service(routes::admin::schedule::subjects)
+                .service(routes::admin::schedule::pin)
+                .service(routes::admin::schedule::unpin)
+                .service(routes::admin::schedule::rollback),
+        );
+
+        let teacher_aliases_scope = utoipa_actix_web::scope("/teacher-aliases")
+            .service(routes::admin::teacher_aliases::list)
+            .service(routes::admin::teacher_aliases::set)
+            .service(routes::admin::teacher_aliases::remove);
+
+        let subject_aliases_scope = utoipa_actix_web::scope("/subject-aliases")
+            .service(routes::admin::subject_aliases::list)
+            .service(routes::admin::subject_aliases::set)
+            .service(routes::admin::subject_aliases::remove);
+
+        let role_requests_scope = utoipa_actix_web::scope("/role-requests")
+            .service(routes::admin::role_requests::list)
+            .service(routes::admin::role_requests::approve)
+            .service(routes::admin::role_requests::reject);
+
+        let teacher_claims_scope = utoipa_actix_web::scope("/teacher-claims")
+            .service(routes::admin::teacher_claims::list)
+            .service(routes::admin::teacher_claims::approve)
+            .service(routes::admin::teacher_claims::reject);
+
+        let calendar_scope = utoipa_actix_web::scope("/calendar")
+            .service(routes::admin::calendar::list)
+            .service(routes::admin::calendar::create)
+            .service(routes::admin::calendar::update)
+            .service(routes::admin::calendar::remove);
+
         utoipa_actix_web::scope("/admin")
             .wrap(
                 JWTAuthorizationBuilder::new()
@@ -41,6 +77,12 @@ pub fn get_api_scope<
                     .build(),
             )
             .service(service_user_scope)
+            .service(schedule_scope)
+            .service(teacher_aliases_scope)
+            .service(subject_aliases_scope)
+            .service(role_requests_scope)
+            .service(teacher_claims_scope)
+            .service(calendar_scope)
     };
 
     let auth_scope = utoipa_actix_web::scope("/auth")
@@ -66,10 +108,27 @@ pub fn get_api_scope<
                 .service(routes::users::by::by_id)
                 .service(routes::users::by::by_telegram_id),
         )
+        .service(
+            utoipa_actix_web::scope("/me/calendar-feed")
+                .service(routes::users::calendar_feed::show)
+                .service(routes::users::calendar_feed::rotate)
+                .service(routes::users::calendar_feed::revoke),
+        )
+        .service(
+            utoipa_actix_web::scope("/me/follows")
+                .service(routes::users::follows::list)
+                .service(routes::users::follows::follow)
+                .service(routes::users::follows::unfollow),
+        )
         .service(routes::users::change_group)
+        .service(routes::users::change_subgroup)
         .service(routes::users::change_username)
+        .service(routes::users::claim_teacher)
+        .service(routes::users::request_role)
         .service(routes::users::me);
 
+    // first segments of these routes can't be used as provider ids,
+    // keep them in sync with the reserved ids of the provider registry
     let schedule_scope = utoipa_actix_web::scope("/schedule")
         .wrap(
             JWTAuthorizationBuilder::new()
@@ -77,16 +136,37 @@ pub fn get_api_scope<
                     allow_service: true,
                     user_roles: None,
                 }))
-                .add_paths(["/group-names", "/teacher-names"], None)
                 .add_paths(
-                    ["/"],
+                    [
+                        "/group-names",
+                        "/group-facets",
+                        "/teacher-names",
+                        "/subject-names",
+                        "/buildings",
+                        "/calendar",
+                        "/bells",
+                        "/search",
+                        "/feed/{token}",
+                        "/{provider}/group-names",
+                        "/{provider}/group-facets",
+                        "/{provider}/teacher-names",
+                        "/{provider}/subject-names",
+                        "/{provider}/buildings",
+                        "/{provider}/calendar",
+                        "/{provider}/bells",
+                        "/{provider}/search",
+                    ],
+                    None,
+                )
+                .add_paths(
+                    ["/", "/{provider}/"],
                     Some(ServiceConfig {
                         allow_service: true,
                         user_roles: Some(&[UserRole::Admin]),
                     }),
                 )
                 .add_paths(
-                    ["/group"],
+                    ["/group", "/now", "/ics/me", "/teacher/me", "/followed"],
                     Some(ServiceConfig {
                         allow_service: false,
                         user_roles: None,
@@ -97,10 +177,48 @@ pub fn get_api_scope<
         .service(routes::schedule::cache_status)
         .service(routes::schedule::schedule)
         .service(routes::schedule::group)
+        .service(routes::schedule::now)
+        .service(routes::schedule::me_ics)
+        .service(routes::schedule::feed)
+        .service(routes::schedule::followed)
         .service(routes::schedule::group_by_name)
         .service(routes::schedule::group_names)
+        .service(routes::schedule::group_facets)
+        .service(routes::schedule::group_ics)
+        // before "/teacher/{name}", which would match it too
+        .service(routes::schedule::teacher_me)
         .service(routes::schedule::teacher)
-        .service(routes::schedule::teacher_names);
+        .service(routes::schedule::teacher_ics)
+        .service(routes::schedule::teacher_names)
+        .service(routes::schedule::subject)
+        .service(routes::schedule::subject_names)
+        .service(routes::schedule::buildings)
+        .service(routes::schedule::calendar)
+        .service(routes::schedule::bells)
+        .service(routes::schedule::batch)
+        .service(routes::schedule::search)
+        .service(
+            utoipa_actix_web::scope("/{provider}")
+                .service(routes::schedule::cache_status)
+                .service(routes::schedule::schedule)
+                .service(routes::schedule::group_by_name)
+                .service(routes::schedule::group_names)
+                .service(routes::schedule::group_facets)
+                .service(routes::schedule::group_ics)
+                .service(routes::schedule::teacher)
+                .service(routes::schedule::teacher_ics)
+                .service(routes::schedule::teacher_names)
+                .service(routes::schedule::subject)
+                .service(routes::schedule::subject_names)
+                .service(routes::schedule::buildings)
+                .service(routes::schedule::calendar)
+                .service(routes::schedule::bells)
+                .service(routes::schedule::batch)
+                .service(routes::schedule::search),
+        );
+
+    let providers_scope = utoipa_actix_web::scope("/providers") //
+        .service(routes::providers::list);
 
     let flow_scope = utoipa_actix_web::scope("/flow")
         .wrap(
@@ -119,6 +237,7 @@ pub fn get_api_scope<
         .service(auth_scope)
         .service(users_scope)
         .service(schedule_scope)
+        .service(providers_scope)
         .service(flow_scope)
         .service(vk_id_scope)
 }
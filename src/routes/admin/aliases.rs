@@ -0,0 +1,206 @@
+//! Storage of the manual name corrections shared by the teacher and subject aliases.
+
+use crate::AppState;
+use actix_macros::{ErrResponse, OkResponse, ResponderJson};
+use database::entity::{
+    ActiveSubjectAlias, ActiveTeacherAlias, SubjectAliasColumn, SubjectAliasEntity,
+    TeacherAliasColumn, TeacherAliasEntity,
+};
+use database::sea_orm::sea_query::OnConflict;
+use database::sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder, Set};
+use derive_more::Display;
+use providers::base::{normalize_teacher_name, subject_key};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Manual correction of the name.
+#[derive(Serialize, Deserialize, ToSchema, ResponderJson, OkResponse)]
+#[serde(rename_all = "camelCase")]
+#[schema(as = Admin::Alias)]
+pub struct AliasResponse {
+    /// Spelling of the name found in the schedule.
+    ///
+    /// Teacher names are compared after normalization, so "Иванов ИИ" also covers "Иванов И.И.".
+    /// Subject names are compared by letters and digits only,
+    /// so "Компьютерные сети" also covers "компьютерные сети.".
+    pub alias: String,
+
+    /// Name used instead of the alias.
+    pub canonical: String,
+}
+
+#[derive(Serialize, ToSchema, ResponderJson)]
+#[schema(as = Admin::Aliases::List::Response)]
+pub struct ListResponse {
+    /// All corrections sorted by alias.
+    pub aliases: Vec<AliasResponse>,
+}
+
+#[derive(Clone, Serialize, Display, ToSchema, ErrResponse)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[schema(as = Admin::Aliases::Set::ErrorCode)]
+pub enum SetErrorCode {
+    /// Alias and canonical name are the same after normalization.
+    #[status_code = "actix_web::http::StatusCode::BAD_REQUEST"]
+    #[display("Alias is the same as the canonical name.")]
+    SameName,
+}
+
+#[derive(Clone, Serialize, Display, ToSchema, ErrResponse)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+#[schema(as = Admin::Aliases::Remove::ErrorCode)]
+pub enum RemoveErrorCode {
+    /// Alias doesn't exist.
+    #[status_code = "actix_web::http::StatusCode::NOT_FOUND"]
+    #[display("Alias not found.")]
+    NotFound,
+}
+
+/// Table of name corrections.
+pub trait AliasTable: EntityTrait {
+    const ALIAS: Self::Column;
+    const CANONICAL: Self::Column;
+
+    fn active_model(alias: String, canonical: String) -> Self::ActiveModel;
+
+    fn response(model: Self::Model) -> AliasResponse;
+
+    /// Whether both names are compared as the same one, so the alias would map the name onto itself.
+    fn is_same_name(alias: &str, canonical: &str) -> bool;
+}
+
+impl AliasTable for TeacherAliasEntity {
+    const ALIAS: Self::Column = TeacherAliasColumn::Alias;
+    const CANONICAL: Self::Column = TeacherAliasColumn::Canonical;
+
+    fn active_model(alias: String, canonical: String) -> Self::ActiveModel {
+        ActiveTeacherAlias {
+            alias: Set(alias),
+            canonical: Set(canonical),
+        }
+    }
+
+    fn response(model: Self::Model) -> AliasResponse {
+        AliasResponse {
+            alias: model.alias,
+            canonical: model.canonical,
+        }
+    }
+
+    fn is_same_name(alias: &str, canonical: &str) -> bool {
+        normalize_teacher_name(alias) == normalize_teacher_name(canonical)
+    }
+}
+
+impl AliasTable for SubjectAliasEntity {
+    const ALIAS: Self::Column = SubjectAliasColumn::Alias;
+    const CANONICAL: Self::Column = SubjectAliasColumn::Canonical;
+
+    fn active_model(alias: String, canonical: String) -> Self::ActiveModel {
+        ActiveSubjectAlias {
+            alias: Set(alias),
+            canonical: Set(canonical),
+        }
+    }
+
+    fn response(model: Self::Model) -> AliasResponse {
+        AliasResponse {
+            alias: model.alias,
+            canonical: model.canonical,
+        }
+    }
+
+    // the same comparison is used when the aliases are applied
+    fn is_same_name(alias: &str, canonical: &str) -> bool {
+        subject_key(alias) == subject_key(canonical)
+    }
+}
+
+/// All corrections of the table sorted by alias.
+pub async fn list<T: AliasTable>(app_state: &AppState) -> ListResponse {
+    let aliases = T::find()
+        .order_by_asc(T::ALIAS)
+        .all(app_state.get_database())
+        .await
+        .expect("Failed to list aliases")
+        .into_iter()
+        .map(T::response)
+        .collect();
+
+    ListResponse { aliases }
+}
+
+/// Adds the correction or replaces the canonical name of the existing one.
+pub async fn set<T: AliasTable>(
+    app_state: &AppState,
+    data: AliasResponse,
+) -> Result<AliasResponse, SetErrorCode> {
+    if T::is_same_name(&data.alias, &data.canonical) {
+        return Err(SetErrorCode::SameName);
+    }
+
+    T::insert(T::active_model(data.alias.clone(), data.canonical.clone()))
+        .on_conflict(
+            OnConflict::column(T::ALIAS)
+                .update_column(T::CANONICAL)
+                .to_owned(),
+        )
+        .exec(app_state.get_database())
+        .await
+        .expect("Failed to save alias");
+
+    app_state
+        .reload_aliases()
+        .await
+        .expect("Failed to reload aliases");
+
+    Ok(data)
+}
+
+/// Removes the correction.
+pub async fn remove<T: AliasTable>(
+    app_state: &AppState,
+    alias: &str,
+) -> Result<AliasResponse, RemoveErrorCode> {
+    let Some(model) = T::find()
+        .filter(T::ALIAS.eq(alias))
+        .one(app_state.get_database())
+        .await
+        .expect("Failed to find alias")
+    else {
+        return Err(RemoveErrorCode::NotFound);
+    };
+
+    T::delete_many()
+        .filter(T::ALIAS.eq(alias))
+        .exec(app_state.get_database())
+        .await
+        .expect("Failed to remove alias");
+
+    app_state
+        .reload_aliases()
+        .await
+        .expect("Failed to reload aliases");
+
+    Ok(T::response(model))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_name() {
+        assert!(SubjectAliasEntity::is_same_name(
+            "Информатика",
+            "информатика."
+        ));
+        assert!(!SubjectAliasEntity::is_same_name("Информ.", "Информатика"));
+
+        assert!(TeacherAliasEntity::is_same_name("Иванов ИИ", "Иванов И.И."));
+        assert!(!TeacherAliasEntity::is_same_name(
+            "Иванов И.И.",
+            "Иванова И.И."
+        ));
+    }
+}
@@ -0,0 +1,75 @@
+use self::schema::*;
+use crate::AppState;
+use crate::routes::admin::calendar::CalendarPeriodResponse;
+use crate::routes::admin::calendar::schema::CalendarPeriodRequest;
+use crate::routes::schema::ResponseError;
+use actix_web::{post, web};
+use database::entity::CalendarPeriodEntity;
+use database::sea_orm::EntityTrait;
+use web::Json;
+
+#[utoipa::path(responses(
+    (status = OK, body = CalendarPeriodResponse),
+    (
+        status = BAD_REQUEST,
+        body = ResponseError<ErrorCode>,
+        example = json!({
+            "code": "INVALID_RANGE",
+            "message": "Period ends before it starts."
+        })
+    ),
+))]
+#[post("")]
+pub async fn create(
+    data_json: Json<CalendarPeriodRequest>,
+    app_state: web::Data<AppState>,
+) -> ServiceResponse {
+    let data = data_json.into_inner();
+
+    if data.period.end < data.period.start {
+        return Err(ErrorCode::InvalidRange).into();
+    }
+
+    if let Some(provider) = &data.provider
+        && app_state.get_provider(provider).is_none()
+    {
+        return Err(ErrorCode::UnknownProvider).into();
+    }
+
+    let period = CalendarPeriodEntity::insert(data.into_active())
+        .exec_with_returning(app_state.get_database())
+        .await
+        .expect("Failed to save calendar period");
+
+    app_state
+        .reload_calendar()
+        .await
+        .expect("Failed to reload calendar");
+
+    Ok(period.into()).into()
+}
+
+mod schema {
+    use crate::routes::admin::calendar::CalendarPeriodResponse;
+    use actix_macros::ErrResponse;
+    use derive_more::Display;
+    use serde::Serialize;
+    use utoipa::ToSchema;
+
+    pub type ServiceResponse = crate::routes::schema::Response<CalendarPeriodResponse, ErrorCode>;
+
+    #[derive(Clone, Serialize, Display, ToSchema, ErrResponse)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[schema(as = Admin::Calendar::Create::ErrorCode)]
+    pub enum ErrorCode {
+        /// End date is earlier than the start date.
+        #[status_code = "actix_web::http::StatusCode::BAD_REQUEST"]
+        #[display("Period ends before it starts.")]
+        InvalidRange,
+
+        /// There is no provider with the specified identifier.
+        #[status_code = "actix_web::http::StatusCode::BAD_REQUEST"]
+        #[display("Unknown provider.")]
+        UnknownProvider,
+    }
+}
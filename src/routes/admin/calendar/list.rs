@@ -0,0 +1,35 @@
+use self::schema::*;
+use crate::AppState;
+use actix_web::{get, web};
+use database::entity::{CalendarPeriodColumn, CalendarPeriodEntity};
+use database::sea_orm::{EntityTrait, QueryOrder};
+
+#[utoipa::path(responses((status = OK, body = Response)))]
+#[get("")]
+pub async fn list(app_state: web::Data<AppState>) -> Response {
+    let periods = CalendarPeriodEntity::find()
+        .order_by_asc(CalendarPeriodColumn::StartDate)
+        .order_by_asc(CalendarPeriodColumn::Id)
+        .all(app_state.get_database())
+        .await
+        .expect("Failed to list calendar periods")
+        .into_iter()
+        .map(Into::into)
+        .collect();
+
+    Response { periods }
+}
+
+mod schema {
+    use crate::routes::admin::calendar::CalendarPeriodResponse;
+    use actix_macros::ResponderJson;
+    use serde::Serialize;
+    use utoipa::ToSchema;
+
+    #[derive(Serialize, ToSchema, ResponderJson)]
+    #[schema(as = Admin::Calendar::List::Response)]
+    pub struct Response {
+        /// Periods of all providers sorted by their beginning.
+        pub periods: Vec<CalendarPeriodResponse>,
+    }
+}
@@ -0,0 +1,11 @@
+mod create;
+mod list;
+mod remove;
+mod schema;
+mod update;
+
+pub use create::*;
+pub use list::*;
+pub use remove::*;
+pub use schema::CalendarPeriodResponse;
+pub use update::*;
@@ -0,0 +1,68 @@
+use self::schema::*;
+use crate::AppState;
+use crate::routes::admin::calendar::CalendarPeriodResponse;
+use crate::routes::schema::ResponseError;
+use actix_web::{delete, web};
+use database::query::Query;
+use database::sea_orm::ModelTrait;
+
+#[utoipa::path(responses(
+    (status = OK, body = CalendarPeriodResponse),
+    (
+        status = NOT_FOUND,
+        body = ResponseError<ErrorCode>,
+        example = json!({
+            "code": "NOT_FOUND",
+            "message": "Calendar period not found."
+        })
+    ),
+))]
+#[delete("/{id}")]
+pub async fn remove(path: web::Path<Path>, app_state: web::Data<AppState>) -> ServiceResponse {
+    let Some(period) = Query::find_calendar_period_by_id(app_state.get_database(), path.id)
+        .await
+        .expect("Failed to find calendar period")
+    else {
+        return Err(ErrorCode::NotFound).into();
+    };
+
+    period
+        .clone()
+        .delete(app_state.get_database())
+        .await
+        .expect("Failed to remove calendar period");
+
+    app_state
+        .reload_calendar()
+        .await
+        .expect("Failed to reload calendar");
+
+    Ok(period.into()).into()
+}
+
+mod schema {
+    use crate::routes::admin::calendar::CalendarPeriodResponse;
+    use actix_macros::ErrResponse;
+    use derive_more::Display;
+    use serde::{Deserialize, Serialize};
+    use utoipa::{IntoParams, ToSchema};
+
+    #[derive(Deserialize, IntoParams)]
+    #[into_params(parameter_in = Path)]
+    pub struct Path {
+        /// Identifier of the period.
+        pub id: i32,
+    }
+
+    pub type ServiceResponse = crate::routes::schema::Response<CalendarPeriodResponse, ErrorCode>;
+
+    #[derive(Clone, Serialize, Display, ToSchema, ErrResponse)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[schema(as = Admin::Calendar::Remove::ErrorCode)]
+    pub enum ErrorCode {
+        /// Period doesn't exist.
+        #[status_code = "actix_web::http::StatusCode::NOT_FOUND"]
+        #[display("Calendar period not found.")]
+        NotFound,
+    }
+}
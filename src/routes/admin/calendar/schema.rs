@@ -0,0 +1,79 @@
+use actix_macros::{OkResponse, ResponderJson};
+use database::entity::sea_orm_active_enums as db;
+use database::entity::{ActiveCalendarPeriod, CalendarPeriod as DbCalendarPeriod};
+use database::sea_orm::Set;
+use providers::base::{CalendarPeriod, CalendarPeriodKind, WeekParity};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Period of the academic calendar.
+#[derive(Serialize, Deserialize, ToSchema, ResponderJson, OkResponse)]
+#[serde(rename_all = "camelCase")]
+#[schema(as = Admin::CalendarPeriod)]
+pub struct CalendarPeriodResponse {
+    /// Identifier.
+    pub id: i32,
+
+    /// Provider the period belongs to, all providers if not specified.
+    pub provider: Option<String>,
+
+    #[serde(flatten)]
+    pub period: CalendarPeriod,
+}
+
+/// Period of the academic calendar to save.
+#[derive(Deserialize, ToSchema)]
+#[serde(rename_all = "camelCase")]
+#[schema(as = Admin::CalendarPeriodRequest)]
+pub struct CalendarPeriodRequest {
+    /// Provider the period belongs to, all providers if not specified.
+    pub provider: Option<String>,
+
+    #[serde(flatten)]
+    pub period: CalendarPeriod,
+}
+
+impl CalendarPeriodRequest {
+    /// Model with all fields except the identifier set.
+    pub fn into_active(self) -> ActiveCalendarPeriod {
+        ActiveCalendarPeriod {
+            provider: Set(self.provider),
+            kind: Set(match self.period.kind {
+                CalendarPeriodKind::Semester => db::CalendarPeriodKind::Semester,
+                CalendarPeriodKind::Holiday => db::CalendarPeriodKind::Holiday,
+                CalendarPeriodKind::Practice => db::CalendarPeriodKind::Practice,
+            }),
+            name: Set(self.period.name),
+            start_date: Set(self.period.start),
+            end_date: Set(self.period.end),
+            first_week: Set(self.period.first_week.map(|parity| match parity {
+                WeekParity::Numerator => db::WeekParity::Numerator,
+                WeekParity::Denominator => db::WeekParity::Denominator,
+            })),
+            ..Default::default()
+        }
+    }
+}
+
+impl From<DbCalendarPeriod> for CalendarPeriodResponse {
+    fn from(value: DbCalendarPeriod) -> Self {
+        Self {
+            id: value.id,
+            provider: value.provider,
+            period: CalendarPeriod {
+                kind: match value.kind {
+                    db::CalendarPeriodKind::Semester => CalendarPeriodKind::Semester,
+                    db::CalendarPeriodKind::Holiday => CalendarPeriodKind::Holiday,
+                    db::CalendarPeriodKind::Practice => CalendarPeriodKind::Practice,
+                },
+                name: value.name,
+                start: value.start_date,
+                end: value.end_date,
+                first_week: value.first_week.map(|parity| match parity {
+                    db::WeekParity::Numerator => WeekParity::Numerator,
+                    db::WeekParity::Denominator => WeekParity::Denominator,
+                }),
+            },
+        }
+    }
+}
@@ -0,0 +1,107 @@
+use self::schema::*;
+use crate::AppState;
+use crate::routes::admin::calendar::CalendarPeriodResponse;
+use crate::routes::admin::calendar::schema::CalendarPeriodRequest;
+use crate::routes::schema::ResponseError;
+use actix_web::{put, web};
+use database::query::Query;
+use database::sea_orm::{ActiveModelTrait, Set};
+use web::Json;
+
+#[utoipa::path(responses(
+    (status = OK, body = CalendarPeriodResponse),
+    (
+        status = BAD_REQUEST,
+        body = ResponseError<ErrorCode>,
+        example = json!({
+            "code": "INVALID_RANGE",
+            "message": "Period ends before it starts."
+        })
+    ),
+    (
+        status = NOT_FOUND,
+        body = ResponseError<ErrorCode>,
+        example = json!({
+            "code": "NOT_FOUND",
+            "message": "Calendar period not found."
+        })
+    ),
+))]
+#[put("/{id}")]
+pub async fn update(
+    path: web::Path<Path>,
+    data_json: Json<CalendarPeriodRequest>,
+    app_state: web::Data<AppState>,
+) -> ServiceResponse {
+    let data = data_json.into_inner();
+
+    if data.period.end < data.period.start {
+        return Err(ErrorCode::InvalidRange).into();
+    }
+
+    if let Some(provider) = &data.provider
+        && app_state.get_provider(provider).is_none()
+    {
+        return Err(ErrorCode::UnknownProvider).into();
+    }
+
+    if Query::find_calendar_period_by_id(app_state.get_database(), path.id)
+        .await
+        .expect("Failed to find calendar period")
+        .is_none()
+    {
+        return Err(ErrorCode::NotFound).into();
+    }
+
+    let mut period = data.into_active();
+    period.id = Set(path.id);
+
+    let period = period
+        .update(app_state.get_database())
+        .await
+        .expect("Failed to update calendar period");
+
+    app_state
+        .reload_calendar()
+        .await
+        .expect("Failed to reload calendar");
+
+    Ok(period.into()).into()
+}
+
+mod schema {
+    use crate::routes::admin::calendar::CalendarPeriodResponse;
+    use actix_macros::ErrResponse;
+    use derive_more::Display;
+    use serde::{Deserialize, Serialize};
+    use utoipa::{IntoParams, ToSchema};
+
+    #[derive(Deserialize, IntoParams)]
+    #[into_params(parameter_in = Path)]
+    pub struct Path {
+        /// Identifier of the period.
+        pub id: i32,
+    }
+
+    pub type ServiceResponse = crate::routes::schema::Response<CalendarPeriodResponse, ErrorCode>;
+
+    #[derive(Clone, Serialize, Display, ToSchema, ErrResponse)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[schema(as = Admin::Calendar::Update::ErrorCode)]
+    pub enum ErrorCode {
+        /// End date is earlier than the start date.
+        #[status_code = "actix_web::http::StatusCode::BAD_REQUEST"]
+        #[display("Period ends before it starts.")]
+        InvalidRange,
+
+        /// There is no provider with the specified identifier.
+        #[status_code = "actix_web::http::StatusCode::BAD_REQUEST"]
+        #[display("Unknown provider.")]
+        UnknownProvider,
+
+        /// Period doesn't exist.
+        #[status_code = "actix_web::http::StatusCode::NOT_FOUND"]
+        #[display("Calendar period not found.")]
+        NotFound,
+    }
+}
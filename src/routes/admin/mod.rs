@@ -1 +1,8 @@
+mod aliases;
+pub mod calendar;
+pub mod role_requests;
+pub mod schedule;
 pub mod service_users;
+pub mod subject_aliases;
+pub mod teacher_aliases;
+pub mod teacher_claims;
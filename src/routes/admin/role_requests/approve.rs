@@ -0,0 +1,142 @@
+use self::schema::*;
+use crate::AppState;
+use crate::extractors::base::AsyncExtractor;
+use crate::routes::admin::role_requests::schema::DecisionRequest;
+use crate::routes::schema::ResponseError;
+use crate::routes::schema::role_request::RoleRequestResponse;
+use actix_web::{post, web};
+use chrono::Utc;
+use database::entity::sea_orm_active_enums::ClaimStatus;
+use database::entity::{RoleRequestEntity, User, UserEntity};
+use database::sea_orm::{
+    ActiveModelTrait, EntityTrait, IntoActiveModel, QuerySelect, Set, TransactionTrait,
+};
+use web::Json;
+
+/// Grants the requested role to the user.
+#[utoipa::path(responses(
+    (status = OK, body = RoleRequestResponse),
+    (
+        status = NOT_FOUND,
+        body = ResponseError<ErrorCode>,
+        example = json!({
+            "code": "NOT_FOUND",
+            "message": "Role request not found."
+        })
+    ),
+    (
+        status = CONFLICT,
+        body = ResponseError<ErrorCode>,
+        example = json!({
+            "code": "ALREADY_DECIDED",
+            "message": "Role request is already decided."
+        })
+    ),
+    (
+        status = CONFLICT,
+        body = ResponseError<ErrorCode>,
+        example = json!({
+            "code": "ROLE_CHANGED",
+            "message": "The role of the user has changed since the request was made."
+        })
+    ),
+))]
+#[post("/{id}/approve")]
+pub async fn approve(
+    path: web::Path<Path>,
+    data_json: Json<DecisionRequest>,
+    admin: AsyncExtractor<User>,
+    app_state: web::Data<AppState>,
+) -> ServiceResponse {
+    let txn = app_state
+        .get_database()
+        .begin()
+        .await
+        .expect("Failed to begin transaction");
+
+    let Some(request) = RoleRequestEntity::find_by_id(path.id)
+        .lock_exclusive()
+        .one(&txn)
+        .await
+        .expect("Failed to find role request")
+    else {
+        return Err(ErrorCode::NotFound).into();
+    };
+
+    if request.status != ClaimStatus::Pending {
+        return Err(ErrorCode::AlreadyDecided).into();
+    }
+
+    let Some(user) = UserEntity::find_by_id(&request.user_id)
+        .lock_exclusive()
+        .one(&txn)
+        .await
+        .expect("Failed to find user")
+    else {
+        return Err(ErrorCode::NotFound).into();
+    };
+
+    // a stale request must not replace a role granted in the meantime, e.g. the admin one
+    if user.role != request.from_role {
+        return Err(ErrorCode::RoleChanged).into();
+    }
+
+    let mut active_user = user.into_active_model();
+    active_user.role = Set(request.role.clone());
+    active_user
+        .update(&txn)
+        .await
+        .expect("Failed to update user");
+
+    let mut request = request.into_active_model();
+    request.status = Set(ClaimStatus::Approved);
+    request.decided_at = Set(Some(Utc::now().fixed_offset()));
+    request.decided_by = Set(Some(admin.into_inner().id));
+    request.comment = Set(data_json.into_inner().comment);
+
+    let request = request
+        .update(&txn)
+        .await
+        .expect("Failed to update role request");
+
+    txn.commit().await.expect("Failed to commit transaction");
+
+    Ok(request.into()).into()
+}
+
+mod schema {
+    use crate::routes::schema::role_request::RoleRequestResponse;
+    use actix_macros::ErrResponse;
+    use derive_more::Display;
+    use serde::{Deserialize, Serialize};
+    use utoipa::{IntoParams, ToSchema};
+
+    #[derive(Deserialize, IntoParams)]
+    #[into_params(parameter_in = Path)]
+    pub struct Path {
+        /// Identifier of the request.
+        pub id: i32,
+    }
+
+    pub type ServiceResponse = crate::routes::schema::Response<RoleRequestResponse, ErrorCode>;
+
+    #[derive(Clone, Serialize, Display, ToSchema, ErrResponse)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[schema(as = Admin::RoleRequests::Approve::ErrorCode)]
+    pub enum ErrorCode {
+        /// Request or its user doesn't exist.
+        #[status_code = "actix_web::http::StatusCode::NOT_FOUND"]
+        #[display("Role request not found.")]
+        NotFound,
+
+        /// The request was already approved or rejected.
+        #[status_code = "actix_web::http::StatusCode::CONFLICT"]
+        #[display("Role request is already decided.")]
+        AlreadyDecided,
+
+        /// The user doesn't have the role the request was made from anymore.
+        #[status_code = "actix_web::http::StatusCode::CONFLICT"]
+        #[display("The role of the user has changed since the request was made.")]
+        RoleChanged,
+    }
+}
@@ -0,0 +1,47 @@
+use self::schema::*;
+use crate::AppState;
+use actix_web::{get, web};
+use database::entity::{RoleRequestColumn, RoleRequestEntity};
+use database::sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder};
+
+#[utoipa::path(responses((status = OK, body = Response)))]
+#[get("")]
+pub async fn list(query: web::Query<Query>, app_state: web::Data<AppState>) -> Response {
+    let mut select = RoleRequestEntity::find().order_by_asc(RoleRequestColumn::CreatedAt);
+
+    if let Some(status) = query.status {
+        select = select.filter(RoleRequestColumn::Status.eq(status));
+    }
+
+    let requests = select
+        .all(app_state.get_database())
+        .await
+        .expect("Failed to list role requests")
+        .into_iter()
+        .map(Into::into)
+        .collect();
+
+    Response { requests }
+}
+
+mod schema {
+    use crate::routes::schema::role_request::RoleRequestResponse;
+    use actix_macros::ResponderJson;
+    use database::entity::sea_orm_active_enums::ClaimStatus;
+    use serde::{Deserialize, Serialize};
+    use utoipa::{IntoParams, ToSchema};
+
+    #[derive(Deserialize, IntoParams)]
+    #[into_params(parameter_in = Query)]
+    pub struct Query {
+        /// Only the requests with this status, `PENDING` to see the ones waiting for a decision.
+        pub status: Option<ClaimStatus>,
+    }
+
+    #[derive(Serialize, ToSchema, ResponderJson)]
+    #[schema(as = Admin::RoleRequests::List::Response)]
+    pub struct Response {
+        /// Requests from the oldest to the newest, decided ones form the audit trail.
+        pub requests: Vec<RoleRequestResponse>,
+    }
+}
@@ -0,0 +1,8 @@
+mod approve;
+mod list;
+mod reject;
+mod schema;
+
+pub use approve::*;
+pub use list::*;
+pub use reject::*;
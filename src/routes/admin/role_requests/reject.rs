@@ -0,0 +1,99 @@
+use self::schema::*;
+use crate::AppState;
+use crate::extractors::base::AsyncExtractor;
+use crate::routes::admin::role_requests::schema::DecisionRequest;
+use crate::routes::schema::ResponseError;
+use crate::routes::schema::role_request::RoleRequestResponse;
+use actix_web::{post, web};
+use chrono::Utc;
+use database::entity::User;
+use database::entity::sea_orm_active_enums::ClaimStatus;
+use database::query::Query;
+use database::sea_orm::{ActiveModelTrait, IntoActiveModel, Set};
+use web::Json;
+
+/// Declines the request, the user keeps the current role.
+#[utoipa::path(responses(
+    (status = OK, body = RoleRequestResponse),
+    (
+        status = NOT_FOUND,
+        body = ResponseError<ErrorCode>,
+        example = json!({
+            "code": "NOT_FOUND",
+            "message": "Role request not found."
+        })
+    ),
+    (
+        status = CONFLICT,
+        body = ResponseError<ErrorCode>,
+        example = json!({
+            "code": "ALREADY_DECIDED",
+            "message": "Role request is already decided."
+        })
+    ),
+))]
+#[post("/{id}/reject")]
+pub async fn reject(
+    path: web::Path<Path>,
+    data_json: Json<DecisionRequest>,
+    admin: AsyncExtractor<User>,
+    app_state: web::Data<AppState>,
+) -> ServiceResponse {
+    let db = app_state.get_database();
+
+    let Some(request) = Query::find_role_request_by_id(db, path.id)
+        .await
+        .expect("Failed to find role request")
+    else {
+        return Err(ErrorCode::NotFound).into();
+    };
+
+    if request.status != ClaimStatus::Pending {
+        return Err(ErrorCode::AlreadyDecided).into();
+    }
+
+    let mut request = request.into_active_model();
+    request.status = Set(ClaimStatus::Rejected);
+    request.decided_at = Set(Some(Utc::now().fixed_offset()));
+    request.decided_by = Set(Some(admin.into_inner().id));
+    request.comment = Set(data_json.into_inner().comment);
+
+    let request = request
+        .update(db)
+        .await
+        .expect("Failed to update role request");
+
+    Ok(request.into()).into()
+}
+
+mod schema {
+    use crate::routes::schema::role_request::RoleRequestResponse;
+    use actix_macros::ErrResponse;
+    use derive_more::Display;
+    use serde::{Deserialize, Serialize};
+    use utoipa::{IntoParams, ToSchema};
+
+    #[derive(Deserialize, IntoParams)]
+    #[into_params(parameter_in = Path)]
+    pub struct Path {
+        /// Identifier of the request.
+        pub id: i32,
+    }
+
+    pub type ServiceResponse = crate::routes::schema::Response<RoleRequestResponse, ErrorCode>;
+
+    #[derive(Clone, Serialize, Display, ToSchema, ErrResponse)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[schema(as = Admin::RoleRequests::Reject::ErrorCode)]
+    pub enum ErrorCode {
+        /// Request doesn't exist.
+        #[status_code = "actix_web::http::StatusCode::NOT_FOUND"]
+        #[display("Role request not found.")]
+        NotFound,
+
+        /// The request was already approved or rejected.
+        #[status_code = "actix_web::http::StatusCode::CONFLICT"]
+        #[display("Role request is already decided.")]
+        AlreadyDecided,
+    }
+}
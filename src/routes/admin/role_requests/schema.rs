@@ -0,0 +1,11 @@
+use serde::Deserialize;
+use utoipa::ToSchema;
+
+/// Decision of the administrator on the role request.
+#[derive(Deserialize, ToSchema)]
+#[schema(as = Admin::RoleRequestDecision)]
+pub struct DecisionRequest {
+    /// Explanation kept in the request, e.g. how the teacher was verified.
+    #[serde(default)]
+    pub comment: Option<String>,
+}
@@ -0,0 +1,72 @@
+use self::schema::*;
+use crate::extractors::base::AsyncExtractor;
+use crate::extractors::provider::Provider;
+use crate::routes::schema::ResponseError;
+use actix_web::{get, web};
+use providers::base::find_conflicts;
+
+#[utoipa::path(responses(
+    (status = OK, body = Response),
+    (
+        status = NOT_FOUND,
+        body = ResponseError<ErrorCode>,
+        example = json!({
+            "code": "NOT_FOUND",
+            "message": "Snapshot with that hash not found."
+        })
+    ),
+))]
+#[get("/snapshots/{hash}/conflicts")]
+pub async fn conflicts(
+    path: web::Path<Path>,
+    provider: AsyncExtractor<Provider>,
+) -> ServiceResponse {
+    let hash = path.into_inner().hash;
+
+    match provider
+        .get_snapshots()
+        .await
+        .into_iter()
+        .find(|entry| entry.hash == hash)
+    {
+        Some(entry) => Ok(Response {
+            conflicts: find_conflicts(&entry.snapshot.data, provider.settings()),
+        }),
+        None => Err(ErrorCode::NotFound),
+    }
+    .into()
+}
+
+mod schema {
+    use actix_macros::{ErrResponse, OkResponse};
+    use derive_more::Display;
+    use providers::base::Conflict;
+    use serde::{Deserialize, Serialize};
+    use utoipa::{IntoParams, ToSchema};
+
+    #[derive(Deserialize, IntoParams)]
+    #[into_params(parameter_in = Path)]
+    pub struct Path {
+        /// Hash of the snapshot.
+        pub hash: String,
+    }
+
+    #[derive(Serialize, ToSchema, OkResponse)]
+    #[schema(as = Admin::Schedule::Conflicts::Response)]
+    pub struct Response {
+        /// Scheduling errors found in the snapshot.
+        pub conflicts: Vec<Conflict>,
+    }
+
+    pub type ServiceResponse = crate::routes::schema::Response<Response, ErrorCode>;
+
+    #[derive(Clone, Serialize, Display, ToSchema, ErrResponse)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[schema(as = Admin::Schedule::Conflicts::ErrorCode)]
+    pub enum ErrorCode {
+        /// Snapshot not found in the history.
+        #[status_code = "actix_web::http::StatusCode::NOT_FOUND"]
+        #[display("Snapshot with that hash not found.")]
+        NotFound,
+    }
+}
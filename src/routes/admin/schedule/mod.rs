@@ -0,0 +1,14 @@
+mod conflicts;
+mod pin;
+mod rollback;
+mod schema;
+mod snapshots;
+mod subjects;
+mod unpin;
+
+pub use conflicts::*;
+pub use pin::*;
+pub use rollback::*;
+pub use snapshots::*;
+pub use subjects::*;
+pub use unpin::*;
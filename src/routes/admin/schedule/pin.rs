@@ -0,0 +1,94 @@
+use self::schema::*;
+use crate::extractors::base::AsyncExtractor;
+use crate::extractors::provider::Provider;
+use crate::routes::admin::schedule::schema::SnapshotResponse;
+use crate::routes::schema::ResponseError;
+use actix_web::{post, web};
+
+#[utoipa::path(responses(
+    (status = OK, body = SnapshotResponse),
+    (
+        status = NOT_FOUND,
+        body = ResponseError<ErrorCode>,
+        example = json!({
+            "code": "NOT_FOUND",
+            "message": "Snapshot with that hash not found."
+        })
+    ),
+))]
+#[post("/snapshots/{hash}/pin")]
+pub async fn pin(path: web::Path<Path>, provider: AsyncExtractor<Provider>) -> ServiceResponse {
+    match provider.pin_snapshot(&path.into_inner().hash).await {
+        Ok(_) => Ok(SnapshotResponse::active(provider.provider.as_ref()).await),
+        Err(_) => Err(ErrorCode::NotFound),
+    }
+    .into()
+}
+
+mod schema {
+    use crate::routes::admin::schedule::schema::SnapshotResponse;
+    use actix_macros::ErrResponse;
+    use derive_more::Display;
+    use serde::{Deserialize, Serialize};
+    use utoipa::{IntoParams, ToSchema};
+
+    #[derive(Deserialize, IntoParams)]
+    #[into_params(parameter_in = Path)]
+    pub struct Path {
+        /// Hash of the snapshot.
+        pub hash: String,
+    }
+
+    pub type ServiceResponse = crate::routes::schema::Response<SnapshotResponse, ErrorCode>;
+
+    #[derive(Clone, Serialize, Display, ToSchema, ErrResponse)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[schema(as = Admin::Schedule::Pin::ErrorCode)]
+    pub enum ErrorCode {
+        /// Snapshot not found in the history.
+        #[status_code = "actix_web::http::StatusCode::NOT_FOUND"]
+        #[display("Snapshot with that hash not found.")]
+        NotFound,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::routes::admin::schedule::pin;
+    use crate::routes::providers::list;
+    use crate::test_env::tests::{test_app_state, test_env};
+    use actix_test::test_app;
+    use actix_web::http::{Method, StatusCode};
+    use actix_web::{test, web};
+    use serde_json::Value;
+
+    #[actix_web::test]
+    async fn pin_listed_hash() {
+        test_env();
+
+        let app = test_app(
+            test_app_state().await,
+            web::scope("")
+                .service(web::scope("/providers").service(list))
+                .service(web::scope("/{provider}").service(pin)),
+        )
+        .await;
+
+        let req = test::TestRequest::with_uri("/providers").to_request();
+        let providers: Value = test::call_and_read_body_json(&app, req).await;
+
+        let hash = providers["providers"][0]["hash"].as_str().unwrap();
+
+        let req = test::TestRequest::with_uri(&format!("/eng_polytechnic/snapshots/{hash}/pin"))
+            .method(Method::POST)
+            .to_request();
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let snapshot: Value = test::read_body_json(resp).await;
+
+        assert_eq!(snapshot["hash"], hash);
+        assert_eq!(snapshot["pinned"], true);
+    }
+}
@@ -0,0 +1,46 @@
+use self::schema::*;
+use crate::extractors::base::AsyncExtractor;
+use crate::extractors::provider::Provider;
+use crate::routes::admin::schedule::schema::SnapshotResponse;
+use crate::routes::schema::ResponseError;
+use actix_web::post;
+
+#[utoipa::path(responses(
+    (status = OK, body = SnapshotResponse),
+    (
+        status = CONFLICT,
+        body = ResponseError<ErrorCode>,
+        example = json!({
+            "code": "NO_PREVIOUS_SNAPSHOT",
+            "message": "There is no snapshot older than the active one."
+        })
+    ),
+))]
+#[post("/rollback")]
+pub async fn rollback(provider: AsyncExtractor<Provider>) -> ServiceResponse {
+    match provider.rollback_snapshot().await {
+        Ok(_) => Ok(SnapshotResponse::active(provider.provider.as_ref()).await),
+        Err(_) => Err(ErrorCode::NoPreviousSnapshot),
+    }
+    .into()
+}
+
+mod schema {
+    use crate::routes::admin::schedule::schema::SnapshotResponse;
+    use actix_macros::ErrResponse;
+    use derive_more::Display;
+    use serde::Serialize;
+    use utoipa::ToSchema;
+
+    pub type ServiceResponse = crate::routes::schema::Response<SnapshotResponse, ErrorCode>;
+
+    #[derive(Clone, Serialize, Display, ToSchema, ErrResponse)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[schema(as = Admin::Schedule::Rollback::ErrorCode)]
+    pub enum ErrorCode {
+        /// The active snapshot is the oldest one in the history.
+        #[status_code = "actix_web::http::StatusCode::CONFLICT"]
+        #[display("There is no snapshot older than the active one.")]
+        NoPreviousSnapshot,
+    }
+}
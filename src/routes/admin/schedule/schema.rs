@@ -0,0 +1,60 @@
+use actix_macros::{OkResponse, ResponderJson};
+use providers::base::{Diagnostic, ScheduleProvider, SnapshotEntry};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// Snapshot of the schedule stored in the provider's history.
+#[derive(Serialize, ToSchema, ResponderJson, OkResponse)]
+#[serde(rename_all = "camelCase")]
+#[schema(as = Admin::Schedule::Snapshot)]
+pub struct SnapshotResponse {
+    /// Schedule hash.
+    pub hash: String,
+
+    /// Url to xls file.
+    pub url: String,
+
+    /// Date when the snapshot was last fetched.
+    pub fetched_at: i64,
+
+    /// Source file update date.
+    ///
+    /// Determined by the polytechnic's server.
+    pub updated_at: i64,
+
+    /// Whether this snapshot is served to clients.
+    pub active: bool,
+
+    /// Whether this snapshot is pinned and auto-update won't replace it.
+    pub pinned: bool,
+
+    /// Problems found while parsing.
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl From<SnapshotEntry> for SnapshotResponse {
+    fn from(value: SnapshotEntry) -> Self {
+        Self {
+            hash: value.hash,
+            url: value.snapshot.url.clone(),
+            fetched_at: value.snapshot.fetched_at.timestamp(),
+            updated_at: value.snapshot.updated_at.timestamp(),
+            active: value.active,
+            pinned: value.pinned,
+            diagnostics: value.snapshot.data.diagnostics.clone(),
+        }
+    }
+}
+
+impl SnapshotResponse {
+    /// Describes the snapshot that the provider currently serves.
+    pub async fn active(provider: &dyn ScheduleProvider) -> Self {
+        provider
+            .get_snapshots()
+            .await
+            .into_iter()
+            .find(|entry| entry.active)
+            .unwrap()
+            .into()
+    }
+}
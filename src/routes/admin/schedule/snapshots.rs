@@ -0,0 +1,31 @@
+use self::schema::*;
+use crate::extractors::base::AsyncExtractor;
+use crate::extractors::provider::Provider;
+use actix_web::get;
+
+#[utoipa::path(responses((status = OK, body = Response)))]
+#[get("/snapshots")]
+pub async fn snapshots(provider: AsyncExtractor<Provider>) -> Response {
+    let snapshots = provider
+        .get_snapshots()
+        .await
+        .into_iter()
+        .map(Into::into)
+        .collect();
+
+    Response { snapshots }
+}
+
+mod schema {
+    use crate::routes::admin::schedule::schema::SnapshotResponse;
+    use actix_macros::ResponderJson;
+    use serde::Serialize;
+    use utoipa::ToSchema;
+
+    #[derive(Serialize, ToSchema, ResponderJson)]
+    #[schema(as = Admin::Schedule::Snapshots::Response)]
+    pub struct Response {
+        /// Recently obtained snapshots, from the newest to the oldest.
+        pub snapshots: Vec<SnapshotResponse>,
+    }
+}
@@ -0,0 +1,27 @@
+use self::schema::*;
+use crate::extractors::base::AsyncExtractor;
+use crate::extractors::provider::ProviderSnapshot;
+use actix_web::get;
+
+#[utoipa::path(responses((status = OK, body = Response)))]
+#[get("/subjects")]
+pub async fn subjects(snapshot: AsyncExtractor<ProviderSnapshot>) -> Response {
+    let mut subjects: Vec<_> = snapshot.data.subjects.values().cloned().collect();
+    subjects.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Response { subjects }
+}
+
+mod schema {
+    use actix_macros::ResponderJson;
+    use providers::base::Subject;
+    use serde::Serialize;
+    use utoipa::ToSchema;
+
+    #[derive(Serialize, ToSchema, ResponderJson)]
+    #[schema(as = Admin::Schedule::Subjects::Response)]
+    pub struct Response {
+        /// Subject catalogue of the active snapshot sorted by name.
+        pub subjects: Vec<Subject>,
+    }
+}
@@ -0,0 +1,12 @@
+use crate::extractors::base::AsyncExtractor;
+use crate::extractors::provider::Provider;
+use crate::routes::admin::schedule::schema::SnapshotResponse;
+use actix_web::post;
+
+#[utoipa::path(responses((status = OK, body = SnapshotResponse)))]
+#[post("/unpin")]
+pub async fn unpin(provider: AsyncExtractor<Provider>) -> SnapshotResponse {
+    provider.unpin_snapshot().await;
+
+    SnapshotResponse::active(provider.provider.as_ref()).await
+}
@@ -0,0 +1,10 @@
+use crate::AppState;
+use crate::routes::admin::aliases::{self, ListResponse};
+use actix_web::{get, web};
+use database::entity::SubjectAliasEntity;
+
+#[utoipa::path(responses((status = OK, body = ListResponse)))]
+#[get("")]
+pub async fn list(app_state: web::Data<AppState>) -> ListResponse {
+    aliases::list::<SubjectAliasEntity>(&app_state).await
+}
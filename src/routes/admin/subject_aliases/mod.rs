@@ -0,0 +1,7 @@
+mod list;
+mod remove;
+mod set;
+
+pub use list::*;
+pub use remove::*;
+pub use set::*;
@@ -0,0 +1,39 @@
+use self::schema::*;
+use crate::AppState;
+use crate::routes::admin::aliases::{self, AliasResponse, RemoveErrorCode};
+use crate::routes::schema::{Response, ResponseError};
+use actix_web::{delete, web};
+use database::entity::SubjectAliasEntity;
+
+#[utoipa::path(responses(
+    (status = OK, body = AliasResponse),
+    (
+        status = NOT_FOUND,
+        body = ResponseError<RemoveErrorCode>,
+        example = json!({
+            "code": "NOT_FOUND",
+            "message": "Alias not found."
+        })
+    ),
+))]
+#[delete("/{alias}")]
+pub async fn remove(
+    path: web::Path<Path>,
+    app_state: web::Data<AppState>,
+) -> Response<AliasResponse, RemoveErrorCode> {
+    aliases::remove::<SubjectAliasEntity>(&app_state, &path.alias)
+        .await
+        .into()
+}
+
+mod schema {
+    use serde::Deserialize;
+    use utoipa::IntoParams;
+
+    #[derive(Deserialize, IntoParams)]
+    #[into_params(parameter_in = Path)]
+    pub struct Path {
+        /// Spelling of the name found in the schedule.
+        pub alias: String,
+    }
+}
@@ -0,0 +1,27 @@
+use crate::AppState;
+use crate::routes::admin::aliases::{self, AliasResponse, SetErrorCode};
+use crate::routes::schema::{Response, ResponseError};
+use actix_web::{post, web};
+use database::entity::SubjectAliasEntity;
+use web::Json;
+
+#[utoipa::path(responses(
+    (status = OK, body = AliasResponse),
+    (
+        status = BAD_REQUEST,
+        body = ResponseError<SetErrorCode>,
+        example = json!({
+            "code": "SAME_NAME",
+            "message": "Alias is the same as the canonical name."
+        })
+    ),
+))]
+#[post("")]
+pub async fn set(
+    data_json: Json<AliasResponse>,
+    app_state: web::Data<AppState>,
+) -> Response<AliasResponse, SetErrorCode> {
+    aliases::set::<SubjectAliasEntity>(&app_state, data_json.into_inner())
+        .await
+        .into()
+}
@@ -0,0 +1,202 @@
+mod list;
+mod remove;
+mod set;
+
+pub use list::*;
+pub use remove::*;
+pub use set::*;
+
+use crate::AppState;
+use database::entity::sea_orm_active_enums::ClaimStatus;
+use database::entity::{TeacherClaimColumn, TeacherClaimEntity, UserColumn, UserEntity};
+use database::sea_orm::{
+    ActiveModelTrait, ColumnTrait, EntityTrait, IntoActiveModel, QueryFilter, Set, SqlErr,
+};
+use providers::base::find_by_name;
+
+/// Moves the bound teachers and pending claims from the names that are no longer in the schedule
+/// to the name the alias change has produced.
+///
+/// # Arguments
+///
+/// * `app_state`: state with the schedules already rebuilt with the new aliases.
+/// * `names`: names the teacher may have been bound by before the change.
+/// * `target`: name of the teacher after the change.
+async fn rebind(app_state: &AppState, names: Vec<String>, target: &str) {
+    let database = app_state.get_database();
+
+    // the teacher is looked up in the schedule of the provider the binding was made in
+    let resolve = async |provider: &str, name: &str| {
+        let snapshot = app_state.get_schedule_snapshot(provider).await?;
+        let teachers = &snapshot.data.teachers;
+
+        if teachers.contains_key(name) {
+            return None;
+        }
+
+        find_by_name(teachers, target).map(|(name, _)| name.clone())
+    };
+
+    let users = UserEntity::find()
+        .filter(UserColumn::TeacherName.is_in(names.clone()))
+        .all(database)
+        .await
+        .expect("Failed to find users");
+
+    for user in users {
+        let (Some(provider), Some(name)) = (&user.teacher_provider, &user.teacher_name) else {
+            continue;
+        };
+
+        let Some(name) = resolve(provider, name).await else {
+            continue;
+        };
+
+        let mut active_user = user.into_active_model();
+        active_user.teacher_name = Set(Some(name));
+
+        if let Err(error) = active_user.update(database).await {
+            // the alias has merged two bound teachers, the binding of the other one is kept
+            if let Some(SqlErr::UniqueConstraintViolation(_)) = error.sql_err() {
+                continue;
+            }
+
+            panic!("Failed to update user: {error}");
+        }
+    }
+
+    let claims = TeacherClaimEntity::find()
+        .filter(TeacherClaimColumn::Status.eq(ClaimStatus::Pending))
+        .filter(TeacherClaimColumn::TeacherName.is_in(names))
+        .all(database)
+        .await
+        .expect("Failed to find teacher claims");
+
+    for claim in claims {
+        let Some(name) = resolve(&claim.provider, &claim.teacher_name).await else {
+            continue;
+        };
+
+        let mut claim = claim.into_active_model();
+        claim.teacher_name = Set(name);
+
+        claim
+            .update(database)
+            .await
+            .expect("Failed to update teacher claim");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::routes::schedule::teacher_me;
+    use crate::test_env::tests::{static_app_state, test_app_state, test_env};
+    use crate::utility::jwt;
+    use actix_test::test_app;
+    use actix_web::http::StatusCode;
+    use actix_web::{test, web};
+    use database::entity::sea_orm_active_enums::UserRole;
+    use database::entity::{ActiveUser, TeacherAliasColumn, TeacherAliasEntity, UserType};
+    use database::sea_orm::sea_query::Expr;
+    use percent_encoding::{NON_ALPHANUMERIC, utf8_percent_encode};
+    use serde_json::json;
+
+    const CANONICAL: &str = "Тестовый Преподаватель Псевдонимович";
+
+    async fn bound_teacher(id: &str) -> Option<String> {
+        UserEntity::find_by_id(id)
+            .one(static_app_state().await.get_database())
+            .await
+            .unwrap()
+            .unwrap()
+            .teacher_name
+    }
+
+    #[actix_web::test]
+    async fn alias_keeps_binding() {
+        test_env();
+
+        let id = "test_teacher_alias_binding";
+        let app_state = test_app_state().await;
+        let db = app_state.get_database().clone();
+
+        let snapshot = app_state
+            .get_schedule_snapshot("eng_polytechnic")
+            .await
+            .unwrap();
+        let teacher = snapshot.data.teachers.keys().max().unwrap().clone();
+
+        TeacherAliasEntity::delete_many()
+            .filter(TeacherAliasColumn::Canonical.eq(CANONICAL))
+            .exec(&db)
+            .await
+            .unwrap();
+
+        UserEntity::delete_by_id(id).exec(&db).await.unwrap();
+
+        UserEntity::update_many()
+            .col_expr(UserColumn::TeacherName, Expr::value(None::<String>))
+            .filter(UserColumn::TeacherName.is_in([teacher.as_str(), CANONICAL]))
+            .exec(&db)
+            .await
+            .unwrap();
+
+        ActiveUser {
+            id: Set(id.to_string()),
+            username: Set(format!("test::{id}")),
+            password: Set(None),
+            vk_id: Set(None),
+            telegram_id: Set(None),
+            group: Set(None),
+            provider: Set(None),
+            subgroup: Set(None),
+            teacher_name: Set(Some(teacher.clone())),
+            teacher_provider: Set(Some("eng_polytechnic".to_string())),
+            role: Set(UserRole::Teacher),
+            android_version: Set(None),
+        }
+        .insert(&db)
+        .await
+        .unwrap();
+
+        let token = jwt::encode(UserType::Default, id);
+
+        let app = test_app(
+            app_state,
+            web::scope("")
+                .service(web::scope("/teacher-aliases").service(set).service(remove))
+                .service(web::scope("/schedule").service(teacher_me)),
+        )
+        .await;
+
+        let teacher_me_status = async || {
+            let req = test::TestRequest::get()
+                .uri("/schedule/teacher/me")
+                .insert_header(("Authorization", format!("Bearer {token}")))
+                .to_request();
+
+            test::call_service(&app, req).await.status()
+        };
+
+        let req = test::TestRequest::post()
+            .uri("/teacher-aliases")
+            .set_json(json!({ "alias": teacher, "canonical": CANONICAL }))
+            .to_request();
+
+        assert_eq!(test::call_service(&app, req).await.status(), StatusCode::OK);
+        assert_eq!(bound_teacher(id).await.as_deref(), Some(CANONICAL));
+        assert_eq!(teacher_me_status().await, StatusCode::OK);
+
+        let req = test::TestRequest::delete()
+            .uri(&format!(
+                "/teacher-aliases/{}",
+                utf8_percent_encode(&teacher, NON_ALPHANUMERIC)
+            ))
+            .to_request();
+
+        assert_eq!(test::call_service(&app, req).await.status(), StatusCode::OK);
+        assert_eq!(bound_teacher(id).await, Some(teacher));
+        assert_eq!(teacher_me_status().await, StatusCode::OK);
+    }
+}
@@ -0,0 +1,45 @@
+use self::schema::*;
+use super::rebind;
+use crate::AppState;
+use crate::routes::admin::aliases::{self, AliasResponse, RemoveErrorCode};
+use crate::routes::schema::{Response, ResponseError};
+use actix_web::{delete, web};
+use database::entity::TeacherAliasEntity;
+
+#[utoipa::path(responses(
+    (status = OK, body = AliasResponse),
+    (
+        status = NOT_FOUND,
+        body = ResponseError<RemoveErrorCode>,
+        example = json!({
+            "code": "NOT_FOUND",
+            "message": "Alias not found."
+        })
+    ),
+))]
+#[delete("/{alias}")]
+pub async fn remove(
+    path: web::Path<Path>,
+    app_state: web::Data<AppState>,
+) -> Response<AliasResponse, RemoveErrorCode> {
+    let result = aliases::remove::<TeacherAliasEntity>(&app_state, &path.alias).await;
+
+    // the teacher gets back the name found in the schedule
+    if let Ok(alias) = &result {
+        rebind(&app_state, vec![alias.canonical.clone()], &alias.alias).await;
+    }
+
+    result.into()
+}
+
+mod schema {
+    use serde::Deserialize;
+    use utoipa::IntoParams;
+
+    #[derive(Deserialize, IntoParams)]
+    #[into_params(parameter_in = Path)]
+    pub struct Path {
+        /// Spelling of the name found in the schedule.
+        pub alias: String,
+    }
+}
@@ -0,0 +1,46 @@
+use super::rebind;
+use crate::AppState;
+use crate::routes::admin::aliases::{self, AliasResponse, SetErrorCode};
+use crate::routes::schema::{Response, ResponseError};
+use actix_web::{post, web};
+use database::entity::{TeacherAliasColumn, TeacherAliasEntity};
+use database::sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
+use providers::base::normalize_teacher_name;
+use web::Json;
+
+#[utoipa::path(responses(
+    (status = OK, body = AliasResponse),
+    (
+        status = BAD_REQUEST,
+        body = ResponseError<SetErrorCode>,
+        example = json!({
+            "code": "SAME_NAME",
+            "message": "Alias is the same as the canonical name."
+        })
+    ),
+))]
+#[post("")]
+pub async fn set(
+    data_json: Json<AliasResponse>,
+    app_state: web::Data<AppState>,
+) -> Response<AliasResponse, SetErrorCode> {
+    let data = data_json.into_inner();
+
+    let previous = TeacherAliasEntity::find()
+        .filter(TeacherAliasColumn::Alias.eq(&data.alias))
+        .one(app_state.get_database())
+        .await
+        .expect("Failed to find alias");
+
+    let result = aliases::set::<TeacherAliasEntity>(&app_state, data).await;
+
+    if let Ok(alias) = &result {
+        // teachers are bound by the name as it was in the schedule before the alias
+        let mut names = vec![alias.alias.clone(), normalize_teacher_name(&alias.alias)];
+        names.extend(previous.map(|previous| previous.canonical));
+
+        rebind(&app_state, names, &alias.canonical).await;
+    }
+
+    result.into()
+}
@@ -0,0 +1,159 @@
+use self::schema::*;
+use crate::AppState;
+use crate::routes::schema::ResponseError;
+use crate::routes::schema::teacher_claim::TeacherClaimResponse;
+use actix_web::{post, web};
+use chrono::Utc;
+use database::entity::sea_orm_active_enums::{ClaimStatus, UserRole};
+use database::entity::{TeacherClaimEntity, UserColumn, UserEntity};
+use database::sea_orm::{
+    ActiveModelTrait, ColumnTrait, EntityTrait, IntoActiveModel, QueryFilter, QuerySelect, Set,
+    SqlErr, TransactionTrait,
+};
+
+/// Binds the account of the user to the claimed teacher.
+#[utoipa::path(responses(
+    (status = OK, body = TeacherClaimResponse),
+    (
+        status = NOT_FOUND,
+        body = ResponseError<ErrorCode>,
+        example = json!({
+            "code": "NOT_FOUND",
+            "message": "Teacher claim not found."
+        })
+    ),
+    (
+        status = CONFLICT,
+        body = ResponseError<ErrorCode>,
+        example = json!({
+            "code": "NOT_TEACHER",
+            "message": "The user no longer has the teacher role."
+        })
+    ),
+    (
+        status = CONFLICT,
+        body = ResponseError<ErrorCode>,
+        example = json!({
+            "code": "ALREADY_BOUND",
+            "message": "The teacher is already bound to another account."
+        })
+    ),
+))]
+#[post("/{id}/approve")]
+pub async fn approve(path: web::Path<Path>, app_state: web::Data<AppState>) -> ServiceResponse {
+    // the checks and the binding are done in one transaction with the rows locked,
+    // so concurrent approvals can't bind the teacher twice
+    let txn = app_state
+        .get_database()
+        .begin()
+        .await
+        .expect("Failed to begin transaction");
+
+    let Some(claim) = TeacherClaimEntity::find_by_id(path.id)
+        .lock_exclusive()
+        .one(&txn)
+        .await
+        .expect("Failed to find teacher claim")
+    else {
+        return Err(ErrorCode::NotFound).into();
+    };
+
+    if claim.status != ClaimStatus::Pending {
+        return Err(ErrorCode::AlreadyDecided).into();
+    }
+
+    let Some(user) = UserEntity::find_by_id(&claim.user_id)
+        .lock_exclusive()
+        .one(&txn)
+        .await
+        .expect("Failed to find user")
+    else {
+        return Err(ErrorCode::NotFound).into();
+    };
+
+    // the role may have changed since the claim was made
+    if user.role != UserRole::Teacher {
+        return Err(ErrorCode::NotTeacher).into();
+    }
+
+    let bound = UserEntity::find()
+        .filter(UserColumn::TeacherProvider.eq(&claim.provider))
+        .filter(UserColumn::TeacherName.eq(&claim.teacher_name))
+        .filter(UserColumn::Id.ne(&user.id))
+        .one(&txn)
+        .await
+        .expect("Failed to find user");
+
+    if bound.is_some() {
+        return Err(ErrorCode::AlreadyBound).into();
+    }
+
+    // the teacher is looked up in the provider of the claim, not the current one of the user
+    let mut active_user = user.into_active_model();
+    active_user.teacher_name = Set(Some(claim.teacher_name.clone()));
+    active_user.teacher_provider = Set(Some(claim.provider.clone()));
+
+    if let Err(error) = active_user.update(&txn).await {
+        // another approval has bound the teacher in the meantime
+        if let Some(SqlErr::UniqueConstraintViolation(_)) = error.sql_err() {
+            return Err(ErrorCode::AlreadyBound).into();
+        }
+
+        panic!("Failed to update user: {error}");
+    }
+
+    let mut claim = claim.into_active_model();
+    claim.status = Set(ClaimStatus::Approved);
+    claim.decided_at = Set(Some(Utc::now().fixed_offset()));
+
+    let claim = claim
+        .update(&txn)
+        .await
+        .expect("Failed to update teacher claim");
+
+    txn.commit().await.expect("Failed to commit transaction");
+
+    Ok(claim.into()).into()
+}
+
+mod schema {
+    use crate::routes::schema::teacher_claim::TeacherClaimResponse;
+    use actix_macros::ErrResponse;
+    use derive_more::Display;
+    use serde::{Deserialize, Serialize};
+    use utoipa::{IntoParams, ToSchema};
+
+    #[derive(Deserialize, IntoParams)]
+    #[into_params(parameter_in = Path)]
+    pub struct Path {
+        /// Identifier of the claim.
+        pub id: i32,
+    }
+
+    pub type ServiceResponse = crate::routes::schema::Response<TeacherClaimResponse, ErrorCode>;
+
+    #[derive(Clone, Serialize, Display, ToSchema, ErrResponse)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[schema(as = Admin::TeacherClaims::Approve::ErrorCode)]
+    pub enum ErrorCode {
+        /// Claim or its user doesn't exist.
+        #[status_code = "actix_web::http::StatusCode::NOT_FOUND"]
+        #[display("Teacher claim not found.")]
+        NotFound,
+
+        /// The claim was already approved or rejected.
+        #[status_code = "actix_web::http::StatusCode::CONFLICT"]
+        #[display("Teacher claim is already decided.")]
+        AlreadyDecided,
+
+        /// The user has lost the teacher role after making the claim.
+        #[status_code = "actix_web::http::StatusCode::CONFLICT"]
+        #[display("The user no longer has the teacher role.")]
+        NotTeacher,
+
+        /// Another user is bound to the same teacher of the same provider.
+        #[status_code = "actix_web::http::StatusCode::CONFLICT"]
+        #[display("The teacher is already bound to another account.")]
+        AlreadyBound,
+    }
+}
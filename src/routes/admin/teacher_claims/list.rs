@@ -0,0 +1,47 @@
+use self::schema::*;
+use crate::AppState;
+use actix_web::{get, web};
+use database::entity::{TeacherClaimColumn, TeacherClaimEntity};
+use database::sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder};
+
+#[utoipa::path(responses((status = OK, body = Response)))]
+#[get("")]
+pub async fn list(query: web::Query<Query>, app_state: web::Data<AppState>) -> Response {
+    let mut select = TeacherClaimEntity::find().order_by_asc(TeacherClaimColumn::CreatedAt);
+
+    if let Some(status) = query.status {
+        select = select.filter(TeacherClaimColumn::Status.eq(status));
+    }
+
+    let claims = select
+        .all(app_state.get_database())
+        .await
+        .expect("Failed to list teacher claims")
+        .into_iter()
+        .map(Into::into)
+        .collect();
+
+    Response { claims }
+}
+
+mod schema {
+    use crate::routes::schema::teacher_claim::TeacherClaimResponse;
+    use actix_macros::ResponderJson;
+    use database::entity::sea_orm_active_enums::ClaimStatus;
+    use serde::{Deserialize, Serialize};
+    use utoipa::{IntoParams, ToSchema};
+
+    #[derive(Deserialize, IntoParams)]
+    #[into_params(parameter_in = Query)]
+    pub struct Query {
+        /// Only the claims with this status, `PENDING` to see the ones waiting for a decision.
+        pub status: Option<ClaimStatus>,
+    }
+
+    #[derive(Serialize, ToSchema, ResponderJson)]
+    #[schema(as = Admin::TeacherClaims::List::Response)]
+    pub struct Response {
+        /// Claims from the oldest to the newest.
+        pub claims: Vec<TeacherClaimResponse>,
+    }
+}
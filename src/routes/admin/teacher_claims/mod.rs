@@ -0,0 +1,7 @@
+mod approve;
+mod list;
+mod reject;
+
+pub use approve::*;
+pub use list::*;
+pub use reject::*;
@@ -0,0 +1,87 @@
+use self::schema::*;
+use crate::AppState;
+use crate::routes::schema::ResponseError;
+use crate::routes::schema::teacher_claim::TeacherClaimResponse;
+use actix_web::{post, web};
+use chrono::Utc;
+use database::entity::sea_orm_active_enums::ClaimStatus;
+use database::query::Query;
+use database::sea_orm::{ActiveModelTrait, IntoActiveModel, Set};
+
+#[utoipa::path(responses(
+    (status = OK, body = TeacherClaimResponse),
+    (
+        status = NOT_FOUND,
+        body = ResponseError<ErrorCode>,
+        example = json!({
+            "code": "NOT_FOUND",
+            "message": "Teacher claim not found."
+        })
+    ),
+    (
+        status = CONFLICT,
+        body = ResponseError<ErrorCode>,
+        example = json!({
+            "code": "ALREADY_DECIDED",
+            "message": "Teacher claim is already decided."
+        })
+    ),
+))]
+#[post("/{id}/reject")]
+pub async fn reject(path: web::Path<Path>, app_state: web::Data<AppState>) -> ServiceResponse {
+    let db = app_state.get_database();
+
+    let Some(claim) = Query::find_teacher_claim_by_id(db, path.id)
+        .await
+        .expect("Failed to find teacher claim")
+    else {
+        return Err(ErrorCode::NotFound).into();
+    };
+
+    if claim.status != ClaimStatus::Pending {
+        return Err(ErrorCode::AlreadyDecided).into();
+    }
+
+    let mut claim = claim.into_active_model();
+    claim.status = Set(ClaimStatus::Rejected);
+    claim.decided_at = Set(Some(Utc::now().fixed_offset()));
+
+    let claim = claim
+        .update(db)
+        .await
+        .expect("Failed to update teacher claim");
+
+    Ok(claim.into()).into()
+}
+
+mod schema {
+    use crate::routes::schema::teacher_claim::TeacherClaimResponse;
+    use actix_macros::ErrResponse;
+    use derive_more::Display;
+    use serde::{Deserialize, Serialize};
+    use utoipa::{IntoParams, ToSchema};
+
+    #[derive(Deserialize, IntoParams)]
+    #[into_params(parameter_in = Path)]
+    pub struct Path {
+        /// Identifier of the claim.
+        pub id: i32,
+    }
+
+    pub type ServiceResponse = crate::routes::schema::Response<TeacherClaimResponse, ErrorCode>;
+
+    #[derive(Clone, Serialize, Display, ToSchema, ErrResponse)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[schema(as = Admin::TeacherClaims::Reject::ErrorCode)]
+    pub enum ErrorCode {
+        /// Claim doesn't exist.
+        #[status_code = "actix_web::http::StatusCode::NOT_FOUND"]
+        #[display("Teacher claim not found.")]
+        NotFound,
+
+        /// The claim was already approved or rejected.
+        #[status_code = "actix_web::http::StatusCode::CONFLICT"]
+        #[display("Teacher claim is already decided.")]
+        AlreadyDecided,
+    }
+}
@@ -189,6 +189,10 @@ mod tests {
             vk_id: Set(None),
             telegram_id: Set(None),
             group: Set(Some("ИС-214/23".to_string())),
+            provider: Set(None),
+            subgroup: Set(None),
+            teacher_name: Set(None),
+            teacher_provider: Set(None),
             role: Set(UserRole::Student),
             android_version: Set(None),
         };
@@ -4,14 +4,15 @@ use crate::routes::schema::user::UserResponse;
 use crate::routes::schema::ResponseError;
 use crate::{utility, AppState};
 use actix_web::{post, web};
-use database::entity::sea_orm_active_enums::UserRole;
-use database::entity::{ActiveUser, UserType};
+use chrono::Utc;
+use database::entity::sea_orm_active_enums::{ClaimStatus, UserRole};
+use database::entity::{ActiveRoleRequest, ActiveUser, UserType};
 use database::query::Query;
-use database::sea_orm::ActiveModelTrait;
+use database::sea_orm::{ActiveModelTrait, Set, TransactionTrait};
 use web::Json;
 
 async fn sign_up_combined(
-    data: SignUpData,
+    mut data: SignUpData,
     app_state: &web::Data<AppState>,
 ) -> Result<UserResponse, ErrorCode> {
     // If user selected forbidden role.
@@ -19,14 +20,11 @@ async fn sign_up_combined(
         return Err(ErrorCode::DisallowedRole);
     }
 
-    if !app_state
-        .get_schedule_snapshot("eng_polytechnic")
-        .await
-        .unwrap()
-        .data
-        .groups
-        .contains_key(&data.group)
-    {
+    let Some(snapshot) = app_state.get_schedule_snapshot(&data.provider).await else {
+        return Err(ErrorCode::UnknownProvider);
+    };
+
+    if !snapshot.data.groups.contains_key(&data.group) {
         return Err(ErrorCode::InvalidGroupName);
     }
 
@@ -49,8 +47,33 @@ async fn sign_up_combined(
         return Err(ErrorCode::VkAlreadyExists);
     }
 
+    // Teachers start as students until an administrator verifies them.
+    let requested_role = match data.role {
+        UserRole::Teacher => Some(std::mem::replace(&mut data.role, UserRole::Student)),
+        _ => None,
+    };
+
+    // the user and the request are saved together, so a teacher can't be left without a request
+    let txn = db.begin().await.expect("Failed to begin transaction");
+
     let active_user: ActiveUser = data.into();
-    let user = active_user.insert(db).await.unwrap();
+    let user = active_user.insert(&txn).await.unwrap();
+
+    if let Some(role) = requested_role {
+        ActiveRoleRequest {
+            user_id: Set(user.id.clone()),
+            role: Set(role),
+            from_role: Set(user.role.clone()),
+            status: Set(ClaimStatus::Pending),
+            created_at: Set(Utc::now().fixed_offset()),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await
+        .expect("Failed to save role request");
+    }
+
+    txn.commit().await.expect("Failed to commit transaction");
     let access_token = utility::jwt::encode(UserType::Default, &user.id);
 
     Ok(UserResponse::from_user_with_token(user, access_token))
@@ -70,6 +93,9 @@ pub async fn sign_up(data_json: Json<Request>, app_state: web::Data<AppState>) -
             password: Some(data.password),
             vk_id: None,
             group: data.group,
+            provider: data
+                .provider
+                .unwrap_or_else(|| app_state.get_default_provider().to_string()),
             role: data.role,
             version: data.version,
         },
@@ -98,6 +124,9 @@ pub async fn sign_up_vk(
                     password: None,
                     vk_id: Some(id),
                     group: data.group,
+                    provider: data
+                        .provider
+                        .unwrap_or_else(|| app_state.get_default_provider().to_string()),
                     role: data.role,
                     version: data.version,
                 },
@@ -136,7 +165,16 @@ mod schema {
         #[schema(examples("ИС-214/23"))]
         pub group: String,
 
+        /// Schedule provider of the group.
+        ///
+        /// If not specified, the default provider is used.
+        #[schema(examples("eng_polytechnic"))]
+        #[serde(default)]
+        pub provider: Option<String>,
+
         /// Role.
+        ///
+        /// Teachers are registered as students until an administrator approves the role.
         pub role: UserRole,
 
         /// Version of the installed Polytechnic+ application.
@@ -163,7 +201,16 @@ mod schema {
             #[schema(examples("ИС-214/23"))]
             pub group: String,
 
+            /// Schedule provider of the group.
+            ///
+            /// If not specified, the default provider is used.
+            #[schema(examples("eng_polytechnic"))]
+            #[serde(default)]
+            pub provider: Option<String>,
+
             /// Role.
+            ///
+            /// Teachers are registered as students until an administrator approves the role.
             pub role: UserRole,
 
             /// Version of the installed Polytechnic+ application.
@@ -187,6 +234,10 @@ mod schema {
         #[display("Unknown name of the group.")]
         InvalidGroupName,
 
+        /// Unknown schedule provider.
+        #[display("Unknown schedule provider.")]
+        UnknownProvider,
+
         /// User with this name is already registered.
         #[display("User with this name is already registered.")]
         UsernameAlreadyExists,
@@ -217,6 +268,9 @@ mod schema {
         /// Group.
         pub group: String,
 
+        /// Schedule provider of the group.
+        pub provider: String,
+
         /// Role.
         pub role: UserRole,
 
@@ -237,6 +291,10 @@ mod schema {
                 vk_id: Set(value.vk_id),
                 telegram_id: Set(None),
                 group: Set(Some(value.group)),
+                provider: Set(Some(value.provider)),
+                subgroup: Set(None),
+                teacher_name: Set(None),
+                teacher_provider: Set(None),
                 role: Set(value.role),
                 android_version: Set(Some(value.version)),
             }
@@ -254,8 +312,8 @@ mod tests {
     use actix_web::http::Method;
     use actix_web::http::StatusCode;
     use actix_web::test;
-    use database::entity::sea_orm_active_enums::UserRole;
-    use database::entity::{UserColumn, UserEntity};
+    use database::entity::sea_orm_active_enums::{ClaimStatus, UserRole};
+    use database::entity::{RoleRequestColumn, RoleRequestEntity, UserColumn, UserEntity};
     use database::sea_orm::ColumnTrait;
     use database::sea_orm::{EntityTrait, QueryFilter};
 
@@ -274,6 +332,7 @@ mod tests {
                 username: data.username.to_string(),
                 password: "example".to_string(),
                 group: data.group.to_string(),
+                provider: None,
                 role: data.role.clone(),
                 version: "1.0.0".to_string(),
             })
@@ -356,6 +415,53 @@ mod tests {
         assert_eq!(resp.status(), StatusCode::NOT_ACCEPTABLE);
     }
 
+    #[actix_web::test]
+    async fn sign_up_teacher_pending() {
+        // prepare
+
+        test_env();
+
+        let app_state = static_app_state().await;
+        let db = app_state.get_database();
+
+        UserEntity::delete_many()
+            .filter(UserColumn::Username.eq("test::sign_up_teacher_pending"))
+            .exec(db)
+            .await
+            .expect("Failed to delete user");
+
+        // test
+
+        let resp = sign_up_client(SignUpPartial {
+            username: "test::sign_up_teacher_pending",
+            group: "ИС-214/23",
+            role: UserRole::Teacher,
+        })
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let user = UserEntity::find()
+            .filter(UserColumn::Username.eq("test::sign_up_teacher_pending"))
+            .one(db)
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(user.role, UserRole::Student);
+
+        let request = RoleRequestEntity::find()
+            .filter(RoleRequestColumn::UserId.eq(&user.id))
+            .one(db)
+            .await
+            .unwrap()
+            .expect("Role request not created");
+
+        assert_eq!(request.role, UserRole::Teacher);
+        assert_eq!(request.from_role, UserRole::Student);
+        assert_eq!(request.status, ClaimStatus::Pending);
+    }
+
     #[actix_web::test]
     async fn sign_up_invalid_group() {
         test_env();
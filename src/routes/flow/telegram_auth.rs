@@ -62,7 +62,11 @@ pub async fn telegram_auth(
                 vk_id: Set(None),
                 telegram_id: Set(Some(web_app_user.id)),
                 group: Set(None),
-                role: Set(UserRole::Student), // TODO: при реге проверять данные
+                provider: Set(None),
+                subgroup: Set(None),
+                teacher_name: Set(None),
+                teacher_provider: Set(None),
+                role: Set(UserRole::Student), // преподаватели подтверждают роль через /users/request-role
                 android_version: Set(None),
             };
 
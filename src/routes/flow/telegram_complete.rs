@@ -44,19 +44,21 @@ pub async fn telegram_complete(
         active_user.username = Set(data.username);
     }
 
+    let provider = data
+        .provider
+        .unwrap_or_else(|| app_state.get_default_provider().to_string());
+
+    let Some(snapshot) = app_state.get_schedule_snapshot(&provider).await else {
+        return Err(ErrorCode::UnknownProvider).into();
+    };
+
     // проверка на существование группы
-    if !app_state
-        .get_schedule_snapshot("eng_polytechnic")
-        .await
-        .unwrap()
-        .data
-        .groups
-        .contains_key(&data.group)
-    {
+    if !snapshot.data.groups.contains_key(&data.group) {
         return Err(ErrorCode::InvalidGroupName).into();
     }
 
     active_user.group = Set(Some(data.group));
+    active_user.provider = Set(Some(provider));
 
     active_user.update(db).await.expect("Failed to update user");
 
@@ -77,6 +79,10 @@ mod schema {
 
         /// Group.
         pub group: String,
+
+        /// Schedule provider of the group.
+        #[serde(default)]
+        pub provider: Option<String>,
     }
 
     pub type ServiceResponse = crate::routes::schema::Response<(), ErrorCode>;
@@ -97,5 +103,9 @@ mod schema {
         #[display("The required group does not exist.")]
         #[status_code = "actix_web::http::StatusCode::BAD_REQUEST"]
         InvalidGroupName,
+
+        #[display("The required schedule provider does not exist.")]
+        #[status_code = "actix_web::http::StatusCode::BAD_REQUEST"]
+        UnknownProvider,
     }
 }
@@ -1,6 +1,7 @@
 pub mod admin;
 pub mod auth;
 pub mod flow;
+pub mod providers;
 pub mod schedule;
 mod schema;
 pub mod users;
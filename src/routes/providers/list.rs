@@ -0,0 +1,59 @@
+use self::schema::*;
+use crate::AppState;
+use actix_web::{get, web};
+
+#[utoipa::path(responses((status = OK, body = Response)))]
+#[get("")]
+pub async fn list(app_state: web::Data<AppState>) -> Response {
+    let mut providers = Vec::with_capacity(app_state.get_providers().len());
+
+    for (id, provider) in app_state.get_providers() {
+        let snapshot = provider.get_schedule().await;
+
+        providers.push(ProviderResponse {
+            id: id.clone(),
+            default: id == app_state.get_default_provider(),
+            hash: snapshot.hash(),
+            fetched_at: snapshot.fetched_at.timestamp(),
+            updated_at: snapshot.updated_at.timestamp(),
+        });
+    }
+
+    providers.sort_by(|a, b| a.id.cmp(&b.id));
+
+    Response { providers }
+}
+
+mod schema {
+    use actix_macros::ResponderJson;
+    use serde::Serialize;
+    use utoipa::ToSchema;
+
+    #[derive(Serialize, ToSchema)]
+    #[serde(rename_all = "camelCase")]
+    #[schema(as = Providers::Provider)]
+    pub struct ProviderResponse {
+        /// Provider identifier used in `/schedule/{provider}/...` paths.
+        #[schema(examples("eng_polytechnic"))]
+        pub id: String,
+
+        /// Whether this provider is used when none is specified.
+        pub default: bool,
+
+        /// Hash of the active schedule.
+        pub hash: String,
+
+        /// Last cache update date.
+        pub fetched_at: i64,
+
+        /// Cached schedule update date.
+        pub updated_at: i64,
+    }
+
+    #[derive(Serialize, ToSchema, ResponderJson)]
+    #[schema(as = Providers::Response)]
+    pub struct Response {
+        /// Available schedule providers.
+        pub providers: Vec<ProviderResponse>,
+    }
+}
@@ -0,0 +1,128 @@
+use self::schema::*;
+use crate::extractors::base::AsyncExtractor;
+use crate::extractors::provider::ProviderSnapshot;
+use crate::routes::schema::ResponseError;
+use actix_web::{post, web};
+use providers::base::{ScheduleEntry, cabinet_schedule};
+use std::collections::HashMap;
+use web::Json;
+
+/// Maximum number of entries requested at once.
+const MAX_ITEMS: usize = 100;
+
+#[utoipa::path(responses(
+    (status = OK, body = Response),
+    (
+        status = BAD_REQUEST,
+        body = ResponseError<ErrorCode>,
+        example = json!({
+            "code": "TOO_MANY_ITEMS",
+            "message": "Too many entries requested at once."
+        })
+    ),
+))]
+#[post("/batch")]
+pub async fn batch(
+    data_json: Json<Request>,
+    snapshot: AsyncExtractor<ProviderSnapshot>,
+) -> ServiceResponse {
+    let data = data_json.into_inner();
+
+    if data.groups.len() + data.teachers.len() + data.cabinets.len() > MAX_ITEMS {
+        return Err(ErrorCode::TooManyItems).into();
+    }
+
+    let groups = &snapshot.data.groups;
+    let teachers = &snapshot.data.teachers;
+
+    Ok(Response {
+        hash: snapshot.hash(),
+        groups: collect(data.groups, |name| groups.get(name).cloned()),
+        teachers: collect(data.teachers, |name| teachers.get(name).cloned()),
+        cabinets: collect(data.cabinets, |name| cabinet_schedule(groups, name)),
+    })
+    .into()
+}
+
+/// Looks up every requested name.
+fn collect(
+    names: Vec<String>,
+    find: impl Fn(&str) -> Option<ScheduleEntry>,
+) -> HashMap<String, BatchItem> {
+    names
+        .into_iter()
+        .map(|name| {
+            let item = match find(&name) {
+                Some(entry) => BatchItem::Ok { entry },
+                None => BatchItem::NotFound,
+            };
+
+            (name, item)
+        })
+        .collect()
+}
+
+mod schema {
+    use actix_macros::{ErrResponse, OkResponse};
+    use derive_more::Display;
+    use providers::base::ScheduleEntry;
+    use serde::{Deserialize, Serialize};
+    use std::collections::HashMap;
+    use utoipa::ToSchema;
+
+    #[derive(Deserialize, ToSchema)]
+    #[schema(as = BatchSchedule::Request)]
+    pub struct Request {
+        /// Names of the groups.
+        #[serde(default)]
+        pub groups: Vec<String>,
+
+        /// Full names of the teachers.
+        #[serde(default)]
+        pub teachers: Vec<String>,
+
+        /// Names of the cabinets.
+        #[serde(default)]
+        pub cabinets: Vec<String>,
+    }
+
+    /// Result of a single requested entry.
+    #[derive(Serialize, ToSchema)]
+    #[serde(tag = "status", rename_all = "SCREAMING_SNAKE_CASE")]
+    #[schema(as = BatchSchedule::Item)]
+    pub enum BatchItem {
+        /// The entry is found.
+        Ok { entry: ScheduleEntry },
+
+        /// There is no entry with the requested name.
+        NotFound,
+    }
+
+    #[derive(Serialize, ToSchema, OkResponse)]
+    #[schema(as = BatchSchedule::Response)]
+    pub struct Response {
+        /// Hash of the snapshot all entries are taken from.
+        pub hash: String,
+
+        /// Requested groups by their names.
+        pub groups: HashMap<String, BatchItem>,
+
+        /// Requested teachers by their names.
+        pub teachers: HashMap<String, BatchItem>,
+
+        /// Requested cabinets by their names.
+        pub cabinets: HashMap<String, BatchItem>,
+    }
+
+    pub type ServiceResponse = crate::routes::schema::Response<Response, ErrorCode>;
+
+    #[derive(Clone, Serialize, Display, ToSchema, ErrResponse)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[schema(as = BatchSchedule::ErrorCode)]
+    pub enum ErrorCode {
+        /// More than 100 entries are requested.
+        #[status_code = "actix_web::http::StatusCode::BAD_REQUEST"]
+        #[display("Too many entries requested at once.")]
+        TooManyItems,
+    }
+}
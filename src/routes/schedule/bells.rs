@@ -0,0 +1,26 @@
+use self::schema::*;
+use crate::extractors::base::AsyncExtractor;
+use crate::extractors::provider::ProviderSnapshot;
+use actix_web::get;
+
+#[utoipa::path(responses((status = OK, body = Response)))]
+#[get("/bells")]
+pub async fn bells(snapshot: AsyncExtractor<ProviderSnapshot>) -> Response {
+    Response {
+        days: snapshot.data.bells.clone(),
+    }
+}
+
+mod schema {
+    use actix_macros::ResponderJson;
+    use providers::base::BellDay;
+    use serde::Serialize;
+    use utoipa::ToSchema;
+
+    #[derive(Serialize, ToSchema, ResponderJson)]
+    #[schema(as = GetBells::Response)]
+    pub struct Response {
+        /// Lesson slots of every day of the week, common to all groups.
+        pub days: Vec<BellDay>,
+    }
+}
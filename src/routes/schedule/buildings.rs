@@ -0,0 +1,26 @@
+use self::schema::*;
+use crate::extractors::base::AsyncExtractor;
+use crate::extractors::provider::Provider;
+use actix_web::get;
+
+#[utoipa::path(responses((status = OK, body = Response)))]
+#[get("/buildings")]
+pub async fn buildings(provider: AsyncExtractor<Provider>) -> Response {
+    Response {
+        buildings: provider.settings().buildings.clone(),
+    }
+}
+
+mod schema {
+    use actix_macros::ResponderJson;
+    use providers::base::Building;
+    use serde::Serialize;
+    use utoipa::ToSchema;
+
+    #[derive(Serialize, ToSchema, ResponderJson)]
+    #[schema(as = GetBuildings::Response)]
+    pub struct Response {
+        /// Buildings where the lessons take place.
+        pub buildings: Vec<Building>,
+    }
+}
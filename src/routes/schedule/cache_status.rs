@@ -1,17 +1,13 @@
+use crate::extractors::base::AsyncExtractor;
+use crate::extractors::provider::ProviderSnapshot;
 use crate::routes::schedule::schema::CacheStatus;
-use crate::AppState;
-use actix_web::{get, web};
+use actix_web::get;
 use std::ops::Deref;
 
 #[utoipa::path(responses(
     (status = OK, body = CacheStatus),
 ))]
 #[get("/cache-status")]
-pub async fn cache_status(app_state: web::Data<AppState>) -> CacheStatus {
-    app_state
-        .get_schedule_snapshot("eng_polytechnic")
-        .await
-        .unwrap()
-        .deref()
-        .into()
+pub async fn cache_status(snapshot: AsyncExtractor<ProviderSnapshot>) -> CacheStatus {
+    snapshot.snapshot.deref().into()
 }
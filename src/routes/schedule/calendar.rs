@@ -0,0 +1,38 @@
+use self::schema::*;
+use crate::extractors::base::AsyncExtractor;
+use crate::extractors::provider::Provider;
+use actix_web::get;
+use chrono::Utc;
+
+#[utoipa::path(responses((status = OK, body = Response)))]
+#[get("/calendar")]
+pub async fn calendar(provider: AsyncExtractor<Provider>) -> Response {
+    let snapshot = provider.get_schedule().await;
+    let calendar = snapshot.data.calendar.clone();
+
+    let today = Utc::now()
+        .with_timezone(&provider.settings().timezone)
+        .date_naive();
+
+    Response {
+        today: calendar.day(today),
+        periods: calendar.periods,
+    }
+}
+
+mod schema {
+    use actix_macros::ResponderJson;
+    use providers::base::{CalendarPeriod, DayCalendar};
+    use serde::Serialize;
+    use utoipa::ToSchema;
+
+    #[derive(Serialize, ToSchema, ResponderJson)]
+    #[schema(as = GetCalendar::Response)]
+    pub struct Response {
+        /// Semesters, holidays and practices sorted by their beginning.
+        pub periods: Vec<CalendarPeriod>,
+
+        /// Place of the current day in the calendar.
+        pub today: DayCalendar,
+    }
+}
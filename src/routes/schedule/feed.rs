@@ -0,0 +1,138 @@
+use self::schema::*;
+use crate::AppState;
+use crate::routes::schedule::schema::user_filter;
+use crate::routes::schema::ResponseError;
+use crate::routes::users::calendar_feed::hash_token;
+use actix_web::{get, web};
+use database::query::Query;
+use providers::base::{find_by_name, to_ical};
+
+/// Calendar of the feed owner, for calendar applications that can't send the authorization header.
+///
+/// Serves the schedule of the bound teacher, otherwise the group of the owner filtered by the subgroup.
+/// Contains only the lessons of the schedule, the API has no storage for custom events of the users.
+#[utoipa::path(responses(
+    (status = OK, content_type = "text/calendar", body = String),
+    (
+        status = NOT_FOUND,
+        body = ResponseError<ErrorCode>,
+        example = json!({
+            "code": "INVALID_TOKEN",
+            "message": "Calendar feed not found or revoked."
+        })
+    ),
+))]
+#[get("/feed/{token}", name = "calendar_feed")]
+pub async fn feed(path: web::Path<Path>, app_state: web::Data<AppState>) -> ServiceResponse {
+    let db = app_state.get_database();
+
+    let Some(feed) = Query::find_calendar_feed_by_token_hash(db, &hash_token(&path.token))
+        .await
+        .expect("Failed to find calendar feed")
+    else {
+        return Err(ErrorCode::InvalidToken).into();
+    };
+
+    let Some(user) = Query::find_user_by_id(db, &feed.user_id)
+        .await
+        .expect("Failed to find user")
+    else {
+        return Err(ErrorCode::InvalidToken).into();
+    };
+
+    // teacher accounts get their own lessons rather than those of a group
+    if let (Some(teacher), Some(provider_id)) = (&user.teacher_name, &user.teacher_provider) {
+        let Some(provider) = app_state.get_provider(provider_id) else {
+            return Err(ErrorCode::UnknownProvider).into();
+        };
+
+        let snapshot = provider.get_schedule().await;
+
+        return match find_by_name(&snapshot.data.teachers, teacher) {
+            None => Err(ErrorCode::TeacherNotFound),
+            Some((_, entry)) => Ok(to_ical(
+                entry,
+                provider.settings().timezone,
+                snapshot.updated_at,
+            )),
+        }
+        .into();
+    }
+
+    let Some(group) = &user.group else {
+        return Err(ErrorCode::SignUpNotCompleted).into();
+    };
+
+    let provider_id = user
+        .provider
+        .as_deref()
+        .unwrap_or(app_state.get_default_provider());
+
+    let Some(provider) = app_state.get_provider(provider_id) else {
+        return Err(ErrorCode::UnknownProvider).into();
+    };
+
+    // built from the latest snapshot on every request, so subscriptions follow schedule updates
+    let snapshot = provider.get_schedule().await;
+
+    match snapshot.data.groups.get(group) {
+        None => Err(ErrorCode::NotFound),
+        Some(entry) => {
+            let timezone = provider.settings().timezone;
+
+            Ok(to_ical(
+                &user_filter(&user).apply(entry, timezone),
+                timezone,
+                snapshot.updated_at,
+            ))
+        }
+    }
+    .into()
+}
+
+mod schema {
+    use crate::routes::schedule::schema::ICalendarResponse;
+    use actix_macros::ErrResponse;
+    use derive_more::Display;
+    use serde::{Deserialize, Serialize};
+    use utoipa::{IntoParams, ToSchema};
+
+    #[derive(Deserialize, IntoParams)]
+    #[into_params(parameter_in = Path)]
+    pub struct Path {
+        /// Secret token of the feed.
+        pub token: String,
+    }
+
+    pub type ServiceResponse = ICalendarResponse<ErrorCode>;
+
+    #[derive(Clone, Serialize, Display, ToSchema, ErrResponse)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[schema(as = CalendarFeed::Feed::ErrorCode)]
+    pub enum ErrorCode {
+        /// The token was rotated, revoked or never existed.
+        #[status_code = "actix_web::http::StatusCode::NOT_FOUND"]
+        #[display("Calendar feed not found or revoked.")]
+        InvalidToken,
+
+        /// The owner of the feed hasn't completed signing up.
+        #[status_code = "actix_web::http::StatusCode::FORBIDDEN"]
+        #[display("The owner of the feed has not completed signing up.")]
+        SignUpNotCompleted,
+
+        /// Group not found.
+        #[status_code = "actix_web::http::StatusCode::NOT_FOUND"]
+        #[display("Required group not found.")]
+        NotFound,
+
+        /// Teacher bound to the owner has no lessons in the current schedule.
+        #[status_code = "actix_web::http::StatusCode::NOT_FOUND"]
+        #[display("Required teacher not found.")]
+        TeacherNotFound,
+
+        /// The provider selected by the owner is no longer available.
+        #[status_code = "actix_web::http::StatusCode::NOT_FOUND"]
+        #[display("Schedule provider of the user not found.")]
+        UnknownProvider,
+    }
+}
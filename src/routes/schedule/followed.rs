@@ -0,0 +1,135 @@
+use self::schema::*;
+use crate::AppState;
+use crate::extractors::base::AsyncExtractor;
+use crate::routes::schedule::schema::EntryQuery;
+use crate::routes::schema::ResponseError;
+use actix_web::{get, web};
+use chrono::Utc;
+use database::entity::sea_orm_active_enums::FollowKind;
+use database::entity::{User, UserFollowColumn, UserFollowEntity};
+use database::sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder};
+use providers::base::cabinet_schedule;
+
+/// Schedule of every entry followed by the user.
+///
+/// All entries are taken from the same snapshot of the provider selected by the user.
+#[utoipa::path(responses(
+    (status = OK, body = Response),
+    (
+        status = NOT_FOUND,
+        body = ResponseError<ErrorCode>,
+        example = json!({
+            "code": "UNKNOWN_PROVIDER",
+            "message": "Schedule provider of the user not found."
+        })
+    ),
+))]
+#[get("/followed")]
+pub async fn followed(
+    user: AsyncExtractor<User>,
+    query: web::Query<EntryQuery>,
+    app_state: web::Data<AppState>,
+) -> ServiceResponse {
+    let user = user.into_inner();
+
+    let provider_id = user
+        .provider
+        .as_deref()
+        .unwrap_or(app_state.get_default_provider());
+
+    let Some(provider) = app_state.get_provider(provider_id) else {
+        return Err(ErrorCode::UnknownProvider).into();
+    };
+
+    let follows = UserFollowEntity::find()
+        .filter(UserFollowColumn::UserId.eq(&user.id))
+        .filter(UserFollowColumn::Provider.eq(provider_id))
+        .order_by_asc(UserFollowColumn::CreatedAt)
+        .all(app_state.get_database())
+        .await
+        .expect("Failed to list user follows");
+
+    let snapshot = provider.get_schedule().await;
+    let timezone = provider.settings().timezone;
+    let filter = query.filter(Utc::now().with_timezone(&timezone).date_naive());
+
+    let groups = &snapshot.data.groups;
+    let teachers = &snapshot.data.teachers;
+
+    let entries = follows
+        .into_iter()
+        .map(|follow| {
+            let entry = match follow.kind {
+                FollowKind::Group => groups
+                    .get(&follow.name)
+                    .map(|entry| filter.apply(entry, timezone)),
+                FollowKind::Teacher => teachers
+                    .get(&follow.name)
+                    .map(|entry| filter.apply(entry, timezone)),
+                FollowKind::Cabinet => cabinet_schedule(groups, &follow.name)
+                    .map(|entry| filter.apply(&entry, timezone)),
+            };
+
+            FollowedEntry {
+                id: follow.id,
+                kind: follow.kind,
+                name: follow.name,
+                entry: entry.map(Into::into),
+            }
+        })
+        .collect();
+
+    Ok(Response {
+        hash: snapshot.hash(),
+        entries,
+    })
+    .into()
+}
+
+mod schema {
+    use crate::routes::schedule::schema::ScheduleEntryResponse;
+    use actix_macros::{ErrResponse, OkResponse};
+    use database::entity::sea_orm_active_enums::FollowKind;
+    use derive_more::Display;
+    use serde::Serialize;
+    use utoipa::ToSchema;
+
+    /// Schedule of a single followed entry.
+    #[derive(Serialize, ToSchema)]
+    #[schema(as = FollowedSchedule::Entry)]
+    pub struct FollowedEntry {
+        /// Identifier of the followed entry.
+        pub id: i32,
+
+        /// Kind of the entry.
+        pub kind: FollowKind,
+
+        /// Name of the entry.
+        pub name: String,
+
+        /// Schedule of the entry, `null` if it is absent from the current snapshot.
+        pub entry: Option<ScheduleEntryResponse>,
+    }
+
+    #[derive(Serialize, ToSchema, OkResponse)]
+    #[schema(as = FollowedSchedule::Response)]
+    pub struct Response {
+        /// Hash of the snapshot all entries are taken from.
+        pub hash: String,
+
+        /// Followed entries from the oldest to the newest.
+        pub entries: Vec<FollowedEntry>,
+    }
+
+    pub type ServiceResponse = crate::routes::schema::Response<Response, ErrorCode>;
+
+    #[derive(Clone, Serialize, Display, ToSchema, ErrResponse)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[schema(as = FollowedSchedule::ErrorCode)]
+    pub enum ErrorCode {
+        /// The provider selected by the user is no longer available.
+        #[status_code = "actix_web::http::StatusCode::NOT_FOUND"]
+        #[display("Schedule provider of the user not found.")]
+        UnknownProvider,
+    }
+}
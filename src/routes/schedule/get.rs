@@ -1,9 +1,11 @@
+use crate::extractors::base::AsyncExtractor;
+use crate::extractors::provider::ProviderSnapshot;
 use crate::routes::schedule::schema::ScheduleView;
-use crate::state::AppState;
-use actix_web::{get, web};
+use actix_web::get;
+use std::ops::Deref;
 
 #[utoipa::path(responses((status = OK, body = ScheduleView)))]
 #[get("/")]
-pub async fn schedule(app_state: web::Data<AppState>) -> ScheduleView {
-    ScheduleView::from(&app_state).await
+pub async fn schedule(snapshot: AsyncExtractor<ProviderSnapshot>) -> ScheduleView {
+    snapshot.snapshot.deref().into()
 }
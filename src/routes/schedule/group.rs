@@ -1,7 +1,7 @@
 use self::schema::*;
 use crate::AppState;
 use crate::extractors::base::AsyncExtractor;
-use crate::routes::schedule::schema::ScheduleEntryResponse;
+use crate::routes::schedule::schema::{ScheduleEntryResponse, user_filter};
 use crate::routes::schema::ResponseError;
 use actix_web::{get, web};
 use database::entity::User;
@@ -27,21 +27,30 @@ use database::entity::User;
 ))]
 #[get("/group")]
 pub async fn group(user: AsyncExtractor<User>, app_state: web::Data<AppState>) -> ServiceResponse {
-    match &user.into_inner().group {
-        None => Err(ErrorCode::SignUpNotCompleted),
+    let user = user.into_inner();
 
-        Some(group) => match app_state
-            .get_schedule_snapshot("eng_polytechnic")
-            .await
-            .unwrap()
-            .data
-            .groups
-            .get(group)
-        {
-            None => Err(ErrorCode::NotFound),
+    let Some(group) = &user.group else {
+        return Err(ErrorCode::SignUpNotCompleted).into();
+    };
 
-            Some(entry) => Ok(entry.clone().into()),
-        },
+    let provider = user
+        .provider
+        .as_deref()
+        .unwrap_or(app_state.get_default_provider());
+
+    let Some(provider) = app_state.get_provider(provider) else {
+        return Err(ErrorCode::UnknownProvider).into();
+    };
+
+    let snapshot = provider.get_schedule().await;
+
+    match snapshot.data.groups.get(group) {
+        None => Err(ErrorCode::NotFound),
+
+        // only the lessons of the user's subgroup, if it's selected
+        Some(entry) => Ok(user_filter(&user)
+            .apply(entry, provider.settings().timezone)
+            .into()),
     }
     .into()
 }
@@ -68,5 +77,10 @@ mod schema {
         #[status_code = "actix_web::http::StatusCode::NOT_FOUND"]
         #[display("Required group not found.")]
         NotFound,
+
+        /// The provider selected by the user is no longer available.
+        #[status_code = "actix_web::http::StatusCode::NOT_FOUND"]
+        #[display("Schedule provider of the user not found.")]
+        UnknownProvider,
     }
 }
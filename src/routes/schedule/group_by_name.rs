@@ -1,8 +1,11 @@
 use self::schema::*;
-use crate::routes::schedule::schema::ScheduleEntryResponse;
+use crate::extractors::base::AsyncExtractor;
+use crate::extractors::provider::Provider;
+use crate::routes::schedule::schema::{EntryQuery, ScheduleEntryResponse};
 use crate::routes::schema::ResponseError;
-use crate::AppState;
 use actix_web::{get, web};
+use chrono::Utc;
+use providers::base::find_by_name;
 
 #[utoipa::path(responses(
     (status = OK, body = ScheduleEntryResponse),
@@ -25,21 +28,22 @@ use actix_web::{get, web};
 ))]
 #[get("/group/{group_name}")]
 pub async fn group_by_name(
-    path: web::Path<String>,
-    app_state: web::Data<AppState>,
+    path: web::Path<Path>,
+    query: web::Query<EntryQuery>,
+    provider: AsyncExtractor<Provider>,
 ) -> ServiceResponse {
-    let group_name = path.into_inner();
+    let snapshot = provider.get_schedule().await;
+    let timezone = provider.settings().timezone;
+    let filter = query.filter(Utc::now().with_timezone(&timezone).date_naive());
 
-    match app_state
-        .get_schedule_snapshot("eng_polytechnic")
-        .await
-        .unwrap()
-        .data
-        .groups
-        .get(&group_name)
-    {
+    let name = path.into_inner().group_name;
+    let entries = &snapshot.data.groups;
+
+    let entry = find_by_name(entries, &name).map(|(_, entry)| entry);
+
+    match entry {
         None => Err(ErrorCode::NotFound),
-        Some(entry) => Ok(entry.clone().into()),
+        Some(entry) => Ok(filter.apply(entry, timezone).into()),
     }
     .into()
 }
@@ -48,8 +52,15 @@ mod schema {
     use crate::routes::schedule::schema::ScheduleEntryResponse;
     use actix_macros::ErrResponse;
     use derive_more::Display;
-    use serde::Serialize;
-    use utoipa::ToSchema;
+    use serde::{Deserialize, Serialize};
+    use utoipa::{IntoParams, ToSchema};
+
+    #[derive(Deserialize, IntoParams)]
+    #[into_params(parameter_in = Path)]
+    pub struct Path {
+        /// Group name.
+        pub group_name: String,
+    }
 
     pub type ServiceResponse = crate::routes::schema::Response<ScheduleEntryResponse, ErrorCode>;
 
@@ -0,0 +1,84 @@
+use self::schema::*;
+use crate::extractors::base::AsyncExtractor;
+use crate::extractors::provider::ProviderSnapshot;
+use actix_web::get;
+use std::collections::BTreeMap;
+
+#[utoipa::path(responses((status = OK, body = Response)))]
+#[get("/group-facets")]
+pub async fn group_facets(snapshot: AsyncExtractor<ProviderSnapshot>) -> Response {
+    let mut specialties: BTreeMap<String, BTreeMap<Option<u32>, Vec<String>>> = BTreeMap::new();
+    let mut other = Vec::new();
+
+    for name in snapshot.data.groups.keys() {
+        match snapshot.data.group_info.get(name) {
+            Some(info) => specialties
+                .entry(info.specialty.clone())
+                .or_default()
+                .entry(info.course)
+                .or_default()
+                .push(name.clone()),
+            None => other.push(name.clone()),
+        }
+    }
+
+    other.sort();
+
+    Response {
+        specialties: specialties
+            .into_iter()
+            .map(|(name, courses)| Specialty {
+                name,
+                courses: courses
+                    .into_iter()
+                    .map(|(course, mut groups)| {
+                        groups.sort();
+
+                        Course { course, groups }
+                    })
+                    .collect(),
+            })
+            .collect(),
+        other,
+    }
+}
+
+mod schema {
+    use actix_macros::ResponderJson;
+    use serde::Serialize;
+    use utoipa::ToSchema;
+
+    #[derive(Serialize, ToSchema, ResponderJson)]
+    #[schema(as = GetGroupFacets::Response)]
+    pub struct Response {
+        /// Specialties sorted in alphabetical order.
+        pub specialties: Vec<Specialty>,
+
+        /// Groups with names in an unknown format, sorted in alphabetical order.
+        #[schema(examples(json!(["Консультации"])))]
+        pub other: Vec<String>,
+    }
+
+    #[derive(Serialize, ToSchema)]
+    #[schema(as = GetGroupFacets::Specialty)]
+    pub struct Specialty {
+        /// Abbreviation of the specialty.
+        #[schema(examples("ИС"))]
+        pub name: String,
+
+        /// Courses of the specialty in ascending order.
+        pub courses: Vec<Course>,
+    }
+
+    #[derive(Serialize, ToSchema)]
+    #[schema(as = GetGroupFacets::Course)]
+    pub struct Course {
+        /// Course number, absent if it can't be determined.
+        #[schema(examples(3))]
+        pub course: Option<u32>,
+
+        /// Group names sorted in alphabetical order.
+        #[schema(examples(json!(["ИС-214/23", "ИС-224/23"])))]
+        pub groups: Vec<String>,
+    }
+}
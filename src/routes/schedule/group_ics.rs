@@ -0,0 +1,67 @@
+use self::schema::*;
+use crate::extractors::base::AsyncExtractor;
+use crate::extractors::provider::Provider;
+use crate::routes::schema::ResponseError;
+use actix_web::{get, web};
+use providers::base::{find_by_name, to_ical};
+
+#[utoipa::path(responses(
+    (status = OK, content_type = "text/calendar", body = String),
+    (
+        status = NOT_FOUND,
+        body = ResponseError<ErrorCode>,
+        example = json!({
+            "code": "NOT_FOUND",
+            "message": "Required group not found."
+        })
+    ),
+))]
+#[get("/ics/group/{group_name}")]
+pub async fn group_ics(
+    path: web::Path<Path>,
+    provider: AsyncExtractor<Provider>,
+) -> ServiceResponse {
+    let snapshot = provider.get_schedule().await;
+
+    let name = path.into_inner().group_name;
+    let entries = &snapshot.data.groups;
+
+    let entry = find_by_name(entries, &name).map(|(_, entry)| entry);
+
+    match entry {
+        None => Err(ErrorCode::NotFound),
+        Some(entry) => Ok(to_ical(
+            entry,
+            provider.settings().timezone,
+            snapshot.updated_at,
+        )),
+    }
+    .into()
+}
+
+mod schema {
+    use crate::routes::schedule::schema::ICalendarResponse;
+    use actix_macros::ErrResponse;
+    use derive_more::Display;
+    use serde::{Deserialize, Serialize};
+    use utoipa::{IntoParams, ToSchema};
+
+    #[derive(Deserialize, IntoParams)]
+    #[into_params(parameter_in = Path)]
+    pub struct Path {
+        /// Group name.
+        pub group_name: String,
+    }
+
+    pub type ServiceResponse = ICalendarResponse<ErrorCode>;
+
+    #[derive(Clone, Serialize, Display, ToSchema, ErrResponse)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[schema(as = GroupIcs::ErrorCode)]
+    pub enum ErrorCode {
+        /// Group not found.
+        #[status_code = "actix_web::http::StatusCode::NOT_FOUND"]
+        #[display("Required group not found.")]
+        NotFound,
+    }
+}
@@ -1,19 +1,12 @@
 use self::schema::*;
-use crate::AppState;
-use actix_web::{get, web};
+use crate::extractors::base::AsyncExtractor;
+use crate::extractors::provider::ProviderSnapshot;
+use actix_web::get;
 
 #[utoipa::path(responses((status = OK, body = Response)))]
 #[get("/group-names")]
-pub async fn group_names(app_state: web::Data<AppState>) -> Response {
-    let mut names: Vec<String> = app_state
-        .get_schedule_snapshot("eng_polytechnic")
-        .await
-        .unwrap()
-        .data
-        .groups
-        .keys()
-        .cloned()
-        .collect();
+pub async fn group_names(snapshot: AsyncExtractor<ProviderSnapshot>) -> Response {
+    let mut names: Vec<String> = snapshot.data.groups.keys().cloned().collect();
 
     names.sort();
 
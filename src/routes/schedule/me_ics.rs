@@ -0,0 +1,83 @@
+use self::schema::*;
+use crate::AppState;
+use crate::extractors::base::AsyncExtractor;
+use crate::routes::schedule::schema::user_filter;
+use crate::routes::schema::ResponseError;
+use actix_web::{get, web};
+use database::entity::User;
+use providers::base::to_ical;
+
+#[utoipa::path(responses(
+    (status = OK, content_type = "text/calendar", body = String),
+    (
+        status = NOT_FOUND,
+        body = ResponseError<ErrorCode>,
+        example = json!({
+            "code": "NOT_FOUND",
+            "message": "Required group not found."
+        })
+    ),
+))]
+#[get("/ics/me")]
+pub async fn me_ics(user: AsyncExtractor<User>, app_state: web::Data<AppState>) -> ServiceResponse {
+    let user = user.into_inner();
+
+    let Some(group) = &user.group else {
+        return Err(ErrorCode::SignUpNotCompleted).into();
+    };
+
+    let provider_id = user
+        .provider
+        .as_deref()
+        .unwrap_or(app_state.get_default_provider());
+
+    let Some(provider) = app_state.get_provider(provider_id) else {
+        return Err(ErrorCode::UnknownProvider).into();
+    };
+
+    let snapshot = provider.get_schedule().await;
+
+    match snapshot.data.groups.get(group) {
+        None => Err(ErrorCode::NotFound),
+        Some(entry) => {
+            let timezone = provider.settings().timezone;
+
+            Ok(to_ical(
+                &user_filter(&user).apply(entry, timezone),
+                timezone,
+                snapshot.updated_at,
+            ))
+        }
+    }
+    .into()
+}
+
+mod schema {
+    use crate::routes::schedule::schema::ICalendarResponse;
+    use actix_macros::ErrResponse;
+    use derive_more::Display;
+    use serde::Serialize;
+    use utoipa::ToSchema;
+
+    pub type ServiceResponse = ICalendarResponse<ErrorCode>;
+
+    #[derive(Clone, Serialize, Display, ToSchema, ErrResponse)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[schema(as = MeIcs::ErrorCode)]
+    pub enum ErrorCode {
+        /// The user tried to access the API without completing singing up.
+        #[status_code = "actix_web::http::StatusCode::FORBIDDEN"]
+        #[display("You have not completed signing up.")]
+        SignUpNotCompleted,
+
+        /// Group not found.
+        #[status_code = "actix_web::http::StatusCode::NOT_FOUND"]
+        #[display("Required group not found.")]
+        NotFound,
+
+        /// The provider selected by the user is no longer available.
+        #[status_code = "actix_web::http::StatusCode::NOT_FOUND"]
+        #[display("Schedule provider of the user not found.")]
+        UnknownProvider,
+    }
+}
@@ -1,16 +1,46 @@
+mod batch;
+mod bells;
+mod buildings;
 mod cache_status;
+mod calendar;
+mod feed;
+mod followed;
 mod group;
 mod group_by_name;
+mod group_facets;
+mod group_ics;
 mod group_names;
+mod me_ics;
+mod now;
 mod get;
 mod schema;
+mod search;
+mod subject;
+mod subject_names;
 mod teacher;
+mod teacher_ics;
+mod teacher_me;
 mod teacher_names;
 
+pub use batch::*;
+pub use bells::*;
+pub use buildings::*;
 pub use cache_status::*;
+pub use calendar::*;
+pub use feed::*;
+pub use followed::*;
 pub use group::*;
 pub use group_by_name::*;
+pub use group_facets::*;
+pub use group_ics::*;
 pub use group_names::*;
+pub use me_ics::*;
+pub use now::*;
 pub use get::*;
+pub use search::*;
+pub use subject::*;
+pub use subject_names::*;
 pub use teacher::*;
+pub use teacher_ics::*;
+pub use teacher_me::*;
 pub use teacher_names::*;
@@ -0,0 +1,117 @@
+use self::schema::*;
+use crate::AppState;
+use crate::extractors::base::AsyncExtractor;
+use crate::routes::schedule::schema::user_filter;
+use crate::routes::schema::ResponseError;
+use actix_web::{get, web};
+use chrono::Utc;
+use database::entity::User;
+use providers::base::{find_by_name, upcoming};
+
+#[utoipa::path(responses(
+    (status = OK, body = Response),
+    (
+        status = NOT_FOUND,
+        body = ResponseError<ErrorCode>,
+        example = json!({
+            "code": "NOT_FOUND",
+            "message": "Required group not found."
+        })
+    ),
+))]
+#[get("/now")]
+pub async fn now(user: AsyncExtractor<User>, app_state: web::Data<AppState>) -> ServiceResponse {
+    let user = user.into_inner();
+
+    if user.group.is_none() && user.teacher_name.is_none() {
+        return Err(ErrorCode::SignUpNotCompleted).into();
+    }
+
+    let provider_id = user
+        .provider
+        .as_deref()
+        .unwrap_or(app_state.get_default_provider());
+
+    let Some(provider) = app_state.get_provider(provider_id) else {
+        return Err(ErrorCode::UnknownProvider).into();
+    };
+
+    let snapshot = provider.get_schedule().await;
+    let now = Utc::now();
+
+    let timezone = provider.settings().timezone;
+
+    let group = match &user.group {
+        None => None,
+        Some(group) => {
+            let Some(entry) = snapshot.data.groups.get(group) else {
+                return Err(ErrorCode::NotFound).into();
+            };
+
+            let entry = user_filter(&user).apply(entry, timezone);
+
+            Some(upcoming(&entry, now, timezone))
+        }
+    };
+
+    // a teacher without lessons this week isn't an error
+    let teacher = match (&user.teacher_name, &user.teacher_provider) {
+        (Some(teacher), Some(teacher_provider)) => match app_state.get_provider(teacher_provider) {
+            None => None,
+            Some(provider) => find_by_name(&provider.get_schedule().await.data.teachers, teacher)
+                .map(|(_, entry)| upcoming(entry, now, provider.settings().timezone)),
+        },
+        _ => None,
+    };
+
+    Ok(Response {
+        now,
+        group,
+        teacher,
+    })
+    .into()
+}
+
+mod schema {
+    use actix_macros::{ErrResponse, OkResponse};
+    use chrono::{DateTime, Utc};
+    use derive_more::Display;
+    use providers::base::Upcoming;
+    use serde::Serialize;
+    use utoipa::ToSchema;
+
+    pub type ServiceResponse = crate::routes::schema::Response<Response, ErrorCode>;
+
+    #[derive(Serialize, ToSchema, OkResponse)]
+    #[schema(as = GetNow::Response)]
+    pub struct Response {
+        /// The moment the lessons were looked up for.
+        pub now: DateTime<Utc>,
+
+        /// Current and next lessons of the user's group.
+        pub group: Option<Upcoming>,
+
+        /// Current and next lessons of the teacher bound to the account.
+        pub teacher: Option<Upcoming>,
+    }
+
+    #[derive(Clone, Serialize, Display, ToSchema, ErrResponse)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[schema(as = GetNow::ErrorCode)]
+    pub enum ErrorCode {
+        /// The user tried to access the API without completing singing up.
+        #[status_code = "actix_web::http::StatusCode::FORBIDDEN"]
+        #[display("You have not completed signing up.")]
+        SignUpNotCompleted,
+
+        /// Group not found.
+        #[status_code = "actix_web::http::StatusCode::NOT_FOUND"]
+        #[display("Required group not found.")]
+        NotFound,
+
+        /// The provider selected by the user is no longer available.
+        #[status_code = "actix_web::http::StatusCode::NOT_FOUND"]
+        #[display("Schedule provider of the user not found.")]
+        UnknownProvider,
+    }
+}
@@ -1,11 +1,14 @@
-use crate::state::AppState;
+use crate::routes::schema::{PartialErrResponse, Response, ResponseError};
 use actix_macros::{OkResponse, ResponderJson};
-use actix_web::web;
-use providers::base::{ScheduleEntry, ScheduleSnapshot};
+use actix_web::body::EitherBody;
+use actix_web::{HttpRequest, HttpResponse, Responder};
+use chrono::{Days, NaiveDate, Weekday};
+use database::entity::User;
+use providers::base::{DayFilter, ScheduleEntry, ScheduleSnapshot};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
-use std::ops::Deref;
-use utoipa::ToSchema;
+use std::fmt::Display;
+use utoipa::{IntoParams, PartialSchema, ToSchema};
 
 /// Response from schedule server.
 #[derive(Serialize, ToSchema, OkResponse, ResponderJson)]
@@ -30,19 +33,12 @@ impl From<ScheduleEntry> for ScheduleEntryResponse {
     }
 }
 
-impl ScheduleView {
-    pub async fn from(app_state: &web::Data<AppState>) -> Self {
-        let schedule = app_state
-            .get_schedule_snapshot("eng_polytechnic")
-            .await
-            .unwrap()
-            .deref()
-            .clone();
-
+impl From<&ScheduleSnapshot> for ScheduleView {
+    fn from(value: &ScheduleSnapshot) -> Self {
         Self {
-            url: schedule.url,
-            groups: schedule.data.groups,
-            teachers: schedule.data.teachers,
+            url: value.url.clone(),
+            groups: value.data.groups.clone(),
+            teachers: value.data.teachers.clone(),
         }
     }
 }
@@ -72,3 +68,120 @@ impl From<&ScheduleSnapshot> for CacheStatus {
         }
     }
 }
+
+/// Day selected relative to the current date or by the day of the week.
+#[derive(Clone, Copy, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum DaySelector {
+    Today,
+    Tomorrow,
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+/// Lessons of the group as seen by the user, without the other subgroup.
+pub fn user_filter(user: &User) -> DayFilter {
+    DayFilter {
+        subgroup: user
+            .subgroup
+            .and_then(|subgroup| u8::try_from(subgroup).ok()),
+        ..Default::default()
+    }
+}
+
+/// Selection of the days of the schedule entry.
+///
+/// All specified conditions must hold for the day to be returned.
+#[derive(Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+#[serde(rename_all = "camelCase")]
+pub struct EntryQuery {
+    /// Only the day with this date.
+    pub date: Option<NaiveDate>,
+
+    /// The first date to return, inclusive.
+    pub from: Option<NaiveDate>,
+
+    /// The last date to return, inclusive.
+    pub to: Option<NaiveDate>,
+
+    /// Day of the week, or `today` and `tomorrow` in the timezone of the provider.
+    pub day: Option<DaySelector>,
+
+    /// Don't return the breaks between lessons.
+    #[serde(default)]
+    pub skip_breaks: bool,
+}
+
+impl EntryQuery {
+    /// Filter described by the query.
+    ///
+    /// # Arguments
+    ///
+    /// * `today`: the current date in the timezone of the provider.
+    pub fn filter(&self, today: NaiveDate) -> DayFilter {
+        let mut filter = DayFilter {
+            from: self.from,
+            to: self.to,
+            weekday: None,
+            skip_breaks: self.skip_breaks,
+            subgroup: None,
+        };
+
+        if let Some(date) = self.date {
+            filter = filter.date(date);
+        }
+
+        match self.day {
+            None => {}
+            Some(DaySelector::Today) => filter = filter.date(today),
+            Some(DaySelector::Tomorrow) => filter = filter.date(today + Days::new(1)),
+            Some(DaySelector::Monday) => filter.weekday = Some(Weekday::Mon),
+            Some(DaySelector::Tuesday) => filter.weekday = Some(Weekday::Tue),
+            Some(DaySelector::Wednesday) => filter.weekday = Some(Weekday::Wed),
+            Some(DaySelector::Thursday) => filter.weekday = Some(Weekday::Thu),
+            Some(DaySelector::Friday) => filter.weekday = Some(Weekday::Fri),
+            Some(DaySelector::Saturday) => filter.weekday = Some(Weekday::Sat),
+            Some(DaySelector::Sunday) => filter.weekday = Some(Weekday::Sun),
+        }
+
+        filter
+    }
+}
+
+/// iCalendar document of the schedule entry.
+///
+/// Errors are returned as JSON, the same way as in the other endpoints.
+pub struct ICalendarResponse<E>(pub Result<String, E>);
+
+impl<E> From<Result<String, E>> for ICalendarResponse<E> {
+    fn from(value: Result<String, E>) -> Self {
+        Self(value)
+    }
+}
+
+impl<E> Responder for ICalendarResponse<E>
+where
+    E: Serialize + PartialSchema + Display + PartialErrResponse + Clone + Into<ResponseError<E>>,
+{
+    type Body = EitherBody<String>;
+
+    fn respond_to(self, request: &HttpRequest) -> HttpResponse<Self::Body> {
+        match self.0 {
+            Ok(ical) => match HttpResponse::Ok()
+                .content_type("text/calendar; charset=utf-8")
+                .message_body(ical)
+            {
+                Ok(res) => res.map_into_left_body(),
+                Err(err) => HttpResponse::from_error(err).map_into_right_body(),
+            },
+
+            Err(err) => Response::<(), E>(Err(err)).respond_to(request),
+        }
+    }
+}
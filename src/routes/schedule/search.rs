@@ -0,0 +1,48 @@
+use self::schema::*;
+use crate::extractors::base::AsyncExtractor;
+use crate::extractors::provider::ProviderSnapshot;
+use actix_web::{get, web};
+use providers::base::search as search_names;
+
+/// Maximum number of results returned at once.
+const MAX_LIMIT: usize = 50;
+
+#[utoipa::path(responses((status = OK, body = Response)))]
+#[get("/search")]
+pub async fn search(
+    query: web::Query<Query>,
+    snapshot: AsyncExtractor<ProviderSnapshot>,
+) -> Response {
+    let limit = query.limit.unwrap_or(10).min(MAX_LIMIT);
+
+    Response {
+        results: search_names(&snapshot.data, &query.q, limit),
+    }
+}
+
+mod schema {
+    use actix_macros::ResponderJson;
+    use providers::base::SearchResult;
+    use serde::{Deserialize, Serialize};
+    use utoipa::{IntoParams, ToSchema};
+
+    #[derive(Deserialize, IntoParams)]
+    #[into_params(parameter_in = Query)]
+    pub struct Query {
+        /// Text to search for.
+        ///
+        /// Case, punctuation and the alphabet are ignored, small typos are tolerated.
+        #[param(example = "is-214")]
+        pub q: String,
+
+        /// Maximum number of results, 10 by default and 50 at most.
+        pub limit: Option<usize>,
+    }
+
+    #[derive(Serialize, ToSchema, ResponderJson)]
+    #[schema(as = Search::Response)]
+    pub struct Response {
+        /// Found groups, teachers, cabinets and subjects from the best match.
+        pub results: Vec<SearchResult>,
+    }
+}
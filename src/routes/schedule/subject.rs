@@ -0,0 +1,122 @@
+use self::schema::*;
+use crate::extractors::base::AsyncExtractor;
+use crate::extractors::provider::ProviderSnapshot;
+use crate::routes::schema::ResponseError;
+use actix_web::{get, web};
+use providers::base::LessonType;
+
+#[utoipa::path(responses(
+    (status = OK, body = Response),
+    (
+        status = NOT_FOUND,
+        body = ResponseError<ErrorCode>,
+        example = json!({
+            "code": "NOT_FOUND",
+            "message": "Required subject not found."
+        })
+    ),
+))]
+#[get("/subject/{name}")]
+pub async fn subject(
+    path: web::Path<Path>,
+    snapshot: AsyncExtractor<ProviderSnapshot>,
+) -> ServiceResponse {
+    let name = path.into_inner().name;
+
+    let Some(subject) = snapshot
+        .data
+        .subjects
+        .values()
+        .find(|subject| subject.name == name || subject.id == name)
+    else {
+        return Err(ErrorCode::NotFound).into();
+    };
+
+    let mut lessons: Vec<SubjectLesson> = snapshot
+        .data
+        .groups
+        .values()
+        .flat_map(|group| {
+            group.days.iter().flat_map(move |day| {
+                day.lessons
+                    .iter()
+                    .filter(|lesson| lesson.lesson_type != LessonType::Break)
+                    .filter(|lesson| lesson.subject_id.as_ref() == Some(&subject.id))
+                    .map(move |lesson| SubjectLesson {
+                        group: group.name.clone(),
+                        day: day.name.clone(),
+                        lesson: lesson.clone(),
+                    })
+            })
+        })
+        .collect();
+
+    lessons.sort_by(|a, b| {
+        a.lesson
+            .time
+            .start
+            .cmp(&b.lesson.time.start)
+            .then_with(|| a.group.cmp(&b.group))
+    });
+
+    Ok(Response {
+        id: subject.id.clone(),
+        name: subject.name.clone(),
+        lessons,
+    })
+    .into()
+}
+
+mod schema {
+    use actix_macros::{ErrResponse, OkResponse};
+    use derive_more::Display;
+    use providers::base::Lesson;
+    use serde::{Deserialize, Serialize};
+    use utoipa::{IntoParams, ToSchema};
+
+    #[derive(Deserialize, IntoParams)]
+    #[into_params(parameter_in = Path)]
+    pub struct Path {
+        /// Canonical name or identifier of the subject.
+        pub name: String,
+    }
+
+    /// Lesson of the subject in the schedule of one of the groups.
+    #[derive(Serialize, ToSchema)]
+    #[schema(as = SubjectSchedule::Lesson)]
+    pub struct SubjectLesson {
+        /// Group name.
+        pub group: String,
+
+        /// Day of the week.
+        pub day: String,
+
+        /// The lesson with its teachers, cabinets and time.
+        pub lesson: Lesson,
+    }
+
+    #[derive(Serialize, ToSchema, OkResponse)]
+    #[schema(as = SubjectSchedule::Response)]
+    pub struct Response {
+        /// Subject identifier.
+        pub id: String,
+
+        /// Canonical name of the subject.
+        pub name: String,
+
+        /// Every lesson of the subject in all groups, ordered by time.
+        pub lessons: Vec<SubjectLesson>,
+    }
+
+    pub type ServiceResponse = crate::routes::schema::Response<Response, ErrorCode>;
+
+    #[derive(Clone, Serialize, Display, ToSchema, ErrResponse)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[schema(as = SubjectSchedule::ErrorCode)]
+    pub enum ErrorCode {
+        /// Subject not found.
+        #[status_code = "actix_web::http::StatusCode::NOT_FOUND"]
+        #[display("Required subject not found.")]
+        NotFound,
+    }
+}
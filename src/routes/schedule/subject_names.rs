@@ -0,0 +1,33 @@
+use self::schema::*;
+use crate::extractors::base::AsyncExtractor;
+use crate::extractors::provider::ProviderSnapshot;
+use actix_web::get;
+
+#[utoipa::path(responses((status = OK, body = Response)))]
+#[get("/subject-names")]
+pub async fn subject_names(snapshot: AsyncExtractor<ProviderSnapshot>) -> Response {
+    let mut names: Vec<String> = snapshot
+        .data
+        .subjects
+        .values()
+        .map(|subject| subject.name.clone())
+        .collect();
+
+    names.sort();
+
+    Response { names }
+}
+
+mod schema {
+    use actix_macros::ResponderJson;
+    use serde::Serialize;
+    use utoipa::ToSchema;
+
+    #[derive(Serialize, ToSchema, ResponderJson)]
+    #[schema(as = GetSubjectNames::Response)]
+    pub struct Response {
+        /// List of canonical subject names sorted alphabetically.
+        #[schema(examples(json!(["Информатика"])))]
+        pub names: Vec<String>,
+    }
+}
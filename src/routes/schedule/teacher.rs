@@ -1,8 +1,11 @@
 use self::schema::*;
-use crate::AppState;
+use crate::extractors::base::AsyncExtractor;
+use crate::extractors::provider::Provider;
+use crate::routes::schedule::schema::EntryQuery;
 use crate::routes::schema::ResponseError;
 use actix_web::{get, web};
-use providers::base::ScheduleEntry;
+use chrono::Utc;
+use providers::base::{ScheduleEntry, find_by_name};
 
 #[utoipa::path(responses(
     (status = OK, body = ScheduleEntry),
@@ -16,18 +19,24 @@ use providers::base::ScheduleEntry;
     ),
 ))]
 #[get("/teacher/{name}")]
-pub async fn teacher(name: web::Path<String>, app_state: web::Data<AppState>) -> ServiceResponse {
-    match app_state
-        .get_schedule_snapshot("eng_polytechnic")
-        .await
-        .unwrap()
-        .data
-        .teachers
-        .get(&name.into_inner())
-    {
+pub async fn teacher(
+    path: web::Path<Path>,
+    query: web::Query<EntryQuery>,
+    provider: AsyncExtractor<Provider>,
+) -> ServiceResponse {
+    let snapshot = provider.get_schedule().await;
+    let timezone = provider.settings().timezone;
+    let filter = query.filter(Utc::now().with_timezone(&timezone).date_naive());
+
+    let name = path.into_inner().name;
+    let entries = &snapshot.data.teachers;
+
+    let entry = find_by_name(entries, &name).map(|(_, entry)| entry);
+
+    match entry {
         None => Err(ErrorCode::NotFound),
 
-        Some(entry) => Ok(entry.clone().into()),
+        Some(entry) => Ok(filter.apply(entry, timezone).into()),
     }
     .into()
 }
@@ -36,8 +45,15 @@ mod schema {
     use crate::routes::schedule::schema::ScheduleEntryResponse;
     use actix_macros::ErrResponse;
     use derive_more::Display;
-    use serde::Serialize;
-    use utoipa::ToSchema;
+    use serde::{Deserialize, Serialize};
+    use utoipa::{IntoParams, ToSchema};
+
+    #[derive(Deserialize, IntoParams)]
+    #[into_params(parameter_in = Path)]
+    pub struct Path {
+        /// Full name of the teacher.
+        pub name: String,
+    }
 
     pub type ServiceResponse = crate::routes::schema::Response<ScheduleEntryResponse, ErrorCode>;
 
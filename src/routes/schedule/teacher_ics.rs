@@ -0,0 +1,67 @@
+use self::schema::*;
+use crate::extractors::base::AsyncExtractor;
+use crate::extractors::provider::Provider;
+use crate::routes::schema::ResponseError;
+use actix_web::{get, web};
+use providers::base::{find_by_name, to_ical};
+
+#[utoipa::path(responses(
+    (status = OK, content_type = "text/calendar", body = String),
+    (
+        status = NOT_FOUND,
+        body = ResponseError<ErrorCode>,
+        example = json!({
+            "code": "NOT_FOUND",
+            "message": "Required teacher not found."
+        })
+    ),
+))]
+#[get("/ics/teacher/{name}")]
+pub async fn teacher_ics(
+    path: web::Path<Path>,
+    provider: AsyncExtractor<Provider>,
+) -> ServiceResponse {
+    let snapshot = provider.get_schedule().await;
+
+    let name = path.into_inner().name;
+    let entries = &snapshot.data.teachers;
+
+    let entry = find_by_name(entries, &name).map(|(_, entry)| entry);
+
+    match entry {
+        None => Err(ErrorCode::NotFound),
+        Some(entry) => Ok(to_ical(
+            entry,
+            provider.settings().timezone,
+            snapshot.updated_at,
+        )),
+    }
+    .into()
+}
+
+mod schema {
+    use crate::routes::schedule::schema::ICalendarResponse;
+    use actix_macros::ErrResponse;
+    use derive_more::Display;
+    use serde::{Deserialize, Serialize};
+    use utoipa::{IntoParams, ToSchema};
+
+    #[derive(Deserialize, IntoParams)]
+    #[into_params(parameter_in = Path)]
+    pub struct Path {
+        /// Full name of the teacher.
+        pub name: String,
+    }
+
+    pub type ServiceResponse = ICalendarResponse<ErrorCode>;
+
+    #[derive(Clone, Serialize, Display, ToSchema, ErrResponse)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[schema(as = TeacherIcs::ErrorCode)]
+    pub enum ErrorCode {
+        /// Teacher not found.
+        #[status_code = "actix_web::http::StatusCode::NOT_FOUND"]
+        #[display("Required teacher not found.")]
+        NotFound,
+    }
+}
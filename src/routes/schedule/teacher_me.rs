@@ -0,0 +1,98 @@
+//! Schedule of the teacher bound to the account.
+//!
+//! Teacher accounts get this endpoint and the teacher part of `/schedule/now`.
+//! The server has no channel to deliver notifications through,
+//! so clients poll `/schedule/now` instead of being notified about the next lesson.
+
+use self::schema::*;
+use crate::AppState;
+use crate::extractors::base::AsyncExtractor;
+use crate::routes::schedule::schema::{EntryQuery, ScheduleEntryResponse};
+use crate::routes::schema::ResponseError;
+use actix_web::{get, web};
+use chrono::Utc;
+use database::entity::User;
+use providers::base::find_by_name;
+
+#[utoipa::path(responses(
+    (status = OK, body = ScheduleEntryResponse),
+    (
+        status = FORBIDDEN,
+        body = ResponseError<ErrorCode>,
+        example = json!({
+            "code": "NOT_BOUND",
+            "message": "The account is not bound to a teacher."
+        })
+    ),
+    (
+        status = NOT_FOUND,
+        body = ResponseError<ErrorCode>,
+        example = json!({
+            "code": "NOT_FOUND",
+            "message": "Required teacher not found."
+        })
+    ),
+))]
+#[get("/teacher/me")]
+pub async fn teacher_me(
+    user: AsyncExtractor<User>,
+    query: web::Query<EntryQuery>,
+    app_state: web::Data<AppState>,
+) -> ServiceResponse {
+    let user = user.into_inner();
+
+    let Some(teacher) = &user.teacher_name else {
+        return Err(ErrorCode::NotBound).into();
+    };
+
+    // the teacher is bound together with the provider the claim was made in
+    let provider_id = user
+        .teacher_provider
+        .as_deref()
+        .unwrap_or(app_state.get_default_provider());
+
+    let Some(provider) = app_state.get_provider(provider_id) else {
+        return Err(ErrorCode::UnknownProvider).into();
+    };
+
+    let snapshot = provider.get_schedule().await;
+    let timezone = provider.settings().timezone;
+    let filter = query.filter(Utc::now().with_timezone(&timezone).date_naive());
+
+    // the name may have been changed by an alias since the binding
+    match find_by_name(&snapshot.data.teachers, teacher) {
+        None => Err(ErrorCode::NotFound),
+        Some((_, entry)) => Ok(filter.apply(entry, timezone).into()),
+    }
+    .into()
+}
+
+mod schema {
+    use crate::routes::schedule::schema::ScheduleEntryResponse;
+    use actix_macros::ErrResponse;
+    use derive_more::Display;
+    use serde::Serialize;
+    use utoipa::ToSchema;
+
+    pub type ServiceResponse = crate::routes::schema::Response<ScheduleEntryResponse, ErrorCode>;
+
+    #[derive(Clone, Serialize, Display, ToSchema, ErrResponse)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[schema(as = TeacherMeSchedule::ErrorCode)]
+    pub enum ErrorCode {
+        /// No teacher claim of the user has been approved yet.
+        #[status_code = "actix_web::http::StatusCode::FORBIDDEN"]
+        #[display("The account is not bound to a teacher.")]
+        NotBound,
+
+        /// Teacher has no lessons in the current schedule.
+        #[status_code = "actix_web::http::StatusCode::NOT_FOUND"]
+        #[display("Required teacher not found.")]
+        NotFound,
+
+        /// The provider of the bound teacher is no longer available.
+        #[status_code = "actix_web::http::StatusCode::NOT_FOUND"]
+        #[display("Schedule provider of the teacher not found.")]
+        UnknownProvider,
+    }
+}
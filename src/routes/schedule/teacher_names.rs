@@ -1,19 +1,12 @@
 use self::schema::*;
-use crate::AppState;
-use actix_web::{get, web};
+use crate::extractors::base::AsyncExtractor;
+use crate::extractors::provider::ProviderSnapshot;
+use actix_web::get;
 
 #[utoipa::path(responses((status = OK, body = Response)))]
 #[get("/teacher-names")]
-pub async fn teacher_names(app_state: web::Data<AppState>) -> Response {
-    let mut names: Vec<String> = app_state
-        .get_schedule_snapshot("eng_polytechnic")
-        .await
-        .unwrap()
-        .data
-        .teachers
-        .keys()
-        .cloned()
-        .collect();
+pub async fn teacher_names(snapshot: AsyncExtractor<ProviderSnapshot>) -> Response {
+    let mut names: Vec<String> = snapshot.data.teachers.keys().cloned().collect();
 
     names.sort();
 
@@ -148,6 +148,22 @@ pub mod user {
         #[schema(examples("ИС-214/23"))]
         pub group: Option<String>,
 
+        /// Поставщик расписания группы
+        #[schema(examples("eng_polytechnic", json!(null)))]
+        pub provider: Option<String>,
+
+        /// Подгруппа
+        #[schema(examples(1, json!(null)))]
+        pub subgroup: Option<i32>,
+
+        /// Преподаватель из расписания, привязанный к аккаунту
+        #[schema(examples("Иванов И.И.", json!(null)))]
+        pub teacher_name: Option<String>,
+
+        /// Поставщик расписания привязанного преподавателя
+        #[schema(examples("eng_polytechnic", json!(null)))]
+        pub teacher_provider: Option<String>,
+
         /// Роль
         pub role: UserRole,
 
@@ -173,6 +189,10 @@ pub mod user {
                 id: user.id.clone(),
                 username: user.username.clone(),
                 group: user.group.clone(),
+                provider: user.provider.clone(),
+                subgroup: user.subgroup,
+                teacher_name: user.teacher_name.clone(),
+                teacher_provider: user.teacher_provider.clone(),
                 role: user.role.clone(),
                 vk_id: user.vk_id,
                 telegram_id: user.telegram_id,
@@ -188,6 +208,10 @@ pub mod user {
                 id: user.id.clone(),
                 username: user.username.clone(),
                 group: user.group.clone(),
+                provider: user.provider.clone(),
+                subgroup: user.subgroup,
+                teacher_name: user.teacher_name.clone(),
+                teacher_provider: user.teacher_provider.clone(),
                 role: user.role.clone(),
                 vk_id: user.vk_id,
                 telegram_id: user.telegram_id,
@@ -203,6 +227,10 @@ pub mod user {
                 id: user.id,
                 username: user.username,
                 group: user.group,
+                provider: user.provider,
+                subgroup: user.subgroup,
+                teacher_name: user.teacher_name,
+                teacher_provider: user.teacher_provider,
                 role: user.role,
                 vk_id: user.vk_id,
                 telegram_id: user.telegram_id,
@@ -211,3 +239,153 @@ pub mod user {
         }
     }
 }
+
+pub mod teacher_claim {
+    use actix_macros::{OkResponse, ResponderJson};
+    use chrono::{DateTime, Utc};
+    use database::entity::TeacherClaim;
+    use database::entity::sea_orm_active_enums::ClaimStatus;
+    use serde::Serialize;
+
+    /// Request of the user to bind the account to a teacher from the schedule.
+    #[derive(Serialize, utoipa::ToSchema, ResponderJson, OkResponse)]
+    #[serde(rename_all = "camelCase")]
+    pub struct TeacherClaimResponse {
+        /// Identifier.
+        pub id: i32,
+
+        /// Identifier of the user.
+        #[schema(examples("67dcc9a9507b0000772744a2"))]
+        pub user_id: String,
+
+        /// Provider of the schedule the teacher was found in.
+        #[schema(examples("eng_polytechnic"))]
+        pub provider: String,
+
+        /// Name of the teacher as written in the schedule.
+        #[schema(examples("Иванов И.И."))]
+        pub teacher_name: String,
+
+        /// Decision of the administrator.
+        pub status: ClaimStatus,
+
+        /// Moment the claim was made.
+        pub created_at: DateTime<Utc>,
+
+        /// Moment the claim was approved or rejected.
+        pub decided_at: Option<DateTime<Utc>>,
+    }
+
+    impl From<TeacherClaim> for TeacherClaimResponse {
+        fn from(claim: TeacherClaim) -> Self {
+            Self {
+                id: claim.id,
+                user_id: claim.user_id,
+                provider: claim.provider,
+                teacher_name: claim.teacher_name,
+                status: claim.status,
+                created_at: claim.created_at.to_utc(),
+                decided_at: claim.decided_at.map(|time| time.to_utc()),
+            }
+        }
+    }
+}
+
+pub mod role_request {
+    use actix_macros::{OkResponse, ResponderJson};
+    use chrono::{DateTime, Utc};
+    use database::entity::RoleRequest;
+    use database::entity::sea_orm_active_enums::{ClaimStatus, UserRole};
+    use serde::Serialize;
+
+    /// Request of the user for a role that has to be verified by an administrator.
+    #[derive(Serialize, utoipa::ToSchema, ResponderJson, OkResponse)]
+    #[serde(rename_all = "camelCase")]
+    pub struct RoleRequestResponse {
+        /// Identifier.
+        pub id: i32,
+
+        /// Identifier of the user.
+        #[schema(examples("67dcc9a9507b0000772744a2"))]
+        pub user_id: String,
+
+        /// Requested role.
+        pub role: UserRole,
+
+        /// Role of the user when the request was made.
+        pub from_role: UserRole,
+
+        /// Decision of the administrator.
+        pub status: ClaimStatus,
+
+        /// Moment the role was requested.
+        pub created_at: DateTime<Utc>,
+
+        /// Moment the request was approved or rejected.
+        pub decided_at: Option<DateTime<Utc>>,
+
+        /// Identifier of the administrator who made the decision.
+        pub decided_by: Option<String>,
+
+        /// Explanation of the decision.
+        pub comment: Option<String>,
+    }
+
+    impl From<RoleRequest> for RoleRequestResponse {
+        fn from(request: RoleRequest) -> Self {
+            Self {
+                id: request.id,
+                user_id: request.user_id,
+                role: request.role,
+                from_role: request.from_role,
+                status: request.status,
+                created_at: request.created_at.to_utc(),
+                decided_at: request.decided_at.map(|time| time.to_utc()),
+                decided_by: request.decided_by,
+                comment: request.comment,
+            }
+        }
+    }
+}
+
+pub mod user_follow {
+    use actix_macros::{OkResponse, ResponderJson};
+    use chrono::{DateTime, Utc};
+    use database::entity::UserFollow;
+    use database::entity::sea_orm_active_enums::FollowKind;
+    use serde::Serialize;
+
+    /// Group, teacher or cabinet whose schedule the user tracks.
+    #[derive(Serialize, utoipa::ToSchema, ResponderJson, OkResponse)]
+    #[serde(rename_all = "camelCase")]
+    pub struct UserFollowResponse {
+        /// Identifier.
+        pub id: i32,
+
+        /// Provider of the schedule the entry was found in.
+        #[schema(examples("eng_polytechnic"))]
+        pub provider: String,
+
+        /// Kind of the entry.
+        pub kind: FollowKind,
+
+        /// Name of the entry as written in the schedule.
+        #[schema(examples("ИС-214/23"))]
+        pub name: String,
+
+        /// Moment the entry was followed.
+        pub created_at: DateTime<Utc>,
+    }
+
+    impl From<UserFollow> for UserFollowResponse {
+        fn from(follow: UserFollow) -> Self {
+            Self {
+                id: follow.id,
+                provider: follow.provider,
+                kind: follow.kind,
+                name: follow.name,
+                created_at: follow.created_at.to_utc(),
+            }
+        }
+    }
+}
@@ -0,0 +1,234 @@
+mod revoke;
+mod rotate;
+mod schema;
+mod show;
+
+pub use revoke::*;
+pub use rotate::*;
+pub use show::*;
+
+use sha2::{Digest, Sha256};
+use std::fmt::Write;
+
+/// Only the hash of the token is stored, so a leaked database doesn't give access to the feeds.
+pub fn hash_token(token: &str) -> String {
+    Sha256::digest(token)
+        .iter()
+        .fold(String::new(), |mut hex, byte| {
+            write!(hex, "{byte:02x}").unwrap();
+            hex
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::routes::schedule::feed;
+    use crate::test_env::tests::{static_app_state, test_app_state, test_env};
+    use crate::utility::jwt;
+    use actix_test::test_app;
+    use actix_web::http::{Method, StatusCode};
+    use actix_web::{test, web};
+    use database::entity::sea_orm_active_enums::UserRole;
+    use database::entity::{ActiveUser, UserColumn, UserEntity, UserType};
+    use database::query::Query;
+    use database::sea_orm::sea_query::Expr;
+    use database::sea_orm::{
+        ActiveModelTrait, ColumnTrait, EntityTrait, IntoActiveModel, QueryFilter, Set,
+    };
+    use serde_json::Value;
+
+    /// Creates a clean user and returns its token.
+    async fn prepare(id: &str) -> String {
+        test_env();
+
+        let db = static_app_state().await.get_database().clone();
+
+        // the feed of the previous run is removed by the cascade
+        UserEntity::delete_by_id(id)
+            .exec(&db)
+            .await
+            .expect("Failed to remove user");
+
+        ActiveUser {
+            id: Set(id.to_string()),
+            username: Set(format!("test::{id}")),
+            password: Set(None),
+            vk_id: Set(None),
+            telegram_id: Set(None),
+            group: Set(Some("ИС-214/23".to_string())),
+            provider: Set(None),
+            subgroup: Set(None),
+            teacher_name: Set(None),
+            teacher_provider: Set(None),
+            role: Set(UserRole::Student),
+            android_version: Set(None),
+        }
+        .insert(&db)
+        .await
+        .expect("Failed to save user");
+
+        jwt::encode(UserType::Default, id)
+    }
+
+    async fn call(token: &str, method: Method, uri: &str) -> (StatusCode, Value) {
+        let app = test_app(
+            test_app_state().await,
+            web::scope("")
+                .service(
+                    web::scope("/me/calendar-feed")
+                        .service(show)
+                        .service(rotate)
+                        .service(revoke),
+                )
+                .service(web::scope("/schedule").service(feed)),
+        )
+        .await;
+
+        let req = test::TestRequest::with_uri(uri)
+            .method(method)
+            .insert_header(("Host", "attacker.example"))
+            .insert_header(("Authorization", format!("Bearer {token}")))
+            .to_request();
+
+        let resp = test::call_service(&app, req).await;
+        let status = resp.status();
+        let bytes = test::read_body(resp).await;
+
+        (
+            status,
+            serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+        )
+    }
+
+    async fn rotate_feed(token: &str) -> String {
+        let (status, body) = call(token, Method::POST, "/me/calendar-feed/rotate").await;
+        assert_eq!(status, StatusCode::OK);
+
+        let feed_token = body["token"].as_str().unwrap().to_string();
+
+        assert_eq!(
+            body["url"],
+            format!("https://example.com/schedule/feed/{feed_token}")
+        );
+        assert_eq!(
+            body["webcalUrl"],
+            format!("webcal://example.com/schedule/feed/{feed_token}")
+        );
+
+        feed_token
+    }
+
+    #[actix_web::test]
+    async fn rotate_feed_token() {
+        let token = prepare("test_calendar_feed_rotate").await;
+
+        let first = rotate_feed(&token).await;
+
+        let stored = Query::find_calendar_feed_by_user_id(
+            static_app_state().await.get_database(),
+            "test_calendar_feed_rotate",
+        )
+        .await
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(stored.token_hash, hash_token(&first));
+        assert_ne!(stored.token_hash, first);
+
+        let (status, body) = call(&token, Method::GET, "/me/calendar-feed").await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(body.get("token").is_none());
+
+        let (status, _) = call(&token, Method::GET, &format!("/schedule/feed/{first}")).await;
+        assert_eq!(status, StatusCode::OK);
+
+        let second = rotate_feed(&token).await;
+        assert_ne!(first, second);
+
+        let (status, _) = call(&token, Method::GET, &format!("/schedule/feed/{first}")).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (status, _) = call(&token, Method::GET, &format!("/schedule/feed/{second}")).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn teacher_feed() {
+        let id = "test_calendar_feed_teacher";
+        let token = prepare(id).await;
+
+        let db = static_app_state().await.get_database().clone();
+        let teacher = static_app_state()
+            .await
+            .get_schedule_snapshot("eng_polytechnic")
+            .await
+            .unwrap()
+            .data
+            .teachers
+            .keys()
+            .min()
+            .unwrap()
+            .clone();
+
+        // the teacher can be bound to one account only
+        UserEntity::update_many()
+            .col_expr(UserColumn::TeacherName, Expr::value(None::<String>))
+            .filter(UserColumn::TeacherName.eq(&teacher))
+            .exec(&db)
+            .await
+            .unwrap();
+
+        let mut user = UserEntity::find_by_id(id)
+            .one(&db)
+            .await
+            .unwrap()
+            .unwrap()
+            .into_active_model();
+
+        user.group = Set(None);
+        user.teacher_name = Set(Some(teacher));
+        user.teacher_provider = Set(Some("eng_polytechnic".to_string()));
+        user.role = Set(UserRole::Teacher);
+        user.update(&db).await.unwrap();
+
+        let feed_token = rotate_feed(&token).await;
+
+        let req = test::TestRequest::with_uri(&format!("/schedule/feed/{feed_token}")).to_request();
+        let app = test_app(
+            test_app_state().await,
+            web::scope("/schedule").service(feed),
+        )
+        .await;
+        let resp = test::call_service(&app, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert!(
+            String::from_utf8(test::read_body(resp).await.to_vec())
+                .unwrap()
+                .contains("BEGIN:VEVENT")
+        );
+    }
+
+    #[actix_web::test]
+    async fn revoke_feed() {
+        let token = prepare("test_calendar_feed_revoke").await;
+
+        let (status, body) = call(&token, Method::DELETE, "/me/calendar-feed").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["code"], "NOT_FOUND");
+
+        let feed_token = rotate_feed(&token).await;
+
+        let (status, _) = call(&token, Method::DELETE, "/me/calendar-feed").await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (status, _) = call(&token, Method::GET, "/me/calendar-feed").await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (status, body) =
+            call(&token, Method::GET, &format!("/schedule/feed/{feed_token}")).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["code"], "INVALID_TOKEN");
+    }
+}
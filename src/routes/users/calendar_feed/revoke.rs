@@ -0,0 +1,53 @@
+use self::schema::*;
+use crate::AppState;
+use crate::extractors::base::AsyncExtractor;
+use crate::routes::schema::ResponseError;
+use actix_web::{delete, web};
+use database::entity::{CalendarFeedColumn, CalendarFeedEntity, User};
+use database::sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
+
+/// Deletes the feed, its address stops working.
+#[utoipa::path(responses(
+    (status = OK),
+    (
+        status = NOT_FOUND,
+        body = ResponseError<ErrorCode>,
+        example = json!({
+            "code": "NOT_FOUND",
+            "message": "Calendar feed not created yet."
+        })
+    ),
+))]
+#[delete("")]
+pub async fn revoke(user: AsyncExtractor<User>, app_state: web::Data<AppState>) -> ServiceResponse {
+    let result = CalendarFeedEntity::delete_many()
+        .filter(CalendarFeedColumn::UserId.eq(&user.id))
+        .exec(app_state.get_database())
+        .await
+        .expect("Failed to revoke calendar feed");
+
+    if result.rows_affected == 0 {
+        return Err(ErrorCode::NotFound).into();
+    }
+
+    Ok(()).into()
+}
+
+mod schema {
+    use actix_macros::ErrResponse;
+    use derive_more::Display;
+    use serde::Serialize;
+    use utoipa::ToSchema;
+
+    pub type ServiceResponse = crate::routes::schema::Response<(), ErrorCode>;
+
+    #[derive(Clone, Serialize, Display, ToSchema, ErrResponse)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[schema(as = CalendarFeed::Revoke::ErrorCode)]
+    pub enum ErrorCode {
+        /// The user hasn't created the feed or has already revoked it.
+        #[status_code = "actix_web::http::StatusCode::NOT_FOUND"]
+        #[display("Calendar feed not created yet.")]
+        NotFound,
+    }
+}
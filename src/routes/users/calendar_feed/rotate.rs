@@ -0,0 +1,47 @@
+use crate::AppState;
+use crate::extractors::base::AsyncExtractor;
+use crate::routes::users::calendar_feed::hash_token;
+use crate::routes::users::calendar_feed::schema::CalendarFeedResponse;
+use actix_web::{HttpRequest, post, web};
+use chrono::Utc;
+use database::entity::{ActiveCalendarFeed, CalendarFeedColumn, CalendarFeedEntity, User};
+use database::sea_orm::sea_query::OnConflict;
+use database::sea_orm::{EntityTrait, Set};
+use uuid::Uuid;
+
+/// Creates the feed or replaces its token, so the old address stops working.
+///
+/// The token can't be shown again later, only a new one can be issued.
+#[utoipa::path(responses((status = OK, body = CalendarFeedResponse)))]
+#[post("/rotate")]
+pub async fn rotate(
+    request: HttpRequest,
+    user: AsyncExtractor<User>,
+    app_state: web::Data<AppState>,
+) -> CalendarFeedResponse {
+    let db = app_state.get_database();
+    let token = Uuid::new_v4().simple().to_string();
+
+    let feed = ActiveCalendarFeed {
+        user_id: Set(user.id.clone()),
+        token_hash: Set(hash_token(&token)),
+        created_at: Set(Utc::now().fixed_offset()),
+    };
+
+    let feed = CalendarFeedEntity::insert(feed)
+        .on_conflict(
+            OnConflict::column(CalendarFeedColumn::UserId)
+                .update_columns([CalendarFeedColumn::TokenHash, CalendarFeedColumn::CreatedAt])
+                .to_owned(),
+        )
+        .exec_with_returning(db)
+        .await
+        .expect("Failed to save calendar feed");
+
+    CalendarFeedResponse::new(
+        token,
+        feed.created_at.to_utc(),
+        &request,
+        &app_state.get_env().server.public_url,
+    )
+}
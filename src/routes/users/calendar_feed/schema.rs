@@ -0,0 +1,59 @@
+use actix_macros::{OkResponse, ResponderJson};
+use actix_web::HttpRequest;
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// Subscription to the schedule of the user for calendar applications.
+#[derive(Serialize, ToSchema, ResponderJson, OkResponse)]
+#[serde(rename_all = "camelCase")]
+#[schema(as = CalendarFeed)]
+pub struct CalendarFeedResponse {
+    /// Secret token included in the address of the feed, only the hash of it is stored.
+    pub token: String,
+
+    /// Address of the feed, doesn't require authorization.
+    #[schema(examples(
+        "https://example.com/api/v1/schedule/feed/9f1c0c3e6b1a4a2f8c4d1e5b7a9d3f20"
+    ))]
+    pub url: String,
+
+    /// The same address with the `webcal` scheme, opens the subscription dialog of calendar apps.
+    #[schema(examples(
+        "webcal://example.com/api/v1/schedule/feed/9f1c0c3e6b1a4a2f8c4d1e5b7a9d3f20"
+    ))]
+    pub webcal_url: String,
+
+    /// Moment the token was issued.
+    pub created_at: DateTime<Utc>,
+}
+
+impl CalendarFeedResponse {
+    pub fn new(
+        token: String,
+        created_at: DateTime<Utc>,
+        request: &HttpRequest,
+        public_url: &str,
+    ) -> Self {
+        // the host of the request can be spoofed, only the path is taken from it
+        let path = request
+            .url_for("calendar_feed", [&token])
+            .expect("Failed to build calendar feed url")
+            .path()
+            .to_string();
+
+        let url = format!("{public_url}{path}");
+
+        let webcal_url = match url.split_once("://") {
+            Some((_, rest)) => format!("webcal://{rest}"),
+            None => url.clone(),
+        };
+
+        Self {
+            token,
+            url,
+            webcal_url,
+            created_at,
+        }
+    }
+}
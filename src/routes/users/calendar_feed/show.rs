@@ -0,0 +1,61 @@
+use self::schema::*;
+use crate::AppState;
+use crate::extractors::base::AsyncExtractor;
+use crate::routes::schema::ResponseError;
+use actix_web::{get, web};
+use database::entity::User;
+use database::query::Query;
+
+/// Whether the feed exists, its token is only returned when it is issued.
+#[utoipa::path(responses(
+    (status = OK, body = Response),
+    (
+        status = NOT_FOUND,
+        body = ResponseError<ErrorCode>,
+        example = json!({
+            "code": "NOT_FOUND",
+            "message": "Calendar feed not created yet."
+        })
+    ),
+))]
+#[get("")]
+pub async fn show(user: AsyncExtractor<User>, app_state: web::Data<AppState>) -> ServiceResponse {
+    match Query::find_calendar_feed_by_user_id(app_state.get_database(), &user.id)
+        .await
+        .expect("Failed to find calendar feed")
+    {
+        None => Err(ErrorCode::NotFound),
+        Some(feed) => Ok(Response {
+            created_at: feed.created_at.to_utc(),
+        }),
+    }
+    .into()
+}
+
+mod schema {
+    use actix_macros::{ErrResponse, OkResponse};
+    use chrono::{DateTime, Utc};
+    use derive_more::Display;
+    use serde::Serialize;
+    use utoipa::ToSchema;
+
+    #[derive(Serialize, ToSchema, OkResponse)]
+    #[serde(rename_all = "camelCase")]
+    #[schema(as = CalendarFeed::Show::Response)]
+    pub struct Response {
+        /// Moment the current token was issued.
+        pub created_at: DateTime<Utc>,
+    }
+
+    pub type ServiceResponse = crate::routes::schema::Response<Response, ErrorCode>;
+
+    #[derive(Clone, Serialize, Display, ToSchema, ErrResponse)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[schema(as = CalendarFeed::Show::ErrorCode)]
+    pub enum ErrorCode {
+        /// The user hasn't created the feed or has revoked it.
+        #[status_code = "actix_web::http::StatusCode::NOT_FOUND"]
+        #[display("Calendar feed not created yet.")]
+        NotFound,
+    }
+}
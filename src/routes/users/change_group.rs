@@ -13,28 +13,47 @@ pub async fn change_group(
     data: web::Json<Request>,
 ) -> ServiceResponse {
     let user = user.into_inner();
+    let data = data.into_inner();
+
+    let provider = data
+        .provider
+        .or_else(|| user.provider.clone())
+        .unwrap_or_else(|| app_state.get_default_provider().to_string());
 
     if user
         .group
         .as_ref()
         .is_some_and(|group| group.eq(&data.group))
+        && user.provider.as_ref().is_some_and(|p| p.eq(&provider))
     {
         return Ok(()).into();
     }
 
-    if !app_state
-        .get_schedule_snapshot("eng_polytechnic")
-        .await
-        .unwrap()
-        .data
-        .groups
-        .contains_key(&data.group)
-    {
+    let Some(snapshot) = app_state.get_schedule_snapshot(&provider).await else {
+        return Err(ErrorCode::UnknownProvider).into();
+    };
+
+    if !snapshot.data.groups.contains_key(&data.group) {
         return Err(ErrorCode::NotFound).into();
     }
 
+    let current_provider = user
+        .provider
+        .as_deref()
+        .unwrap_or(app_state.get_default_provider());
+
+    // subgroups of another group are numbered independently
+    let group_changed =
+        user.group.as_ref() != Some(&data.group) || current_provider != provider.as_str();
+
     let mut active_user = user.clone().into_active_model();
-    active_user.group = Set(Some(data.into_inner().group));
+
+    if group_changed {
+        active_user.subgroup = Set(None);
+    }
+
+    active_user.group = Set(Some(data.group));
+    active_user.provider = Set(Some(provider));
 
     active_user.update(app_state.get_database()).await.unwrap();
 
@@ -54,6 +73,12 @@ mod schema {
     pub struct Request {
         // Group.
         pub group: String,
+
+        /// Schedule provider of the group.
+        ///
+        /// If not specified, the current provider of the user is kept.
+        #[serde(default)]
+        pub provider: Option<String>,
     }
 
     #[derive(Clone, Serialize, Display, ToSchema, ErrResponse)]
@@ -65,5 +90,93 @@ mod schema {
         #[display("The required group does not exist.")]
         #[status_code = "actix_web::http::StatusCode::NOT_FOUND"]
         NotFound,
+
+        /// The required schedule provider does not exist.
+        #[display("The required schedule provider does not exist.")]
+        #[status_code = "actix_web::http::StatusCode::NOT_FOUND"]
+        UnknownProvider,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_env::tests::{static_app_state, test_app_state, test_env};
+    use crate::utility::jwt;
+    use actix_test::test_app;
+    use actix_web::http::{Method, StatusCode};
+    use actix_web::test;
+    use database::entity::sea_orm_active_enums::UserRole;
+    use database::entity::{ActiveUser, UserEntity, UserType};
+    use database::query::Query;
+    use database::sea_orm::EntityTrait;
+    use serde_json::json;
+
+    const ID: &str = "test_change_group";
+
+    async fn change(group: &str) -> Option<i32> {
+        let app = test_app(test_app_state().await, change_group).await;
+
+        let req = test::TestRequest::with_uri("/change-group")
+            .method(Method::POST)
+            .insert_header((
+                "Authorization",
+                format!("Bearer {}", jwt::encode(UserType::Default, ID)),
+            ))
+            .set_json(json!({ "group": group }))
+            .to_request();
+
+        assert_eq!(test::call_service(&app, req).await.status(), StatusCode::OK);
+
+        Query::find_user_by_id(static_app_state().await.get_database(), ID)
+            .await
+            .unwrap()
+            .unwrap()
+            .subgroup
+    }
+
+    #[actix_web::test]
+    async fn change_group_resets_subgroup() {
+        test_env();
+
+        let app_state = static_app_state().await;
+        let db = app_state.get_database();
+
+        UserEntity::delete_by_id(ID)
+            .exec(db)
+            .await
+            .expect("Failed to remove user");
+
+        ActiveUser {
+            id: Set(ID.to_string()),
+            username: Set(format!("test::{ID}")),
+            password: Set(None),
+            vk_id: Set(None),
+            telegram_id: Set(None),
+            group: Set(Some("ИС-214/23".to_string())),
+            provider: Set(None),
+            subgroup: Set(Some(2)),
+            teacher_name: Set(None),
+            teacher_provider: Set(None),
+            role: Set(UserRole::Student),
+            android_version: Set(None),
+        }
+        .insert(db)
+        .await
+        .expect("Failed to save user");
+
+        let other = app_state
+            .get_schedule_snapshot(app_state.get_default_provider())
+            .await
+            .unwrap()
+            .data
+            .groups
+            .keys()
+            .find(|name| *name != "ИС-214/23")
+            .unwrap()
+            .clone();
+
+        assert_eq!(change("ИС-214/23").await, Some(2));
+        assert_eq!(change(&other).await, None);
     }
 }
@@ -0,0 +1,71 @@
+use self::schema::*;
+use crate::extractors::base::AsyncExtractor;
+use crate::routes::schema::ResponseError;
+use crate::state::AppState;
+use actix_web::{post, web};
+use database::entity::User;
+use database::sea_orm::{ActiveModelTrait, IntoActiveModel, Set};
+
+#[utoipa::path(responses(
+    (status = OK),
+    (
+        status = BAD_REQUEST,
+        body = ResponseError<ErrorCode>,
+        example = json!({
+            "code": "INVALID_SUBGROUP",
+            "message": "Subgroup must be 1 or 2."
+        })
+    ),
+))]
+#[post("/change-subgroup")]
+pub async fn change_subgroup(
+    app_state: web::Data<AppState>,
+    user: AsyncExtractor<User>,
+    data: web::Json<Request>,
+) -> ServiceResponse {
+    let user = user.into_inner();
+    let subgroup = data.into_inner().subgroup;
+
+    if subgroup.is_some_and(|subgroup| !(1..=2).contains(&subgroup)) {
+        return Err(ErrorCode::InvalidSubgroup).into();
+    }
+
+    let subgroup = subgroup.map(i32::from);
+
+    if user.subgroup == subgroup {
+        return Ok(()).into();
+    }
+
+    let mut active_user = user.into_active_model();
+    active_user.subgroup = Set(subgroup);
+    active_user.update(app_state.get_database()).await.unwrap();
+
+    Ok(()).into()
+}
+
+mod schema {
+    use actix_macros::ErrResponse;
+    use derive_more::Display;
+    use serde::{Deserialize, Serialize};
+    use utoipa::ToSchema;
+
+    pub type ServiceResponse = crate::routes::schema::Response<(), ErrorCode>;
+
+    #[derive(Deserialize, ToSchema)]
+    #[schema(as = ChangeSubgroup::Request)]
+    pub struct Request {
+        /// Number of the subgroup, `null` to see the lessons of both subgroups.
+        #[schema(examples(1, json!(null)))]
+        pub subgroup: Option<u8>,
+    }
+
+    #[derive(Clone, Serialize, Display, ToSchema, ErrResponse)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[schema(as = ChangeSubgroup::ErrorCode)]
+    pub enum ErrorCode {
+        /// Groups are split into two subgroups at most.
+        #[status_code = "actix_web::http::StatusCode::BAD_REQUEST"]
+        #[display("Subgroup must be 1 or 2.")]
+        InvalidSubgroup,
+    }
+}
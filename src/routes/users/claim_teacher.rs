@@ -0,0 +1,140 @@
+use self::schema::*;
+use crate::extractors::base::AsyncExtractor;
+use crate::routes::schema::ResponseError;
+use crate::routes::schema::teacher_claim::TeacherClaimResponse;
+use crate::state::AppState;
+use actix_web::{post, web};
+use chrono::Utc;
+use database::entity::sea_orm_active_enums::{ClaimStatus, UserRole};
+use database::entity::{ActiveTeacherClaim, TeacherClaimColumn, TeacherClaimEntity, User};
+use database::sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter, Set};
+use providers::base::find_by_name;
+
+/// Asks the administrators to bind the account to a teacher from the schedule.
+#[utoipa::path(responses(
+    (status = OK, body = TeacherClaimResponse),
+    (
+        status = FORBIDDEN,
+        body = ResponseError<ErrorCode>,
+        example = json!({
+            "code": "NOT_TEACHER",
+            "message": "Only teachers can claim a teacher schedule."
+        })
+    ),
+    (
+        status = NOT_FOUND,
+        body = ResponseError<ErrorCode>,
+        example = json!({
+            "code": "NOT_FOUND",
+            "message": "Required teacher not found."
+        })
+    ),
+    (
+        status = CONFLICT,
+        body = ResponseError<ErrorCode>,
+        example = json!({
+            "code": "ALREADY_PENDING",
+            "message": "Another claim is waiting for a decision."
+        })
+    ),
+))]
+#[post("/claim-teacher")]
+pub async fn claim_teacher(
+    app_state: web::Data<AppState>,
+    user: AsyncExtractor<User>,
+    data: web::Json<Request>,
+) -> ServiceResponse {
+    let user = user.into_inner();
+
+    if user.role != UserRole::Teacher {
+        return Err(ErrorCode::NotTeacher).into();
+    }
+
+    let provider = user
+        .provider
+        .clone()
+        .unwrap_or_else(|| app_state.get_default_provider().to_string());
+
+    let Some(snapshot) = app_state.get_schedule_snapshot(&provider).await else {
+        return Err(ErrorCode::UnknownProvider).into();
+    };
+
+    let teachers = &snapshot.data.teachers;
+    let name = &data.name;
+
+    // the name is stored as written in the schedule
+    let Some((teacher_name, _)) = find_by_name(teachers, name) else {
+        return Err(ErrorCode::NotFound).into();
+    };
+
+    let db = app_state.get_database();
+
+    let pending = TeacherClaimEntity::find()
+        .filter(TeacherClaimColumn::UserId.eq(&user.id))
+        .filter(TeacherClaimColumn::Status.eq(ClaimStatus::Pending))
+        .one(db)
+        .await
+        .expect("Failed to find teacher claim");
+
+    if pending.is_some() {
+        return Err(ErrorCode::AlreadyPending).into();
+    }
+
+    let claim = ActiveTeacherClaim {
+        user_id: Set(user.id),
+        provider: Set(provider),
+        teacher_name: Set(teacher_name.clone()),
+        status: Set(ClaimStatus::Pending),
+        created_at: Set(Utc::now().fixed_offset()),
+        decided_at: Set(None),
+        ..Default::default()
+    }
+    .insert(db)
+    .await
+    .expect("Failed to save teacher claim");
+
+    Ok(claim.into()).into()
+}
+
+mod schema {
+    use crate::routes::schema::teacher_claim::TeacherClaimResponse;
+    use actix_macros::ErrResponse;
+    use derive_more::Display;
+    use serde::{Deserialize, Serialize};
+    use utoipa::ToSchema;
+
+    pub type ServiceResponse = crate::routes::schema::Response<TeacherClaimResponse, ErrorCode>;
+
+    #[derive(Deserialize, ToSchema)]
+    #[schema(as = ClaimTeacher::Request)]
+    pub struct Request {
+        /// Name of the teacher in the schedule.
+        #[schema(examples("Иванов И.И."))]
+        pub name: String,
+    }
+
+    #[derive(Clone, Serialize, Display, ToSchema, ErrResponse)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[schema(as = ClaimTeacher::ErrorCode)]
+    pub enum ErrorCode {
+        /// The user doesn't have the teacher role.
+        #[status_code = "actix_web::http::StatusCode::FORBIDDEN"]
+        #[display("Only teachers can claim a teacher schedule.")]
+        NotTeacher,
+
+        /// The provider selected by the user is no longer available.
+        #[status_code = "actix_web::http::StatusCode::NOT_FOUND"]
+        #[display("Schedule provider of the user not found.")]
+        UnknownProvider,
+
+        /// Teacher not found.
+        #[status_code = "actix_web::http::StatusCode::NOT_FOUND"]
+        #[display("Required teacher not found.")]
+        NotFound,
+
+        /// The previous claim of the user hasn't been approved or rejected yet.
+        #[status_code = "actix_web::http::StatusCode::CONFLICT"]
+        #[display("Another claim is waiting for a decision.")]
+        AlreadyPending,
+    }
+}
@@ -0,0 +1,177 @@
+use self::schema::*;
+use crate::extractors::base::AsyncExtractor;
+use crate::routes::schema::ResponseError;
+use crate::routes::schema::user_follow::UserFollowResponse;
+use crate::state::AppState;
+use actix_web::{post, web};
+use chrono::Utc;
+use database::entity::sea_orm_active_enums::FollowKind;
+use database::entity::{ActiveUserFollow, User, UserEntity, UserFollowColumn, UserFollowEntity};
+use database::sea_orm::{
+    ActiveModelTrait, ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QuerySelect, Set,
+    SqlErr, TransactionTrait,
+};
+use providers::base::{cabinet_schedule, find_by_name};
+
+/// Maximum number of entries of one provider followed by one user.
+const MAX_FOLLOWS: u64 = 50;
+
+/// Starts tracking the schedule of a group, a teacher or a cabinet.
+///
+/// The entry is looked up in the schedule of the provider selected by the user.
+#[utoipa::path(responses(
+    (status = OK, body = UserFollowResponse),
+    (
+        status = NOT_FOUND,
+        body = ResponseError<ErrorCode>,
+        example = json!({
+            "code": "NOT_FOUND",
+            "message": "Required entry not found."
+        })
+    ),
+    (
+        status = CONFLICT,
+        body = ResponseError<ErrorCode>,
+        example = json!({
+            "code": "ALREADY_FOLLOWED",
+            "message": "The entry is already followed."
+        })
+    ),
+    (
+        status = BAD_REQUEST,
+        body = ResponseError<ErrorCode>,
+        example = json!({
+            "code": "TOO_MANY_FOLLOWS",
+            "message": "Too many entries are followed."
+        })
+    ),
+))]
+#[post("")]
+pub async fn follow(
+    app_state: web::Data<AppState>,
+    user: AsyncExtractor<User>,
+    data: web::Json<Request>,
+) -> ServiceResponse {
+    let user = user.into_inner();
+    let data = data.into_inner();
+
+    let provider = user
+        .provider
+        .clone()
+        .unwrap_or_else(|| app_state.get_default_provider().to_string());
+
+    let Some(snapshot) = app_state.get_schedule_snapshot(&provider).await else {
+        return Err(ErrorCode::UnknownProvider).into();
+    };
+
+    let groups = &snapshot.data.groups;
+    let teachers = &snapshot.data.teachers;
+
+    // the name is stored as written in the schedule
+    let name = match data.kind {
+        FollowKind::Group => find_by_name(groups, &data.name).map(|(key, _)| key.clone()),
+        FollowKind::Teacher => find_by_name(teachers, &data.name).map(|(key, _)| key.clone()),
+        FollowKind::Cabinet => cabinet_schedule(groups, &data.name).map(|entry| entry.name),
+    };
+
+    let Some(name) = name else {
+        return Err(ErrorCode::NotFound).into();
+    };
+
+    let txn = app_state
+        .get_database()
+        .begin()
+        .await
+        .expect("Failed to begin transaction");
+
+    // concurrent follows of the user wait here, so they can't exceed the limit together
+    UserEntity::find_by_id(&user.id)
+        .lock_exclusive()
+        .one(&txn)
+        .await
+        .expect("Failed to lock user");
+
+    // only the entries of one provider are shown together, so the limit is per provider
+    let count = UserFollowEntity::find()
+        .filter(UserFollowColumn::UserId.eq(&user.id))
+        .filter(UserFollowColumn::Provider.eq(&provider))
+        .count(&txn)
+        .await
+        .expect("Failed to count user follows");
+
+    if count >= MAX_FOLLOWS {
+        return Err(ErrorCode::TooManyFollows).into();
+    }
+
+    let result = ActiveUserFollow {
+        user_id: Set(user.id),
+        provider: Set(provider),
+        kind: Set(data.kind),
+        name: Set(name),
+        created_at: Set(Utc::now().fixed_offset()),
+        ..Default::default()
+    }
+    .insert(&txn)
+    .await;
+
+    match result {
+        Ok(follow) => {
+            txn.commit().await.expect("Failed to commit transaction");
+
+            Ok(follow.into())
+        }
+        // the unique index also covers concurrent requests
+        Err(error) => match error.sql_err() {
+            Some(SqlErr::UniqueConstraintViolation(_)) => Err(ErrorCode::AlreadyFollowed),
+            _ => panic!("Failed to save user follow: {error}"),
+        },
+    }
+    .into()
+}
+
+mod schema {
+    use crate::routes::schema::user_follow::UserFollowResponse;
+    use actix_macros::ErrResponse;
+    use database::entity::sea_orm_active_enums::FollowKind;
+    use derive_more::Display;
+    use serde::{Deserialize, Serialize};
+    use utoipa::ToSchema;
+
+    pub type ServiceResponse = crate::routes::schema::Response<UserFollowResponse, ErrorCode>;
+
+    #[derive(Deserialize, ToSchema)]
+    #[schema(as = Follows::Follow::Request)]
+    pub struct Request {
+        /// Kind of the entry.
+        pub kind: FollowKind,
+
+        /// Name of the group, the teacher or the cabinet.
+        #[schema(examples("ИС-214/23"))]
+        pub name: String,
+    }
+
+    #[derive(Clone, Serialize, Display, ToSchema, ErrResponse)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[schema(as = Follows::Follow::ErrorCode)]
+    pub enum ErrorCode {
+        /// The provider selected by the user is no longer available.
+        #[status_code = "actix_web::http::StatusCode::NOT_FOUND"]
+        #[display("Schedule provider of the user not found.")]
+        UnknownProvider,
+
+        /// There is no entry with this name in the current schedule.
+        #[status_code = "actix_web::http::StatusCode::NOT_FOUND"]
+        #[display("Required entry not found.")]
+        NotFound,
+
+        /// The user already follows this entry.
+        #[status_code = "actix_web::http::StatusCode::CONFLICT"]
+        #[display("The entry is already followed.")]
+        AlreadyFollowed,
+
+        /// The user follows 50 entries of the provider already.
+        #[status_code = "actix_web::http::StatusCode::BAD_REQUEST"]
+        #[display("Too many entries are followed.")]
+        TooManyFollows,
+    }
+}
@@ -0,0 +1,36 @@
+use self::schema::*;
+use crate::AppState;
+use crate::extractors::base::AsyncExtractor;
+use actix_web::{get, web};
+use database::entity::{User, UserFollowColumn, UserFollowEntity};
+use database::sea_orm::{ColumnTrait, EntityTrait, QueryFilter, QueryOrder};
+
+#[utoipa::path(responses((status = OK, body = Response)))]
+#[get("")]
+pub async fn list(user: AsyncExtractor<User>, app_state: web::Data<AppState>) -> Response {
+    let follows = UserFollowEntity::find()
+        .filter(UserFollowColumn::UserId.eq(&user.id))
+        .order_by_asc(UserFollowColumn::CreatedAt)
+        .all(app_state.get_database())
+        .await
+        .expect("Failed to list user follows")
+        .into_iter()
+        .map(Into::into)
+        .collect();
+
+    Response { follows }
+}
+
+mod schema {
+    use crate::routes::schema::user_follow::UserFollowResponse;
+    use actix_macros::ResponderJson;
+    use serde::Serialize;
+    use utoipa::ToSchema;
+
+    #[derive(Serialize, ToSchema, ResponderJson)]
+    #[schema(as = Follows::List::Response)]
+    pub struct Response {
+        /// Followed entries of every provider, from the oldest to the newest.
+        pub follows: Vec<UserFollowResponse>,
+    }
+}
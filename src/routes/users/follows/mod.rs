@@ -0,0 +1,200 @@
+mod follow;
+mod list;
+mod unfollow;
+
+pub use follow::*;
+pub use list::*;
+pub use unfollow::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::routes::schedule::followed;
+    use crate::test_env::tests::{static_app_state, test_app_state, test_env};
+    use crate::utility::jwt;
+    use actix_test::test_app;
+    use actix_web::http::{Method, StatusCode};
+    use actix_web::{test, web};
+    use chrono::Utc;
+    use database::entity::sea_orm_active_enums::{FollowKind, UserRole};
+    use database::entity::{ActiveUser, ActiveUserFollow, UserEntity, UserType};
+    use database::sea_orm::{ActiveModelTrait, EntityTrait, Set};
+    use serde_json::{Value, json};
+
+    /// Creates a clean user and returns its token.
+    async fn prepare(id: &str) -> String {
+        test_env();
+
+        let db = static_app_state().await.get_database().clone();
+
+        // follows of the previous run are removed by the cascade
+        UserEntity::delete_by_id(id)
+            .exec(&db)
+            .await
+            .expect("Failed to remove user");
+
+        ActiveUser {
+            id: Set(id.to_string()),
+            username: Set(format!("test::{id}")),
+            password: Set(None),
+            vk_id: Set(None),
+            telegram_id: Set(None),
+            group: Set(Some("ИС-214/23".to_string())),
+            provider: Set(None),
+            subgroup: Set(None),
+            teacher_name: Set(None),
+            teacher_provider: Set(None),
+            role: Set(UserRole::Student),
+            android_version: Set(None),
+        }
+        .insert(&db)
+        .await
+        .expect("Failed to save user");
+
+        jwt::encode(UserType::Default, id)
+    }
+
+    async fn call(
+        token: &str,
+        method: Method,
+        uri: &str,
+        body: Option<Value>,
+    ) -> (StatusCode, Value) {
+        let app = test_app(
+            test_app_state().await,
+            web::scope("")
+                .service(
+                    web::scope("/me/follows")
+                        .service(list)
+                        .service(follow)
+                        .service(unfollow),
+                )
+                .service(followed),
+        )
+        .await;
+
+        let mut req = test::TestRequest::with_uri(uri)
+            .method(method)
+            .insert_header(("Authorization", format!("Bearer {token}")));
+
+        if let Some(body) = body {
+            req = req.set_json(body);
+        }
+
+        let resp = test::call_service(&app, req.to_request()).await;
+        let status = resp.status();
+        let bytes = test::read_body(resp).await;
+
+        (
+            status,
+            serde_json::from_slice(&bytes).unwrap_or(Value::Null),
+        )
+    }
+
+    fn group(name: &str) -> Option<Value> {
+        Some(json!({ "kind": "GROUP", "name": name }))
+    }
+
+    #[actix_web::test]
+    async fn follow_flow() {
+        let token = prepare("test_follow_flow").await;
+
+        let (status, body) = call(&token, Method::POST, "/me/follows", group("ИС-214/23")).await;
+        assert_eq!(status, StatusCode::OK);
+        let id = body["id"].as_i64().unwrap();
+
+        let (status, body) = call(&token, Method::POST, "/me/follows", group("ИС-214/23")).await;
+        assert_eq!(status, StatusCode::CONFLICT);
+        assert_eq!(body["code"], "ALREADY_FOLLOWED");
+
+        let (status, body) = call(&token, Method::GET, "/me/follows", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["follows"].as_array().unwrap().len(), 1);
+
+        let (status, body) = call(&token, Method::GET, "/followed", None).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(body["entries"][0]["name"], "ИС-214/23");
+        assert!(!body["entries"][0]["entry"].is_null());
+
+        let uri = format!("/me/follows/{id}");
+
+        let (status, _) = call(&token, Method::DELETE, &uri, None).await;
+        assert_eq!(status, StatusCode::OK);
+
+        let (status, body) = call(&token, Method::DELETE, &uri, None).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["code"], "NOT_FOUND");
+    }
+
+    #[actix_web::test]
+    async fn follow_unknown() {
+        let token = prepare("test_follow_unknown").await;
+
+        let (status, body) = call(&token, Method::POST, "/me/follows", group("ИС-999/99")).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+        assert_eq!(body["code"], "NOT_FOUND");
+    }
+
+    #[actix_web::test]
+    async fn follow_limit_per_provider() {
+        let token = prepare("test_follow_limit").await;
+        let db = static_app_state().await.get_database().clone();
+
+        for index in 0..50 {
+            ActiveUserFollow {
+                user_id: Set("test_follow_limit".to_string()),
+                provider: Set("other_provider".to_string()),
+                kind: Set(FollowKind::Cabinet),
+                name: Set(format!("{index}")),
+                created_at: Set(Utc::now().fixed_offset()),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .expect("Failed to save user follow");
+        }
+
+        let (status, _) = call(&token, Method::POST, "/me/follows", group("ИС-214/23")).await;
+        assert_eq!(status, StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn follow_limit_concurrent() {
+        let token = prepare("test_follow_limit_concurrent").await;
+        let app_state = static_app_state().await;
+        let db = app_state.get_database().clone();
+
+        for index in 0..49 {
+            ActiveUserFollow {
+                user_id: Set("test_follow_limit_concurrent".to_string()),
+                provider: Set(app_state.get_default_provider().to_string()),
+                kind: Set(FollowKind::Cabinet),
+                name: Set(format!("{index}")),
+                created_at: Set(Utc::now().fixed_offset()),
+                ..Default::default()
+            }
+            .insert(&db)
+            .await
+            .expect("Failed to save user follow");
+        }
+
+        let snapshot = app_state
+            .get_schedule_snapshot(app_state.get_default_provider())
+            .await
+            .unwrap();
+
+        let mut groups = snapshot.data.groups.keys();
+        let (first, second) = (groups.next().unwrap(), groups.next().unwrap());
+
+        // only one of the requests fits into the limit
+        let ((first, _), (second, _)) = tokio::join!(
+            call(&token, Method::POST, "/me/follows", group(first)),
+            call(&token, Method::POST, "/me/follows", group(second)),
+        );
+
+        let mut statuses = [first, second];
+        statuses.sort();
+
+        assert_eq!(statuses, [StatusCode::OK, StatusCode::BAD_REQUEST]);
+    }
+}
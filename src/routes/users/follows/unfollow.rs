@@ -0,0 +1,65 @@
+use self::schema::*;
+use crate::AppState;
+use crate::extractors::base::AsyncExtractor;
+use crate::routes::schema::ResponseError;
+use actix_web::{delete, web};
+use database::entity::{User, UserFollowColumn, UserFollowEntity};
+use database::sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
+
+#[utoipa::path(responses(
+    (status = OK),
+    (
+        status = NOT_FOUND,
+        body = ResponseError<ErrorCode>,
+        example = json!({
+            "code": "NOT_FOUND",
+            "message": "Followed entry not found."
+        })
+    ),
+))]
+#[delete("/{id}")]
+pub async fn unfollow(
+    path: web::Path<Path>,
+    user: AsyncExtractor<User>,
+    app_state: web::Data<AppState>,
+) -> ServiceResponse {
+    // the owner is checked too, so users can't remove entries of each other
+    let result = UserFollowEntity::delete_many()
+        .filter(UserFollowColumn::Id.eq(path.id))
+        .filter(UserFollowColumn::UserId.eq(&user.id))
+        .exec(app_state.get_database())
+        .await
+        .expect("Failed to remove user follow");
+
+    if result.rows_affected == 0 {
+        return Err(ErrorCode::NotFound).into();
+    }
+
+    Ok(()).into()
+}
+
+mod schema {
+    use actix_macros::ErrResponse;
+    use derive_more::Display;
+    use serde::{Deserialize, Serialize};
+    use utoipa::{IntoParams, ToSchema};
+
+    #[derive(Deserialize, IntoParams)]
+    #[into_params(parameter_in = Path)]
+    pub struct Path {
+        /// Identifier of the followed entry.
+        pub id: i32,
+    }
+
+    pub type ServiceResponse = crate::routes::schema::Response<(), ErrorCode>;
+
+    #[derive(Clone, Serialize, Display, ToSchema, ErrResponse)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[schema(as = Follows::Unfollow::ErrorCode)]
+    pub enum ErrorCode {
+        /// The user doesn't follow an entry with this identifier.
+        #[status_code = "actix_web::http::StatusCode::NOT_FOUND"]
+        #[display("Followed entry not found.")]
+        NotFound,
+    }
+}
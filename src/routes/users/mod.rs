@@ -1,8 +1,16 @@
 pub mod by;
+pub mod calendar_feed;
 mod change_group;
+mod change_subgroup;
 mod change_username;
+mod claim_teacher;
+pub mod follows;
 mod me;
+mod request_role;
 
 pub use change_group::*;
+pub use change_subgroup::*;
 pub use change_username::*;
+pub use claim_teacher::*;
 pub use me::*;
+pub use request_role::*;
@@ -0,0 +1,115 @@
+use self::schema::*;
+use crate::extractors::base::AsyncExtractor;
+use crate::routes::schema::ResponseError;
+use crate::routes::schema::role_request::RoleRequestResponse;
+use crate::state::AppState;
+use actix_web::{post, web};
+use chrono::Utc;
+use database::entity::sea_orm_active_enums::{ClaimStatus, UserRole};
+use database::entity::{ActiveRoleRequest, RoleRequestColumn, RoleRequestEntity, User};
+use database::sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter, Set};
+
+/// Asks the administrators to verify the role of the user.
+///
+/// Used by the users who signed up without choosing a role, e.g. through Telegram.
+#[utoipa::path(responses(
+    (status = OK, body = RoleRequestResponse),
+    (
+        status = BAD_REQUEST,
+        body = ResponseError<ErrorCode>,
+        example = json!({
+            "code": "DISALLOWED_ROLE",
+            "message": "This role can't be requested."
+        })
+    ),
+    (
+        status = CONFLICT,
+        body = ResponseError<ErrorCode>,
+        example = json!({
+            "code": "ALREADY_PENDING",
+            "message": "Another request is waiting for a decision."
+        })
+    ),
+))]
+#[post("/request-role")]
+pub async fn request_role(
+    app_state: web::Data<AppState>,
+    user: AsyncExtractor<User>,
+    data: web::Json<Request>,
+) -> ServiceResponse {
+    let user = user.into_inner();
+    let role = data.into_inner().role;
+
+    if role != UserRole::Teacher {
+        return Err(ErrorCode::DisallowedRole).into();
+    }
+
+    if user.role == role {
+        return Err(ErrorCode::AlreadyGranted).into();
+    }
+
+    let db = app_state.get_database();
+
+    let pending = RoleRequestEntity::find()
+        .filter(RoleRequestColumn::UserId.eq(&user.id))
+        .filter(RoleRequestColumn::Status.eq(ClaimStatus::Pending))
+        .one(db)
+        .await
+        .expect("Failed to find role request");
+
+    if pending.is_some() {
+        return Err(ErrorCode::AlreadyPending).into();
+    }
+
+    let request = ActiveRoleRequest {
+        user_id: Set(user.id),
+        role: Set(role),
+        from_role: Set(user.role),
+        status: Set(ClaimStatus::Pending),
+        created_at: Set(Utc::now().fixed_offset()),
+        ..Default::default()
+    }
+    .insert(db)
+    .await
+    .expect("Failed to save role request");
+
+    Ok(request.into()).into()
+}
+
+mod schema {
+    use crate::routes::schema::role_request::RoleRequestResponse;
+    use actix_macros::ErrResponse;
+    use database::entity::sea_orm_active_enums::UserRole;
+    use derive_more::Display;
+    use serde::{Deserialize, Serialize};
+    use utoipa::ToSchema;
+
+    pub type ServiceResponse = crate::routes::schema::Response<RoleRequestResponse, ErrorCode>;
+
+    #[derive(Deserialize, ToSchema)]
+    #[schema(as = RequestRole::Request)]
+    pub struct Request {
+        /// Requested role, only `TEACHER` can be requested.
+        pub role: UserRole,
+    }
+
+    #[derive(Clone, Serialize, Display, ToSchema, ErrResponse)]
+    #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+    #[schema(as = RequestRole::ErrorCode)]
+    pub enum ErrorCode {
+        /// Students don't need verification and administrators are appointed manually.
+        #[status_code = "actix_web::http::StatusCode::BAD_REQUEST"]
+        #[display("This role can't be requested.")]
+        DisallowedRole,
+
+        /// The user already has the role.
+        #[status_code = "actix_web::http::StatusCode::CONFLICT"]
+        #[display("The role is already granted.")]
+        AlreadyGranted,
+
+        /// The previous request of the user hasn't been approved or rejected yet.
+        #[status_code = "actix_web::http::StatusCode::CONFLICT"]
+        #[display("Another request is waiting for a decision.")]
+        AlreadyPending,
+    }
+}
@@ -4,6 +4,8 @@ use std::env;
 pub struct ScheduleEnvData {
     #[cfg(not(test))]
     pub url: Option<String>,
+    #[cfg(not(test))]
+    pub providers_config: Option<String>,
     pub auto_update: bool,
 }
 
@@ -12,8 +14,43 @@ impl Default for ScheduleEnvData {
         Self {
             #[cfg(not(test))]
             url: env::var("SCHEDULE_INIT_URL").ok(),
+            #[cfg(not(test))]
+            providers_config: env::var("SCHEDULE_PROVIDERS_CONFIG").ok(),
             auto_update: !env::var("SCHEDULE_DISABLE_AUTO_UPDATE")
                 .is_ok_and(|v| v.eq("1") || v.eq("true")),
         }
     }
 }
+
+#[cfg(not(test))]
+impl ScheduleEnvData {
+    /// Single provider config used when `SCHEDULE_PROVIDERS_CONFIG` is not set.
+    ///
+    /// Yandex Cloud credentials are taken from `YANDEX_CLOUD_API_KEY` and `YANDEX_CLOUD_FUNC_ID`.
+    pub fn registry_config(&self) -> providers::RegistryConfig {
+        let source = match &self.url {
+            Some(url) => providers::SourceConfig::Url { url: url.clone() },
+            None => providers::SourceConfig::GrabFromSite {
+                yandex_api_key: "$YANDEX_CLOUD_API_KEY".to_string(),
+                yandex_func_id: "$YANDEX_CLOUD_FUNC_ID".to_string(),
+            },
+        };
+
+        let settings = providers::base::ProviderSettings::default();
+
+        providers::RegistryConfig {
+            providers: vec![providers::ProviderConfig {
+                id: "eng_polytechnic".to_string(),
+                kind: providers::ProviderKind::EngelsPolytechnic,
+                default: true,
+                source,
+                update_interval: settings.update_interval.as_secs(),
+                timezone: settings.timezone,
+                day_start: settings.day_start,
+                day_end: settings.day_end,
+                layout: None,
+                buildings: settings.buildings,
+            }],
+        }
+    }
+}
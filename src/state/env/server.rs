@@ -0,0 +1,17 @@
+use std::env;
+
+#[derive(Clone)]
+pub struct ServerEnvData {
+    /// Address the API is reachable at from the outside, used in links given to clients.
+    pub public_url: String,
+}
+
+impl Default for ServerEnvData {
+    fn default() -> Self {
+        let public_url = env::var("PUBLIC_URL").expect("PUBLIC_URL must be set");
+
+        Self {
+            public_url: public_url.trim_end_matches('/').to_string(),
+        }
+    }
+}
@@ -1,10 +1,16 @@
 mod env;
 
+use crate::routes::admin::calendar::CalendarPeriodResponse;
 pub use crate::state::env::AppEnv;
 use actix_web::web;
+use database::entity::{CalendarPeriodEntity, SubjectAliasEntity, TeacherAliasEntity};
 use database::migration::{Migrator, MigratorTrait};
-use database::sea_orm::{ConnectOptions, Database, DatabaseConnection};
-use providers::base::{ScheduleProvider, ScheduleSnapshot};
+use database::sea_orm::{ConnectOptions, Database, DatabaseConnection, DbErr, EntityTrait};
+#[cfg(not(test))]
+use providers::ProviderRegistry;
+#[cfg(test)]
+use providers::base::ProviderSettings;
+use providers::base::{AcademicCalendar, ScheduleAliases, ScheduleProvider, ScheduleSnapshot};
 use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
@@ -15,6 +21,7 @@ pub struct AppState {
     cancel_token: CancellationToken,
     database: DatabaseConnection,
     providers: HashMap<String, Arc<dyn ScheduleProvider>>,
+    default_provider: String,
     env: AppEnv,
 }
 
@@ -23,33 +30,40 @@ impl AppState {
         database: Option<DatabaseConnection>,
     ) -> Result<Self, Box<dyn std::error::Error>> {
         let env = AppEnv::default();
-        let providers: HashMap<String, Arc<dyn ScheduleProvider>> = HashMap::from([(
-            "eng_polytechnic".to_string(),
-            providers::EngelsPolytechnicProvider::get({
-                #[cfg(test)]
-                {
-                    providers::EngelsPolytechnicUpdateSource::Prepared(ScheduleSnapshot {
-                        url: "".to_string(),
-                        fetched_at: chrono::DateTime::default(),
-                        updated_at: chrono::DateTime::default(),
-                        data: providers::test_utils::engels_polytechnic::test_result().unwrap(),
-                    })
-                }
-
-                #[cfg(not(test))]
-                {
-                    if let Some(url) = &env.schedule.url {
-                        providers::EngelsPolytechnicUpdateSource::Url(url.clone())
-                    } else {
-                        providers::EngelsPolytechnicUpdateSource::GrabFromSite {
-                            yandex_api_key: env.yandex_cloud.api_key.clone(),
-                            yandex_func_id: env.yandex_cloud.func_id.clone(),
-                        }
-                    }
-                }
-            })
-            .await?,
-        )]);
+
+        #[cfg(test)]
+        let (providers, default_provider) = {
+            let provider = providers::EngelsPolytechnicProvider::get(
+                providers::EngelsPolytechnicUpdateSource::Prepared(Box::new(ScheduleSnapshot {
+                    url: "".to_string(),
+                    fetched_at: chrono::DateTime::default(),
+                    updated_at: chrono::DateTime::default(),
+                    data: providers::test_utils::engels_polytechnic::test_result().unwrap(),
+                    cached_hash: None,
+                })),
+                ProviderSettings::default(),
+                providers::SheetLayout::default(),
+            )
+            .await?;
+
+            (
+                HashMap::from([("eng_polytechnic".to_string(), provider)]),
+                "eng_polytechnic".to_string(),
+            )
+        };
+
+        #[cfg(not(test))]
+        let (providers, default_provider) = {
+            let registry = match &env.schedule.providers_config {
+                Some(path) => ProviderRegistry::from_file(path)?,
+                None => ProviderRegistry::new(env.schedule.registry_config())?,
+            };
+
+            (
+                registry.instantiate().await?,
+                registry.default_provider().to_string(),
+            )
+        };
 
         let this = Self {
             cancel_token: CancellationToken::new(),
@@ -78,8 +92,12 @@ impl AppState {
             },
             env,
             providers,
+            default_provider,
         };
 
+        this.reload_aliases().await?;
+        this.reload_calendar().await?;
+
         if this.env.schedule.auto_update {
             for provider in this.providers.values() {
                 let provider = provider.clone();
@@ -92,6 +110,19 @@ impl AppState {
         Ok(this)
     }
 
+    /// Identifier of the provider used when neither the request nor the user specifies one.
+    pub fn get_default_provider(&self) -> &str {
+        &self.default_provider
+    }
+
+    pub fn get_providers(&self) -> &HashMap<String, Arc<dyn ScheduleProvider>> {
+        &self.providers
+    }
+
+    pub fn get_provider(&self, provider: &str) -> Option<Arc<dyn ScheduleProvider>> {
+        self.providers.get(provider).cloned()
+    }
+
     pub async fn get_schedule_snapshot(&'_ self, provider: &str) -> Option<Arc<ScheduleSnapshot>> {
         if let Some(provider) = self.providers.get(provider) {
             return Some(provider.get_schedule().await);
@@ -100,6 +131,59 @@ impl AppState {
         None
     }
 
+    /// Loads the administrator corrections from the database and applies them to all providers.
+    pub async fn reload_aliases(&self) -> Result<(), DbErr> {
+        let aliases = ScheduleAliases {
+            teachers: TeacherAliasEntity::find()
+                .all(&self.database)
+                .await?
+                .into_iter()
+                .map(|alias| (alias.alias, alias.canonical))
+                .collect(),
+            subjects: SubjectAliasEntity::find()
+                .all(&self.database)
+                .await?
+                .into_iter()
+                .map(|alias| (alias.alias, alias.canonical))
+                .collect(),
+        };
+
+        for provider in self.providers.values() {
+            provider.set_aliases(aliases.clone()).await;
+        }
+
+        Ok(())
+    }
+
+    /// Loads the academic calendar from the database and applies it to all providers.
+    pub async fn reload_calendar(&self) -> Result<(), DbErr> {
+        let periods: Vec<CalendarPeriodResponse> = CalendarPeriodEntity::find()
+            .all(&self.database)
+            .await?
+            .into_iter()
+            .map(Into::into)
+            .collect();
+
+        for (id, provider) in &self.providers {
+            let calendar = AcademicCalendar::new(
+                periods
+                    .iter()
+                    .filter(|period| {
+                        period
+                            .provider
+                            .as_ref()
+                            .is_none_or(|provider| provider == id)
+                    })
+                    .map(|period| period.period.clone())
+                    .collect(),
+            );
+
+            provider.set_calendar(calendar).await;
+        }
+
+        Ok(())
+    }
+
     pub fn get_database(&'_ self) -> &DatabaseConnection {
         &self.database
     }